@@ -0,0 +1,235 @@
+use std::f64::consts::PI;
+
+/// Describes how the progress of an animation is distributed over its duration.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Starts slow and speeds up, like the css `ease-in` timing function.
+    EaseIn,
+
+    /// Starts fast and slows down, like the css `ease-out` timing function.
+    EaseOut,
+
+    /// Starts and ends slow, like the css `ease-in-out` timing function.
+    EaseInOut,
+
+    /// Quadratic acceleration from zero velocity.
+    QuadIn,
+
+    /// Quadratic deceleration to zero velocity.
+    QuadOut,
+
+    /// Quadratic acceleration until halfway, then deceleration.
+    QuadInOut,
+
+    /// Cubic acceleration from zero velocity.
+    CubicIn,
+
+    /// Cubic deceleration to zero velocity.
+    CubicOut,
+
+    /// Cubic acceleration until halfway, then deceleration.
+    CubicInOut,
+
+    /// Sinusoidal acceleration from zero velocity.
+    SineIn,
+
+    /// Sinusoidal deceleration to zero velocity.
+    SineOut,
+
+    /// Sinusoidal acceleration until halfway, then deceleration.
+    SineInOut,
+
+    /// Overshoots the target a little and moves back.
+    BackOut,
+
+    /// Bounces at the target like a falling ball.
+    BounceOut,
+
+    /// Custom cubic bézier curve with the control points `(x1, y1)` and `(x2, y2)` like the css `cubic-bezier`
+    /// timing function.
+    CubicBezier(f64, f64, f64, f64),
+}
+
+impl Default for Easing {
+    fn default() -> Self {
+        Easing::Linear
+    }
+}
+
+impl Easing {
+    /// Returns the eased progress of the given linear progress between `0.0` and `1.0`.
+    pub fn ease(&self, t: f64) -> f64 {
+        let t = t.max(0.0).min(1.0);
+
+        match *self {
+            Easing::Linear => t,
+            Easing::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Easing::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Easing::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => t * (2.0 - t),
+            Easing::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => (t - 1.0).powi(3) + 1.0,
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    (t - 1.0) * (2.0 * t - 2.0).powi(2) + 1.0
+                }
+            }
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::BackOut => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+            }
+            Easing::BounceOut => bounce_out(t),
+            Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, t),
+        }
+    }
+}
+
+impl From<&str> for Easing {
+    fn from(s: &str) -> Easing {
+        match s {
+            "ease_in" => Easing::EaseIn,
+            "ease_out" => Easing::EaseOut,
+            "ease_in_out" => Easing::EaseInOut,
+            "quad_in" => Easing::QuadIn,
+            "quad_out" => Easing::QuadOut,
+            "quad_in_out" => Easing::QuadInOut,
+            "cubic_in" => Easing::CubicIn,
+            "cubic_out" => Easing::CubicOut,
+            "cubic_in_out" => Easing::CubicInOut,
+            "sine_in" => Easing::SineIn,
+            "sine_out" => Easing::SineOut,
+            "sine_in_out" => Easing::SineInOut,
+            "back_out" => Easing::BackOut,
+            "bounce_out" => Easing::BounceOut,
+            _ => Easing::Linear,
+        }
+    }
+}
+
+impl From<String> for Easing {
+    fn from(s: String) -> Easing {
+        Easing::from(s.as_str())
+    }
+}
+
+fn bounce_out(t: f64) -> f64 {
+    let n1 = 7.5625;
+    let d1 = 2.75;
+
+    if t < 1.0 / d1 {
+        n1 * t * t
+    } else if t < 2.0 / d1 {
+        let t = t - 1.5 / d1;
+        n1 * t * t + 0.75
+    } else if t < 2.5 / d1 {
+        let t = t - 2.25 / d1;
+        n1 * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / d1;
+        n1 * t * t + 0.984_375
+    }
+}
+
+// Calculates a coordinate of a cubic bézier curve that starts at 0.0 and ends at 1.0.
+fn bezier(p1: f64, p2: f64, t: f64) -> f64 {
+    let u = 1.0 - t;
+    3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+}
+
+// Returns the y coordinate of the curve for the given x coordinate.
+fn cubic_bezier(x1: f64, y1: f64, x2: f64, y2: f64, x: f64) -> f64 {
+    // finds the curve parameter of x by bisection, x of the curve is monotonic for control points between 0 and 1
+    let mut low = 0.0;
+    let mut high = 1.0;
+    let mut t = x;
+
+    for _ in 0..32 {
+        let current = bezier(x1, x2, t);
+
+        if (current - x).abs() < 1e-7 {
+            break;
+        }
+
+        if current < x {
+            low = t;
+        } else {
+            high = t;
+        }
+
+        t = (low + high) / 2.0;
+    }
+
+    bezier(y1, y2, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [Easing; 16] = [
+        Easing::Linear,
+        Easing::EaseIn,
+        Easing::EaseOut,
+        Easing::EaseInOut,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::BackOut,
+        Easing::BounceOut,
+        Easing::CubicBezier(0.25, 0.1, 0.25, 1.0),
+    ];
+
+    #[test]
+    fn test_start_and_end() {
+        for easing in EASINGS.iter() {
+            assert!(easing.ease(0.0).abs() < 1e-6, "{:?}", easing);
+            assert!((easing.ease(1.0) - 1.0).abs() < 1e-6, "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn test_ease() {
+        assert!((Easing::Linear.ease(0.25) - 0.25).abs() < 1e-6);
+        assert!((Easing::QuadIn.ease(0.5) - 0.25).abs() < 1e-6);
+        assert!((Easing::EaseInOut.ease(0.5) - 0.5).abs() < 1e-6);
+        assert!(Easing::EaseIn.ease(0.25) < 0.25);
+        assert!(Easing::EaseOut.ease(0.25) > 0.25);
+
+        // overshoots the target
+        assert!(Easing::BackOut.ease(0.8) > 1.0);
+
+        // progress outside of the duration is clamped
+        assert!((Easing::Linear.ease(2.0) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Easing::from("ease_in_out"), Easing::EaseInOut);
+        assert_eq!(Easing::from("bounce_out"), Easing::BounceOut);
+        assert_eq!(Easing::from("linear"), Easing::Linear);
+        assert_eq!(Easing::from("unknown"), Easing::Linear);
+    }
+}
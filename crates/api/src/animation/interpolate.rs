@@ -0,0 +1,198 @@
+use crate::utils::{Brush, Color, LinearGradientStop, Point, Rectangle, Size, Thickness};
+
+/// Used to calculate the values of a property between the start and the end of an animation.
+pub trait Interpolate {
+    /// Returns the value between `self` and `to` at the given progress. The progress is `0.0` at `self` and `1.0` at
+    /// `to`, it could be outside of this range e.g. if an easing curve overshoots the target.
+    fn interpolate(&self, to: &Self, progress: f64) -> Self;
+}
+
+impl Interpolate for f64 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self + (to - self) * progress
+    }
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        self + (to - self) * progress as f32
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Point::new(
+            self.x().interpolate(&to.x(), progress),
+            self.y().interpolate(&to.y(), progress),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Size::new(
+            self.width().interpolate(&to.width(), progress),
+            self.height().interpolate(&to.height(), progress),
+        )
+    }
+}
+
+impl Interpolate for Rectangle {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Rectangle::new(
+            self.position().interpolate(&to.position(), progress),
+            self.size().interpolate(&to.size(), progress),
+        )
+    }
+}
+
+impl Interpolate for Thickness {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Thickness::new(
+            self.left.interpolate(&to.left, progress),
+            self.top.interpolate(&to.top, progress),
+            self.right.interpolate(&to.right, progress),
+            self.bottom.interpolate(&to.bottom, progress),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        Color::interpolate(*self, *to, progress)
+    }
+}
+
+impl Interpolate for Brush {
+    fn interpolate(&self, to: &Self, progress: f64) -> Self {
+        match (self, to) {
+            (Brush::SolidColor(from), Brush::SolidColor(to)) => {
+                Brush::SolidColor(from.interpolate(to, progress))
+            }
+            (
+                Brush::LinearGradient {
+                    start: from_start,
+                    end: from_end,
+                    stops: from_stops,
+                },
+                Brush::LinearGradient { start, end, stops },
+            ) if from_stops.len() == stops.len() => Brush::LinearGradient {
+                start: from_start.interpolate(start, progress),
+                end: from_end.interpolate(end, progress),
+                stops: from_stops
+                    .iter()
+                    .zip(stops)
+                    .map(|(from, to)| LinearGradientStop {
+                        position: from.position.interpolate(&to.position, progress),
+                        color: from.color.interpolate(&to.color, progress),
+                    })
+                    .collect(),
+            },
+            // a solid color fades to a gradient like a gradient with the same color on each stop
+            (Brush::SolidColor(color), Brush::LinearGradient { start, end, stops }) => {
+                Brush::LinearGradient {
+                    start: *start,
+                    end: *end,
+                    stops: stops
+                        .iter()
+                        .map(|stop| LinearGradientStop {
+                            position: stop.position,
+                            color: color.interpolate(&stop.color, progress),
+                        })
+                        .collect(),
+                }
+            }
+            (Brush::LinearGradient { start, end, stops }, Brush::SolidColor(color)) => {
+                Brush::LinearGradient {
+                    start: *start,
+                    end: *end,
+                    stops: stops
+                        .iter()
+                        .map(|stop| LinearGradientStop {
+                            position: stop.position,
+                            color: stop.color.interpolate(color, progress),
+                        })
+                        .collect(),
+                }
+            }
+            // gradients with different stops could not be interpolated
+            _ => {
+                if progress < 0.5 {
+                    self.clone()
+                } else {
+                    to.clone()
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert!((0.0_f64.interpolate(&10.0, 0.25) - 2.5).abs() < f64::EPSILON);
+        assert!((1.0_f32.interpolate(&0.0, 0.5) - 0.5).abs() < f32::EPSILON);
+
+        // overshoot
+        assert!((0.0_f64.interpolate(&10.0, 1.1) - 11.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_thickness_and_rectangle() {
+        assert_eq!(
+            Thickness::new(0.0, 0.0, 4.0, 8.0)
+                .interpolate(&Thickness::new(4.0, 8.0, 0.0, 0.0), 0.5),
+            Thickness::new(2.0, 4.0, 2.0, 4.0)
+        );
+
+        assert_eq!(
+            Rectangle::new((0.0, 0.0), (10.0, 20.0))
+                .interpolate(&Rectangle::new((10.0, 20.0), (20.0, 40.0)), 0.5),
+            Rectangle::new((5.0, 10.0), (15.0, 30.0))
+        );
+    }
+
+    #[test]
+    fn test_brush() {
+        assert_eq!(
+            Brush::from(Color::rgb(0, 0, 0))
+                .interpolate(&Brush::from(Color::rgb(200, 100, 0)), 0.5),
+            Brush::from(Color::rgb(100, 50, 0))
+        );
+
+        let gradient = Brush::LinearGradient {
+            start: Point::new(0.0, 0.0),
+            end: Point::new(10.0, 0.0),
+            stops: vec![
+                LinearGradientStop {
+                    position: 0.0,
+                    color: Color::rgb(200, 0, 0),
+                },
+                LinearGradientStop {
+                    position: 1.0,
+                    color: Color::rgb(0, 200, 0),
+                },
+            ],
+        };
+
+        match Brush::from(Color::rgb(0, 0, 0)).interpolate(&gradient, 0.5) {
+            Brush::LinearGradient { stops, .. } => {
+                assert_eq!(stops[0].color, Color::rgb(100, 0, 0));
+                assert_eq!(stops[1].color, Color::rgb(0, 100, 0));
+            }
+            _ => panic!("gradient expected"),
+        }
+
+        // different count of stops switches at the half
+        let other = Brush::LinearGradient {
+            start: Point::new(0.0, 0.0),
+            end: Point::new(10.0, 0.0),
+            stops: vec![],
+        };
+        assert_eq!(gradient.interpolate(&other, 0.4), gradient);
+        assert_eq!(gradient.interpolate(&other, 0.6), other);
+    }
+}
@@ -0,0 +1,12 @@
+//! This module contains the animation of widget properties. Animations are combined to storyboards that are started
+//! with `Context::start_storyboard` and run by the `AnimationSystem`.
+
+pub use self::easing::*;
+pub use self::interpolate::*;
+pub use self::property_animation::*;
+pub use self::storyboard::*;
+
+mod easing;
+mod interpolate;
+mod property_animation;
+mod storyboard;
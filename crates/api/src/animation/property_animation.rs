@@ -0,0 +1,156 @@
+use dces::prelude::Component;
+
+use crate::widget_base::WidgetContainer;
+
+use super::{Easing, Interpolate};
+
+/// The default duration of an animation in milliseconds.
+pub const DEFAULT_ANIMATION_DURATION: f64 = 250.0;
+
+/// A track describes how the properties of one widget change over time. It is the building block of a `Storyboard`.
+pub trait AnimationTrack {
+    /// Returns the length of the track in milliseconds, including its delay.
+    fn length(&self) -> f64;
+
+    /// Is called once when the storyboard reaches the begin of the track.
+    fn start(&mut self, widget: &mut WidgetContainer);
+
+    /// Updates the widget at the given time in milliseconds since the begin of the track.
+    fn apply(&mut self, widget: &mut WidgetContainer, time: f64);
+}
+
+/// Animates a property of a widget from its current value or a given start value to a target value.
+///
+/// # Example
+///
+/// ```rust
+/// use orbtk_api::prelude::*;
+///
+/// let fade_out: PropertyAnimation<f32> = PropertyAnimation::new("opacity", 0.0)
+///     .duration(200.0)
+///     .easing(Easing::EaseOut);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyAnimation<P> {
+    key: String,
+    from: Option<P>,
+    to: P,
+    duration: f64,
+    delay: f64,
+    easing: Easing,
+}
+
+impl<P> PropertyAnimation<P> {
+    /// Creates a new animation of the property with the given key to the given value.
+    pub fn new(key: impl Into<String>, to: impl Into<P>) -> Self {
+        PropertyAnimation {
+            key: key.into(),
+            from: None,
+            to: to.into(),
+            duration: DEFAULT_ANIMATION_DURATION,
+            delay: 0.0,
+            easing: Easing::default(),
+        }
+    }
+
+    /// Sets the start value. Without a start value the animation starts with the current value of the property.
+    pub fn from(mut self, from: impl Into<P>) -> Self {
+        self.from = Some(from.into());
+        self
+    }
+
+    /// Sets the duration in milliseconds.
+    pub fn duration(mut self, duration: f64) -> Self {
+        self.duration = duration.max(0.0);
+        self
+    }
+
+    /// Sets the time in milliseconds the animation waits before it starts.
+    pub fn delay(mut self, delay: f64) -> Self {
+        self.delay = delay.max(0.0);
+        self
+    }
+
+    /// Sets the easing curve.
+    pub fn easing(mut self, easing: impl Into<Easing>) -> Self {
+        self.easing = easing.into();
+        self
+    }
+
+    /// Gets the key of the animated property.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl<P> AnimationTrack for PropertyAnimation<P>
+where
+    P: Component + Clone + PartialEq + Interpolate,
+{
+    fn length(&self) -> f64 {
+        self.delay + self.duration
+    }
+
+    fn start(&mut self, widget: &mut WidgetContainer) {
+        if self.from.is_none() {
+            self.from = widget.try_clone::<P>(&self.key);
+        }
+    }
+
+    fn apply(&mut self, widget: &mut WidgetContainer, time: f64) {
+        if !widget.has::<P>(&self.key) {
+            return;
+        }
+
+        let progress = match animation_progress(time, self.delay, self.duration) {
+            Some(progress) => progress,
+            None => return,
+        };
+
+        if let Some(from) = &self.from {
+            widget.set(
+                &self.key,
+                from.interpolate(&self.to, self.easing.ease(progress)),
+            );
+        }
+    }
+}
+
+/// Returns the linear progress between `0.0` and `1.0` of an animation at the given time in milliseconds. Returns
+/// `None` while the animation waits for its delay.
+pub fn animation_progress(time: f64, delay: f64, duration: f64) -> Option<f64> {
+    if time < delay {
+        return None;
+    }
+
+    if duration <= 0.0 {
+        return Some(1.0);
+    }
+
+    Some(((time - delay) / duration).min(1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress() {
+        assert_eq!(animation_progress(50.0, 100.0, 200.0), None);
+        assert_eq!(animation_progress(100.0, 100.0, 200.0), Some(0.0));
+        assert_eq!(animation_progress(200.0, 100.0, 200.0), Some(0.5));
+        assert_eq!(animation_progress(500.0, 100.0, 200.0), Some(1.0));
+
+        // animations without duration jump to the end
+        assert_eq!(animation_progress(0.0, 0.0, 0.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_length() {
+        let animation = PropertyAnimation::<f64>::new("width", 100.0)
+            .duration(300.0)
+            .delay(100.0);
+        assert_eq!(animation.key(), "width");
+        assert!((animation.length() - 400.0).abs() < f64::EPSILON);
+    }
+}
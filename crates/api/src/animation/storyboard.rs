@@ -0,0 +1,347 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use dces::prelude::Entity;
+
+use crate::{event::AnimationCompletedHandlerFunction, widget_base::StatesContext};
+
+use super::AnimationTrack;
+
+// A track of a storyboard with the widget it animates.
+struct StoryboardTrack {
+    target: Entity,
+    begin: f64,
+    track: Box<dyn AnimationTrack>,
+}
+
+/// A storyboard combines animation tracks of one or more widgets to one animation. Tracks added with `animate` run
+/// together with the previous track, tracks added with `then` start after all previous tracks are finished.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ctx.start_storyboard(
+///     Storyboard::new()
+///         .animate(popup, PropertyAnimation::<f32>::new("opacity", 1.0).from(0.0))
+///         .animate(popup, PropertyAnimation::<Thickness>::new("margin", 0).from((0, 16, 0, 0)))
+///         .then(label, PropertyAnimation::<Brush>::new("foreground", "#ffffff"))
+///         .on_completed(move |states| states.get_mut::<MainViewState>(id).shown()),
+/// );
+/// ```
+#[derive(Default)]
+pub struct Storyboard {
+    tracks: Vec<StoryboardTrack>,
+    step_begin: f64,
+    length: f64,
+    on_completed: Option<Rc<AnimationCompletedHandlerFunction>>,
+}
+
+impl Storyboard {
+    /// Creates a new empty storyboard.
+    pub fn new() -> Self {
+        Storyboard::default()
+    }
+
+    /// Adds a track for the given widget that starts together with the previous track.
+    pub fn animate(mut self, target: Entity, track: impl AnimationTrack + 'static) -> Self {
+        self.push(target, self.step_begin, Box::new(track));
+        self
+    }
+
+    /// Adds a track for the given widget that starts after all previous tracks are finished.
+    pub fn then(mut self, target: Entity, track: impl AnimationTrack + 'static) -> Self {
+        self.step_begin = self.length;
+        self.push(target, self.step_begin, Box::new(track));
+        self
+    }
+
+    /// Inserts a handler that is called after all tracks are finished. It is not called if the storyboard is stopped.
+    pub fn on_completed<H: Fn(&mut StatesContext) + 'static>(mut self, handler: H) -> Self {
+        self.on_completed = Some(Rc::new(handler));
+        self
+    }
+
+    /// Returns the length of the storyboard in milliseconds.
+    pub fn length(&self) -> f64 {
+        self.length
+    }
+
+    /// Returns the count of tracks.
+    pub fn len(&self) -> usize {
+        self.tracks.len()
+    }
+
+    /// Checks if the storyboard has no tracks.
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+    }
+
+    /// Takes the completed handler out of the storyboard.
+    pub fn take_completed_handler(&mut self) -> Option<Rc<AnimationCompletedHandlerFunction>> {
+        self.on_completed.take()
+    }
+
+    fn push(&mut self, target: Entity, begin: f64, track: Box<dyn AnimationTrack>) {
+        self.length = self.length.max(begin + track.length());
+        self.tracks.push(StoryboardTrack {
+            target,
+            begin,
+            track,
+        });
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum TrackState {
+    Pending,
+    Running,
+    Finished,
+}
+
+// A storyboard that is started by a widget.
+struct RunningStoryboard {
+    id: u32,
+    caller: Entity,
+    start_time: Option<f64>,
+    storyboard: Storyboard,
+    track_states: Vec<TrackState>,
+}
+
+/// Runs the storyboards of a window. It is advanced by the `AnimationSystem` on each frame.
+#[derive(Default)]
+pub struct Animator {
+    storyboards: Vec<RunningStoryboard>,
+    transitions: BTreeMap<(Entity, String), u32>,
+    next_id: u32,
+}
+
+impl Animator {
+    /// Creates a new animator.
+    pub fn new() -> Self {
+        Animator::default()
+    }
+
+    /// Starts the given storyboard on the next frame and returns its id. The caller is the widget that receives the
+    /// completed event.
+    pub fn start(&mut self, caller: Entity, storyboard: Storyboard) -> u32 {
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+
+        self.storyboards.push(RunningStoryboard {
+            id,
+            caller,
+            start_time: None,
+            track_states: vec![TrackState::Pending; storyboard.len()],
+            storyboard,
+        });
+
+        id
+    }
+
+    /// Stops the storyboard with the given id. The animated properties keep their current values. Returns the widget
+    /// that has started the storyboard or `None` if the storyboard is not running.
+    pub fn stop(&mut self, id: u32) -> Option<Entity> {
+        let index = self
+            .storyboards
+            .iter()
+            .position(|storyboard| storyboard.id == id)?;
+
+        self.transitions.retain(|_, transition| *transition != id);
+
+        Some(self.storyboards.remove(index).caller)
+    }
+
+    /// Starts the transition of a property of the given widget and returns its id. A running transition of the same
+    /// property is stopped.
+    pub fn start_transition(
+        &mut self,
+        target: Entity,
+        key: &str,
+        track: impl AnimationTrack + 'static,
+    ) -> u32 {
+        self.stop_transition(target, key);
+
+        let id = self.start(target, Storyboard::new().animate(target, track));
+        self.transitions.insert((target, key.to_string()), id);
+
+        id
+    }
+
+    /// Stops the transition of a property of the given widget.
+    pub fn stop_transition(&mut self, target: Entity, key: &str) {
+        if let Some(id) = self.transitions.remove(&(target, key.to_string())) {
+            self.stop(id);
+        }
+    }
+
+    /// Checks if a transition of a property of the given widget is running.
+    pub fn is_transition_running(&self, target: Entity, key: &str) -> bool {
+        self.transitions.contains_key(&(target, key.to_string()))
+    }
+
+    /// Checks if the storyboard with the given id is running.
+    pub fn is_running(&self, id: u32) -> bool {
+        self.storyboards
+            .iter()
+            .any(|storyboard| storyboard.id == id)
+    }
+
+    /// Checks if no storyboard is running.
+    pub fn is_empty(&self) -> bool {
+        self.storyboards.is_empty()
+    }
+
+    /// Advances all storyboards to the given time stamp in milliseconds. `apply` is called with the target widget,
+    /// the track, a flag that is `true` the first time the track is applied and the time since the begin of the
+    /// track. Returns the ids and callers of the completed storyboards.
+    pub fn advance<F>(&mut self, time: f64, mut apply: F) -> Vec<(u32, Entity)>
+    where
+        F: FnMut(Entity, &mut dyn AnimationTrack, bool, f64),
+    {
+        let mut completed = vec![];
+
+        for running in &mut self.storyboards {
+            let elapsed = time - *running.start_time.get_or_insert(time);
+
+            for (track, state) in running
+                .storyboard
+                .tracks
+                .iter_mut()
+                .zip(running.track_states.iter_mut())
+            {
+                let track_time = elapsed - track.begin;
+
+                if *state == TrackState::Finished || track_time < 0.0 {
+                    continue;
+                }
+
+                // a track is applied at least once at its end, also if the frame comes late
+                apply(
+                    track.target,
+                    &mut *track.track,
+                    *state == TrackState::Pending,
+                    track_time,
+                );
+
+                *state = if track_time >= track.track.length() {
+                    TrackState::Finished
+                } else {
+                    TrackState::Running
+                };
+            }
+
+            if elapsed >= running.storyboard.length() {
+                completed.push((running.id, running.caller));
+            }
+        }
+
+        self.storyboards
+            .retain(|running| !completed.iter().any(|(id, _)| running.id == *id));
+        self.transitions
+            .retain(|_, transition| !completed.iter().any(|(id, _)| transition == id));
+
+        completed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::widget_base::WidgetContainer;
+
+    struct TestTrack(f64);
+
+    impl AnimationTrack for TestTrack {
+        fn length(&self) -> f64 {
+            self.0
+        }
+
+        fn start(&mut self, _: &mut WidgetContainer) {}
+
+        fn apply(&mut self, _: &mut WidgetContainer, _: f64) {}
+    }
+
+    #[test]
+    fn test_length() {
+        let storyboard = Storyboard::new()
+            .animate(Entity(0), TestTrack(100.0))
+            .animate(Entity(1), TestTrack(200.0))
+            .then(Entity(0), TestTrack(50.0))
+            .animate(Entity(1), TestTrack(100.0));
+
+        assert_eq!(storyboard.len(), 4);
+        assert!((storyboard.length() - 300.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut animator = Animator::new();
+        let id = animator.start(
+            Entity(5),
+            Storyboard::new()
+                .animate(Entity(0), TestTrack(100.0))
+                .then(Entity(1), TestTrack(100.0)),
+        );
+        assert!(animator.is_running(id));
+
+        let mut applied = vec![];
+
+        // the storyboard starts with the first frame
+        assert!(animator
+            .advance(1000.0, |target, _, start, time| applied
+                .push((target, start, time)))
+            .is_empty());
+        assert_eq!(applied, vec![(Entity(0), true, 0.0)]);
+
+        // a late frame finishes the first track and starts the second
+        applied.clear();
+        assert!(animator
+            .advance(1150.0, |target, _, start, time| applied
+                .push((target, start, time)))
+            .is_empty());
+        assert_eq!(
+            applied,
+            vec![(Entity(0), false, 150.0), (Entity(1), true, 50.0)]
+        );
+
+        // finished tracks are not applied again
+        applied.clear();
+        assert_eq!(
+            animator.advance(1200.0, |target, _, start, time| applied
+                .push((target, start, time))),
+            vec![(id, Entity(5))]
+        );
+        assert_eq!(applied, vec![(Entity(1), false, 100.0)]);
+        assert!(animator.is_empty());
+    }
+
+    #[test]
+    fn test_stop() {
+        let mut animator = Animator::new();
+        let id = animator.start(
+            Entity(0),
+            Storyboard::new().animate(Entity(0), TestTrack(100.0)),
+        );
+
+        assert_eq!(animator.stop(id), Some(Entity(0)));
+        assert_eq!(animator.stop(id), None);
+        assert!(!animator.is_running(id));
+    }
+
+    #[test]
+    fn test_transition() {
+        let mut animator = Animator::new();
+        let first = animator.start_transition(Entity(0), "background", TestTrack(100.0));
+        assert!(animator.is_transition_running(Entity(0), "background"));
+
+        // a new transition of the same property replaces the running one
+        let second = animator.start_transition(Entity(0), "background", TestTrack(100.0));
+        assert!(!animator.is_running(first));
+        assert!(animator.is_running(second));
+
+        animator.advance(0.0, |_, _, _, _| {});
+        animator.advance(100.0, |_, _, _, _| {});
+        assert!(!animator.is_transition_running(Entity(0), "background"));
+        assert!(animator.is_empty());
+    }
+}
@@ -7,13 +7,15 @@ use std::{
 
 use dces::prelude::*;
 
-use super::WindowAdapter;
+use super::{MessageBus, ModalOverlay, ModalParent, WindowAdapter};
 
 use crate::{
+    animation::Animator,
     event::*,
     layout::*,
     render_object::*,
     shell::{ShellRequest, WindowRequest},
+    theming::ThemeWatcher,
     utils::Point,
     widget_base::*,
 };
@@ -27,10 +29,24 @@ pub struct ContextProvider {
     pub states: Rc<RefCell<BTreeMap<Entity, Box<dyn State>>>>,
     pub event_queue: Rc<RefCell<EventQueue>>,
     pub mouse_position: Rc<Cell<Point>>,
+    pub hovered_widgets: Rc<RefCell<Vec<Entity>>>,
+    pub mouse_capture: Rc<Cell<Option<Entity>>>,
+    pub drag_session: Rc<RefCell<Option<DragSession>>>,
     pub window_sender: mpsc::Sender<WindowRequest>,
     pub shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
     pub application_name: String,
+    pub registry: Rc<RefCell<Registry>>,
+    pub message_bus: MessageBus,
+    pub window_position: Rc<Cell<Point>>,
+    pub scale_factor: Rc<Cell<f64>>,
+    pub modal_parent: Rc<RefCell<Option<ModalParent>>>,
+    pub modal_windows: Rc<Cell<usize>>,
+    pub modal_overlays: Rc<RefCell<Vec<ModalOverlay>>>,
+    pub next_modal_id: Rc<Cell<u32>>,
+    pub animation_frames: Rc<RefCell<Vec<Entity>>>,
+    pub animator: Rc<RefCell<Animator>>,
     pub first_run: Rc<Cell<bool>>,
+    pub theme_watcher: Rc<RefCell<Option<ThemeWatcher>>>,
 }
 
 impl ContextProvider {
@@ -39,6 +55,8 @@ impl ContextProvider {
         window_sender: mpsc::Sender<WindowRequest>,
         shell_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
         application_name: impl Into<String>,
+        registry: Rc<RefCell<Registry>>,
+        message_bus: MessageBus,
     ) -> Self {
         ContextProvider {
             render_objects: Rc::new(RefCell::new(BTreeMap::new())),
@@ -47,10 +65,24 @@ impl ContextProvider {
             states: Rc::new(RefCell::new(BTreeMap::new())),
             event_queue: Rc::new(RefCell::new(EventQueue::new())),
             mouse_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
+            hovered_widgets: Rc::new(RefCell::new(vec![])),
+            mouse_capture: Rc::new(Cell::new(None)),
+            drag_session: Rc::new(RefCell::new(None)),
             window_sender,
             shell_sender,
             application_name: application_name.into(),
+            registry,
+            message_bus,
+            window_position: Rc::new(Cell::new(Point::new(0.0, 0.0))),
+            scale_factor: Rc::new(Cell::new(1.0)),
+            modal_parent: Rc::new(RefCell::new(None)),
+            modal_windows: Rc::new(Cell::new(0)),
+            modal_overlays: Rc::new(RefCell::new(vec![])),
+            next_modal_id: Rc::new(Cell::new(0)),
+            animation_frames: Rc::new(RefCell::new(vec![])),
+            animator: Rc::new(RefCell::new(Animator::new())),
             first_run: Rc::new(Cell::new(true)),
+            theme_watcher: Rc::new(RefCell::new(None)),
         }
     }
 }
@@ -2,7 +2,11 @@ use std::collections::HashMap;
 
 use dces::prelude::Entity;
 
-use crate::{shell::Key, theming::Theme};
+use crate::{
+    event::{ClickSettings, GestureSettings},
+    shell::Key,
+    theming::Theme,
+};
 
 #[derive(Default, Clone, Debug, PartialEq)]
 /// The `Global` struct is used to define global `properties` that could be access application width.
@@ -18,6 +22,12 @@ pub struct Global {
 
     /// The current window theme
     pub theme: Theme,
+
+    /// Describes how mouse presses are combined to double clicks and long presses.
+    pub click_settings: ClickSettings,
+
+    /// Describes how touch points are recognized as gestures.
+    pub gesture_settings: GestureSettings,
 }
 
 /// Contains the state information for the keyboard.
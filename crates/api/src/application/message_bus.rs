@@ -0,0 +1,144 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::mpsc};
+
+use dces::prelude::Entity;
+
+use crate::{event::*, shell::WindowRequest};
+
+struct WindowEndpoint {
+    event_queue: Rc<RefCell<EventQueue>>,
+    root: Entity,
+    window_sender: mpsc::Sender<WindowRequest>,
+}
+
+/// The `MessageBus` is shared by all windows of an application. It is used to send messages from one window to
+/// another window by the id of the target window.
+///
+/// A window could be reached over the bus if its root widget has an id, e.g. `Window::new().id("settings")`.
+#[derive(Clone, Default)]
+pub struct MessageBus {
+    windows: Rc<RefCell<HashMap<String, WindowEndpoint>>>,
+}
+
+impl MessageBus {
+    /// Creates a new message bus.
+    pub fn new() -> Self {
+        MessageBus::default()
+    }
+
+    /// Registers a window with the given id. An already registered window with the same id is replaced.
+    pub fn register_window(
+        &self,
+        id: impl Into<String>,
+        event_queue: Rc<RefCell<EventQueue>>,
+        root: Entity,
+        window_sender: mpsc::Sender<WindowRequest>,
+    ) {
+        self.windows.borrow_mut().insert(
+            id.into(),
+            WindowEndpoint {
+                event_queue,
+                root,
+                window_sender,
+            },
+        );
+    }
+
+    /// Removes the window with the given id from the bus.
+    pub fn unregister_window(&self, id: &str) {
+        self.windows.borrow_mut().remove(id);
+    }
+
+    /// Checks if a window with the given id is registered.
+    pub fn contains_window(&self, id: &str) -> bool {
+        self.windows.borrow().contains_key(id)
+    }
+
+    /// Returns the ids of all registered windows.
+    pub fn window_ids(&self) -> Vec<String> {
+        self.windows.borrow().keys().cloned().collect()
+    }
+
+    /// Sends a message to the window with the given id. Returns `false` if there is no window with the id.
+    pub fn send(&self, sender: Option<&str>, window_id: &str, message: Message) -> bool {
+        if let Some(window) = self.windows.borrow().get(window_id) {
+            window
+                .event_queue
+                .borrow_mut()
+                .register_event_with_strategy(
+                    MessageEvent {
+                        sender: sender.map(String::from),
+                        message,
+                    },
+                    EventStrategy::TopDown,
+                    window.root,
+                );
+
+            // wakes up the target window to handle the message
+            window.window_sender.send(WindowRequest::Redraw).is_ok()
+        } else {
+            false
+        }
+    }
+
+    /// Sends a message to all registered windows except the sender.
+    pub fn broadcast(&self, sender: Option<&str>, message: Message) {
+        let ids: Vec<String> = self
+            .window_ids()
+            .into_iter()
+            .filter(|id| Some(id.as_str()) != sender)
+            .collect();
+
+        for id in ids {
+            self.send(sender, id.as_str(), message.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send() {
+        let bus = MessageBus::new();
+        let main_queue = Rc::new(RefCell::new(EventQueue::new()));
+        let settings_queue = Rc::new(RefCell::new(EventQueue::new()));
+        let (main_sender, main_receiver) = mpsc::channel();
+        let (settings_sender, _settings_receiver) = mpsc::channel();
+
+        bus.register_window("main", main_queue.clone(), Entity(0), main_sender);
+        bus.register_window(
+            "settings",
+            settings_queue.clone(),
+            Entity(0),
+            settings_sender,
+        );
+
+        assert!(bus.send(
+            Some("settings"),
+            "main",
+            Message::new(String::from("changed"))
+        ));
+        assert!(!bus.send(Some("settings"), "unknown", Message::new(0)));
+
+        let event = main_queue.borrow_mut().dequeue().unwrap();
+        assert_eq!(event.strategy, EventStrategy::TopDown);
+
+        let event = event.downcast::<MessageEvent>().unwrap();
+        assert_eq!(event.sender, Some(String::from("settings")));
+        assert_eq!(
+            event.message.get::<String>(),
+            Some(&String::from("changed"))
+        );
+        assert!(main_receiver.try_recv().is_ok());
+        assert!(settings_queue.borrow().is_empty());
+
+        bus.broadcast(Some("main"), Message::new(1));
+        assert!(main_queue.borrow().is_empty());
+        assert_eq!(settings_queue.borrow().len(), 1);
+
+        bus.unregister_window("settings");
+        assert!(!bus.contains_window("settings"));
+        assert_eq!(bus.window_ids(), vec![String::from("main")]);
+    }
+}
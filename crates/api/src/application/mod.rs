@@ -1,22 +1,30 @@
 //! This module contains the base elements of an OrbTk application (Application, WindowBuilder and Window).
 
-use std::sync::mpsc;
+use std::{cell::RefCell, rc::Rc, sync::mpsc};
 
-use dces::prelude::Entity;
+use dces::prelude::{Component, Entity};
 
 use crate::{
-    shell::{Shell, ShellRequest},
-    theming::Theme,
-    widget_base::BuildContext,
+    services::{
+        ColorSchemeSource, ColorSchemeThemes, FontIconProvider, IconProvider, IconRegistry,
+        Settings,
+    },
+    shell::{ColorScheme, Shell, ShellRequest},
+    theming::{Density, Theme},
+    widget_base::{BuildContext, Registry},
 };
 
 pub use self::context_provider::*;
 pub use self::global::*;
+pub use self::message_bus::*;
+pub use self::modal::*;
 pub use self::overlay::*;
 pub use self::window_adapter::*;
 
 mod context_provider;
 mod global;
+mod message_bus;
+mod modal;
 mod overlay;
 mod window_adapter;
 
@@ -27,6 +35,8 @@ pub struct Application {
     shell: Shell<WindowAdapter>,
     name: Box<str>,
     theme: Theme,
+    registry: Rc<RefCell<Registry>>,
+    message_bus: MessageBus,
 }
 
 impl Default for Application {
@@ -41,33 +51,141 @@ impl Application {
         Self::default()
     }
 
-    /// Sets the default theme for the application. Could be changed per window.
+    /// Sets the default theme for the application. Could be changed per window. The theme is not switched by the
+    /// preferred color scheme of the desktop.
     pub fn theme(mut self, theme: Theme) -> Self {
         self.theme = theme;
+        self.color_scheme_themes().set_enabled(false);
         self
     }
 
+    /// Sets the density of the themes of the application, e.g. `Density::Touch` for kiosks. Could be switched at
+    /// runtime with `Context::set_density`.
+    pub fn density(mut self, density: Density) -> Self {
+        self.theme.set_density(density);
+        self.color_scheme_themes().set_density(density);
+        self
+    }
+
+    /// Overrides the preferred color scheme of the desktop, e.g. to start the application always with the light
+    /// theme. Could be changed later by the `"color_scheme"` service of the registry.
+    pub fn color_scheme(self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme_themes().set_manual(Some(color_scheme));
+        self
+    }
+
+    /// Replaces the source of the preferred color scheme, e.g. with a `StubColorScheme` for tests without a desktop
+    /// or with `SystemColorScheme::polling` for shells that do not report changes of the desktop.
+    pub fn color_scheme_source(self, source: impl ColorSchemeSource + 'static) -> Self {
+        self.color_scheme_themes().set_source(source);
+        self
+    }
+
+    fn color_scheme_themes(&self) -> std::cell::RefMut<'_, ColorSchemeThemes> {
+        std::cell::RefMut::map(self.registry.borrow_mut(), |registry| {
+            registry.get_mut::<ColorSchemeThemes>("color_scheme")
+        })
+    }
+
     /// Create a new application with the given name.
     pub fn from_name(name: impl Into<Box<str>>) -> Self {
         let (sender, receiver) = mpsc::channel();
+        let name = name.into();
+
+        // services of the registry are shared by all windows of the application
+        let mut registry = Registry::new();
+
+        if name.is_empty() {
+            registry.register("settings", Settings::default());
+        } else {
+            registry.register("settings", Settings::new(name.clone()));
+        }
+
+        // the light and dark themes follow the color scheme of the desktop, the features select the theme that is
+        // used if the color scheme is unknown
+        let fallback = if cfg!(feature = "light") {
+            ColorScheme::Light
+        } else {
+            ColorScheme::Dark
+        };
+
+        let mut color_scheme_themes = ColorSchemeThemes::new(
+            crate::theme::light_theme(),
+            crate::theme::dark_theme(),
+            fallback,
+        );
+        color_scheme_themes.set_enabled(!cfg!(feature = "redox"));
+        registry.register("color_scheme", color_scheme_themes);
+
+        let mut icons = IconRegistry::new();
+        icons.register(
+            "material",
+            FontIconProvider::with_table(
+                crate::theme::MATERIAL_ICONS_FONT,
+                crate::theme::MATERIAL_ICONS,
+            ),
+        );
+        registry.register("icons", icons);
 
         Application {
             request_sender: sender,
-            name: name.into(),
+            name,
             shell: Shell::new(receiver),
-            #[cfg(all(not(feature = "light"), not(feature = "redox")))]
             theme: crate::theme::default_theme(),
-            #[cfg(feature = "light")]
-            theme: crate::theme::light_theme(),
+            registry: Rc::new(RefCell::new(registry)),
+            message_bus: MessageBus::new(),
         }
     }
 
+    /// Overrides the scale factor of all windows, e.g. to test the ui on HiDPI displays. By default the scale factor
+    /// of the monitor is used. It could also be overridden by the `ORBTK_SCALE_FACTOR` environment variable.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.shell.set_scale_factor(scale_factor);
+        self
+    }
+
+    /// Registers the provider of an icon set, e.g. `app` for icons like `app.save`. Icon sets could also be
+    /// registered later by the `"icons"` service of the registry.
+    pub fn icon_set(self, set: impl Into<String>, provider: impl IconProvider + 'static) -> Self {
+        self.registry
+            .borrow_mut()
+            .get_mut::<IconRegistry>("icons")
+            .register(set, provider);
+        self
+    }
+
+    /// Registers a service that is shared by all windows of the application. Could be accessed from the registry
+    /// of a state, e.g. `registry.get::<MyService>("my_service")`.
+    pub fn service<S: Component>(self, key: impl Into<String>, service: S) -> Self {
+        self.registry.borrow_mut().register(key, service);
+        self
+    }
+
+    /// Returns the message bus that is used to send messages between the windows of the application.
+    pub fn message_bus(&self) -> MessageBus {
+        self.message_bus.clone()
+    }
+
     /// Creates a new window and add it to the application.
     pub fn window<F: Fn(&mut BuildContext) -> Entity + 'static>(mut self, create_fn: F) -> Self {
+        let theme = {
+            let mut themes = self.color_scheme_themes();
+
+            if themes.is_enabled() {
+                themes.poll();
+                themes.theme().clone()
+            } else {
+                self.theme.clone()
+            }
+        };
+
         let (adapter, settings, receiver) = create_window(
             self.name.clone(),
-            self.theme.clone(),
+            theme,
             self.request_sender.clone(),
+            self.registry.clone(),
+            self.message_bus.clone(),
+            None,
             create_fn,
         );
 
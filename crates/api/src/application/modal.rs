@@ -0,0 +1,143 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::mpsc,
+};
+
+use dces::prelude::Entity;
+
+use crate::{
+    event::{EventQueue, EventStrategy, Message, ModalResultEvent},
+    shell::WindowRequest,
+    utils::Rectangle,
+};
+
+/// Connects a modal window with the window that has opened it.
+#[derive(Clone)]
+pub struct ModalParent {
+    id: u32,
+    caller: Entity,
+    event_queue: Rc<RefCell<EventQueue>>,
+    window_sender: mpsc::Sender<WindowRequest>,
+    modal_windows: Rc<Cell<usize>>,
+    bounds: Rectangle,
+}
+
+impl ModalParent {
+    /// Creates a new modal parent. Input of the parent window is blocked until the modal is finished.
+    pub fn new(
+        id: u32,
+        caller: Entity,
+        event_queue: Rc<RefCell<EventQueue>>,
+        window_sender: mpsc::Sender<WindowRequest>,
+        modal_windows: Rc<Cell<usize>>,
+        bounds: Rectangle,
+    ) -> Self {
+        modal_windows.set(modal_windows.get() + 1);
+
+        ModalParent {
+            id,
+            caller,
+            event_queue,
+            window_sender,
+            modal_windows,
+            bounds,
+        }
+    }
+
+    /// Gets the screen bounds of the parent window.
+    pub fn bounds(&self) -> Rectangle {
+        self.bounds
+    }
+
+    /// Sends the result to the widget that has opened the modal and unblocks the parent window.
+    pub fn finish(self, result: Option<Message>) {
+        self.modal_windows
+            .set(self.modal_windows.get().saturating_sub(1));
+
+        self.event_queue.borrow_mut().register_event_with_strategy(
+            ModalResultEvent {
+                id: self.id,
+                result,
+            },
+            EventStrategy::Direct,
+            self.caller,
+        );
+
+        self.window_sender.send(WindowRequest::Redraw).ok();
+    }
+}
+
+/// Describes a modal that is displayed on the overlay of the window.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ModalOverlay {
+    /// The id of the modal.
+    pub id: u32,
+
+    /// The widget that has opened the modal and receives the result.
+    pub caller: Entity,
+
+    /// The modal layer on the overlay that contains the content of the modal.
+    pub layer: Entity,
+}
+
+/// Calculates the position of a window with the given size centered on the given parent bounds.
+pub fn center_on_parent(parent: Rectangle, size: (f64, f64)) -> (f64, f64) {
+    (
+        parent.x() + ((parent.width() - size.0) / 2.0).floor(),
+        parent.y() + ((parent.height() - size.1) / 2.0).floor(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_center_on_parent() {
+        assert_eq!(
+            center_on_parent(
+                Rectangle::new((100.0, 50.0), (400.0, 300.0)),
+                (200.0, 100.0)
+            ),
+            (200.0, 150.0)
+        );
+
+        // bigger windows are moved to the top left
+        assert_eq!(
+            center_on_parent(
+                Rectangle::new((100.0, 50.0), (400.0, 300.0)),
+                (500.0, 301.0)
+            ),
+            (50.0, 49.0)
+        );
+    }
+
+    #[test]
+    fn test_modal_parent() {
+        let event_queue = Rc::new(RefCell::new(EventQueue::new()));
+        let modal_windows = Rc::new(Cell::new(0));
+        let (sender, receiver) = mpsc::channel();
+
+        let parent = ModalParent::new(
+            1,
+            Entity(0),
+            event_queue.clone(),
+            sender,
+            modal_windows.clone(),
+            Rectangle::default(),
+        );
+        assert_eq!(modal_windows.get(), 1);
+
+        parent.finish(Some(Message::new(true)));
+        assert_eq!(modal_windows.get(), 0);
+        assert!(receiver.try_recv().is_ok());
+
+        let event = event_queue.borrow_mut().dequeue().unwrap();
+        assert_eq!(event.strategy, EventStrategy::Direct);
+
+        let event = event.downcast::<ModalResultEvent>().unwrap();
+        assert_eq!(event.id, 1);
+        assert_eq!(event.result.unwrap().get::<bool>(), Some(&true));
+    }
+}
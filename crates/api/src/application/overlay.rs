@@ -10,9 +10,10 @@ use dces::prelude::*;
 
 use crate::{
     event::*,
-    layout::{AbsoluteLayout, Layout},
+    layout::{AbsoluteLayout, GridLayout, Layout},
     proc_macros::WidgetCtx,
     properties::*,
+    render_object::{RectangleRenderObject, RenderObject},
     theming::Selector,
     utils::*,
     widget,
@@ -33,3 +34,30 @@ impl Template for Overlay {
         Box::new(AbsoluteLayout::new())
     }
 }
+
+widget!(
+    /// The `ModalLayer` covers the window on the overlay and displays the content of a modal in its center. Input
+    /// to the widgets below the layer is blocked while it is open.
+    ///
+    /// **style:** `modal_layer`
+    ModalLayer {
+        /// Sets or shares the background property.
+        background: Brush
+    }
+);
+
+impl Template for ModalLayer {
+    fn template(self, _: Entity, _: &mut BuildContext) -> Self {
+        self.name("ModalLayer")
+            .style("modal_layer")
+            .background("transparent")
+    }
+
+    fn render_object(&self) -> Box<dyn RenderObject> {
+        Box::new(RectangleRenderObject)
+    }
+
+    fn layout(&self) -> Box<dyn Layout> {
+        Box::new(GridLayout::new())
+    }
+}
@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::HashMap, sync::mpsc};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, sync::mpsc, time::Duration};
 
 use dces::prelude::*;
 
@@ -7,7 +7,7 @@ use crate::{
     event::*,
     properties::*,
     render,
-    services::Settings,
+    services::ColorSchemeThemes,
     shell,
     shell::{ShellRequest, WindowRequest, WindowSettings},
     systems::*,
@@ -16,10 +16,17 @@ use crate::{
     widget_base::*,
 };
 
+// Interval in which the file of a watched theme is checked for changes.
+const THEME_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Represents a window. Each window has its own tree, event pipeline and shell.
 pub struct WindowAdapter {
     world: World<Tree, StringComponentStore, render::RenderContext2D>,
     ctx: ContextProvider,
+    click_counter: ClickCounter,
+    gesture_recognizer: GestureRecognizer,
+    frame_clock: FrameClock,
+    color_scheme: Option<shell::ColorScheme>,
 }
 
 impl WindowAdapter {
@@ -28,7 +35,14 @@ impl WindowAdapter {
         world: World<Tree, StringComponentStore, render::RenderContext2D>,
         ctx: ContextProvider,
     ) -> Self {
-        WindowAdapter { world, ctx }
+        WindowAdapter {
+            world,
+            ctx,
+            click_counter: ClickCounter::new(),
+            gesture_recognizer: GestureRecognizer::new(),
+            frame_clock: FrameClock::new(),
+            color_scheme: None,
+        }
     }
 }
 
@@ -40,9 +54,204 @@ impl WindowAdapter {
             .root
             .unwrap()
     }
+
+    fn click_settings(&mut self) -> ClickSettings {
+        let root = self.root();
+        self.world
+            .entity_component_manager()
+            .component_store()
+            .get::<Global>("global", root)
+            .map_or(ClickSettings::default(), |global| global.click_settings)
+    }
+
+    fn gesture_settings(&mut self) -> GestureSettings {
+        let root = self.root();
+        self.world
+            .entity_component_manager()
+            .component_store()
+            .get::<Global>("global", root)
+            .map_or(GestureSettings::default(), |global| global.gesture_settings)
+    }
+
+    fn register_gesture(&mut self, gesture: Gesture) {
+        let root = self.root();
+        let mut event_queue = self.ctx.event_queue.borrow_mut();
+
+        match gesture {
+            Gesture::Tap { position } => event_queue.register_event(TapEvent { position }, root),
+            Gesture::Pan { position, delta } => {
+                event_queue.register_event(PanEvent { position, delta }, root)
+            }
+            Gesture::Pinch { center, scale } => {
+                event_queue.register_event(PinchEvent { center, scale }, root)
+            }
+            Gesture::Swipe { position, velocity } => {
+                event_queue.register_event(SwipeEvent { position, velocity }, root)
+            }
+        }
+    }
+
+    // Sends the next step of the kinetic panning after a swipe.
+    fn check_kinetic_pan(&mut self) {
+        if !self.gesture_recognizer.is_kinetic_pan_pending() {
+            return;
+        }
+
+        let settings = self.gesture_settings();
+
+        if let Some(gesture) = self.gesture_recognizer.kinetic_pan(&settings, time_stamp()) {
+            self.register_gesture(gesture);
+        }
+
+        if self.gesture_recognizer.is_kinetic_pan_pending() {
+            // keeps the window updating until the kinetic panning stops
+            self.ctx
+                .window_sender
+                .send(WindowRequest::Redraw)
+                .expect("WindowAdapter::check_kinetic_pan: could not send request to window.");
+        }
+    }
+
+    // Sends the next frame to all widgets that have requested one and marks them as dirty to redraw them.
+    fn check_animation_frames(&mut self) {
+        let widgets: Vec<Entity> = self.ctx.animation_frames.borrow_mut().drain(..).collect();
+
+        if widgets.is_empty() {
+            self.frame_clock.stop();
+            return;
+        }
+
+        let frame = self.frame_clock.next(time_stamp());
+        let ecm = self.world.entity_component_manager();
+
+        for widget in widgets {
+            // the widget could be removed since the request
+            if ecm.component_store().get::<bool>("dirty", widget).is_err() {
+                continue;
+            }
+
+            mark_as_dirty("bounds", widget, ecm);
+
+            self.ctx
+                .event_queue
+                .borrow_mut()
+                .register_event_with_strategy(
+                    AnimationFrameEvent { frame },
+                    EventStrategy::Direct,
+                    widget,
+                );
+        }
+    }
+
+    // Switches to the theme of the watched theme file if the file has changed.
+    fn check_theme_watcher(&mut self, render_context: &mut render::RenderContext2D) {
+        let result = match self.ctx.theme_watcher.borrow_mut().as_mut() {
+            Some(watcher) => watcher.poll(),
+            None => return,
+        };
+
+        match result {
+            Some(Ok(theme)) => self.switch_theme(theme, render_context),
+            Some(Err(error)) => crate::shell::CONSOLE
+                .log(format!("Warning: theme could not be reloaded: {}", error)),
+            None => {}
+        }
+    }
+
+    // Switches to the theme of the preferred color scheme if the color scheme has changed.
+    fn check_color_scheme(&mut self, render_context: &mut render::RenderContext2D) {
+        let (color_scheme, theme) = {
+            let mut registry = self.ctx.registry.borrow_mut();
+
+            let themes = match registry.try_get_mut::<ColorSchemeThemes>("color_scheme") {
+                Some(themes) if themes.is_enabled() => themes,
+                _ => return,
+            };
+
+            themes.poll();
+            let color_scheme = themes.color_scheme();
+
+            // the window is created with the theme of the current color scheme
+            match self.color_scheme.replace(color_scheme) {
+                Some(current) if current != color_scheme => {}
+                _ => return,
+            }
+
+            (color_scheme, themes.theme().clone())
+        };
+
+        self.switch_theme(theme, render_context);
+
+        let root = self.root();
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                WindowEvent::ColorSchemeChanged(color_scheme),
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
+    fn switch_theme(&mut self, theme: Theme, render_context: &mut render::RenderContext2D) {
+        let root = self.root();
+        let ecm = self.world.entity_component_manager();
+        let current_theme = ecm
+            .component_store()
+            .get::<Global>("global", root)
+            .unwrap()
+            .theme
+            .clone();
+
+        // the density of the window is kept
+        let theme = theme.with_density(current_theme.density());
+
+        Context::new((root, ecm), &current_theme, &self.ctx, render_context).switch_theme(theme);
+    }
+
+    // Input of the window is blocked while a modal window is open.
+    fn is_blocked(&self) -> bool {
+        self.ctx.modal_windows.get() > 0
+    }
+
+    // Sends a long press event if a mouse button is held down long enough.
+    fn check_long_press(&mut self) {
+        if !self.click_counter.is_long_press_pending() {
+            return;
+        }
+
+        let settings = self.click_settings();
+        let root = self.root();
+
+        // the shell runs the adapter again when the long press duration is reached, see `poll_interval`
+        if let Some((button, position)) = self.click_counter.long_press(&settings, time_stamp()) {
+            self.ctx
+                .event_queue
+                .borrow_mut()
+                .register_event(LongPressEvent { button, position }, root);
+        }
+    }
 }
 
 impl shell::WindowAdapter for WindowAdapter {
+    fn scale_factor_changed(&mut self, scale_factor: f64) {
+        if (self.ctx.scale_factor.get() - scale_factor).abs() < f64::EPSILON {
+            return;
+        }
+
+        self.ctx.scale_factor.set(scale_factor);
+
+        let root = self.root();
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event_with_strategy(
+                WindowEvent::ScaleFactorChanged(scale_factor),
+                EventStrategy::Direct,
+                root,
+            );
+    }
+
     fn resize(&mut self, width: f64, height: f64) {
         let root = self.root();
         self.ctx
@@ -56,7 +265,13 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn mouse(&mut self, x: f64, y: f64) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
+        let settings = self.click_settings();
+        self.click_counter.mouse_move(&settings, Point::new(x, y));
         self.ctx.mouse_position.set(Point::new(x, y));
         self.ctx.event_queue.borrow_mut().register_event(
             MouseMoveEvent {
@@ -66,7 +281,62 @@ impl shell::WindowAdapter for WindowAdapter {
         )
     }
 
+    fn touch_event(&mut self, event: shell::TouchEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
+        let root = self.root();
+        let settings = self.gesture_settings();
+        let time = time_stamp();
+        let id = event.id;
+        let position = event.position;
+
+        let gestures = match event.phase {
+            shell::TouchPhase::Started => {
+                self.ctx
+                    .event_queue
+                    .borrow_mut()
+                    .register_event(TouchStartEvent { id, position }, root);
+                self.gesture_recognizer.touch_start(id, position, time);
+                vec![]
+            }
+            shell::TouchPhase::Moved => {
+                self.ctx
+                    .event_queue
+                    .borrow_mut()
+                    .register_event(TouchMoveEvent { id, position }, root);
+                self.gesture_recognizer
+                    .touch_move(&settings, id, position, time)
+            }
+            shell::TouchPhase::Ended => {
+                self.ctx
+                    .event_queue
+                    .borrow_mut()
+                    .register_event(TouchEndEvent { id, position }, root);
+                self.gesture_recognizer
+                    .touch_end(&settings, id, position, time)
+            }
+            shell::TouchPhase::Cancelled => {
+                self.ctx
+                    .event_queue
+                    .borrow_mut()
+                    .register_event(TouchCancelEvent { id, position }, root);
+                self.gesture_recognizer.touch_cancel(id);
+                vec![]
+            }
+        };
+
+        for gesture in gestures {
+            self.register_gesture(gesture);
+        }
+    }
+
     fn scroll(&mut self, delta_x: f64, delta_y: f64) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
         self.ctx.event_queue.borrow_mut().register_event(
             ScrollEvent {
@@ -77,13 +347,19 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn mouse_event(&mut self, event: shell::MouseEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
         match event.state {
             shell::ButtonState::Up => {
+                let click_count = self.click_counter.release(event.button);
                 self.ctx.event_queue.borrow_mut().register_event(
                     MouseUpEvent {
                         position: event.position,
                         button: event.button,
+                        click_count,
                     },
                     root,
                 );
@@ -91,17 +367,25 @@ impl shell::WindowAdapter for WindowAdapter {
                     GlobalMouseUpEvent {
                         position: event.position,
                         button: event.button,
+                        click_count,
                     },
                     root,
                 );
             }
-            shell::ButtonState::Down => self.ctx.event_queue.borrow_mut().register_event(
-                MouseDownEvent {
-                    position: event.position,
-                    button: event.button,
-                },
-                root,
-            ),
+            shell::ButtonState::Down => {
+                let settings = self.click_settings();
+                let click_count =
+                    self.click_counter
+                        .press(&settings, event.button, event.position, time_stamp());
+                self.ctx.event_queue.borrow_mut().register_event(
+                    MouseDownEvent {
+                        position: event.position,
+                        button: event.button,
+                        click_count,
+                    },
+                    root,
+                )
+            }
         }
     }
 
@@ -109,7 +393,15 @@ impl shell::WindowAdapter for WindowAdapter {
         self.ctx.mouse_position.get()
     }
 
+    fn moved(&mut self, x: f64, y: f64) {
+        self.ctx.window_position.set(Point::new(x, y));
+    }
+
     fn key_event(&mut self, event: shell::KeyEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
         let root = self.root();
         match event.state {
             shell::ButtonState::Up => self
@@ -125,9 +417,69 @@ impl shell::WindowAdapter for WindowAdapter {
         }
     }
 
+    fn ime_event(&mut self, event: shell::ImeEvent) {
+        if self.is_blocked() {
+            return;
+        }
+
+        let root = self.root();
+        self.ctx
+            .event_queue
+            .borrow_mut()
+            .register_event(TextInputEvent { event }, root);
+    }
+
+    fn file_hovered(&mut self, paths: Vec<PathBuf>, position: Point) {
+        if self.is_blocked() {
+            return;
+        }
+
+        let root = self.root();
+        register_file_hover(
+            &mut self.ctx.event_queue.borrow_mut(),
+            paths,
+            position,
+            root,
+        );
+    }
+
+    fn file_hover_cancelled(&mut self) {
+        let root = self.root();
+        register_file_hover_cancelled(&mut self.ctx.event_queue.borrow_mut(), root);
+    }
+
+    fn file_dropped(&mut self, paths: Vec<PathBuf>, position: Point) {
+        if self.is_blocked() {
+            return;
+        }
+
+        let root = self.root();
+        self.ctx.mouse_position.set(position);
+        register_file_drop(
+            &mut self.ctx.event_queue.borrow_mut(),
+            paths,
+            position,
+            root,
+        );
+    }
+
     fn quit_event(&mut self) {
         let root = self.root();
 
+        if let Ok(id) = self
+            .world
+            .entity_component_manager()
+            .component_store()
+            .get::<String>("id", root)
+        {
+            self.ctx.message_bus.unregister_window(id);
+        }
+
+        // a modal window that is closed by the user has no result
+        if let Some(parent) = self.ctx.modal_parent.borrow_mut().take() {
+            parent.finish(None);
+        }
+
         self.ctx
             .event_queue
             .borrow_mut()
@@ -148,8 +500,43 @@ impl shell::WindowAdapter for WindowAdapter {
     }
 
     fn run(&mut self, render_context: &mut render::RenderContext2D) {
+        self.check_long_press();
+        self.check_kinetic_pan();
+        self.check_animation_frames();
+        self.check_theme_watcher(render_context);
+        self.check_color_scheme(render_context);
         self.world.run_with_context(render_context);
     }
+
+    fn color_scheme_changed(&mut self, color_scheme: shell::ColorScheme) {
+        if let Some(themes) = self
+            .ctx
+            .registry
+            .borrow_mut()
+            .try_get_mut::<ColorSchemeThemes>("color_scheme")
+        {
+            themes.system_changed(color_scheme);
+        }
+    }
+
+    fn poll_interval(&self) -> Option<Duration> {
+        // wakes up the window when a pending long press is reached
+        if let Some(remaining) = self.click_counter.long_press_remaining(time_stamp()) {
+            return Some(Duration::from_secs_f64(remaining / 1000.0));
+        }
+
+        if self.ctx.theme_watcher.borrow().is_some() {
+            return Some(THEME_POLL_INTERVAL);
+        }
+
+        // changes of the color scheme are reported by the shell, the desktop is only polled if the application
+        // opts in
+        self.ctx.registry.try_borrow().ok().and_then(|registry| {
+            registry
+                .try_get::<ColorSchemeThemes>("color_scheme")
+                .and_then(ColorSchemeThemes::poll_interval)
+        })
+    }
 }
 
 /// Creates a `WindowAdapter` and a `WindowSettings` object from a window builder closure.
@@ -157,6 +544,9 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
     app_name: impl Into<String>,
     theme: Theme,
     request_sender: mpsc::Sender<ShellRequest<WindowAdapter>>,
+    registry: Rc<RefCell<Registry>>,
+    message_bus: MessageBus,
+    modal_parent: Option<ModalParent>,
     create_fn: F,
 ) -> (WindowAdapter, WindowSettings, mpsc::Receiver<WindowRequest>) {
     let app_name = app_name.into();
@@ -165,19 +555,13 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
 
     let (sender, receiver) = mpsc::channel();
 
-    let registry = Rc::new(RefCell::new(Registry::new()));
-
-    if app_name.is_empty() {
-        registry
-            .borrow_mut()
-            .register("settings", Settings::default());
-    } else {
-        registry
-            .borrow_mut()
-            .register("settings", Settings::new(app_name.clone()));
-    };
-
-    let context_provider = ContextProvider::new(sender, request_sender, app_name);
+    let context_provider = ContextProvider::new(
+        sender.clone(),
+        request_sender,
+        app_name,
+        registry.clone(),
+        message_bus.clone(),
+    );
 
     let window = {
         let overlay = Overlay::new().build(&mut BuildContext::new(
@@ -239,7 +623,7 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         crate::theme::fonts::MATERIAL_ICONS_FONT,
     );
 
-    let settings = WindowSettings {
+    let mut settings = WindowSettings {
         title: world
             .entity_component_manager()
             .component_store()
@@ -264,8 +648,33 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         position: (position.x(), position.y()),
         size: (constraint.width(), constraint.height()),
         fonts,
+        scale_factor: None,
     };
 
+    // modal windows are centered on their parent
+    if let Some(parent) = modal_parent {
+        settings.position = center_on_parent(parent.bounds(), settings.size);
+        *context_provider.modal_parent.borrow_mut() = Some(parent);
+    }
+
+    context_provider
+        .window_position
+        .set(Point::new(settings.position.0, settings.position.1));
+
+    // windows with an id could be reached by other windows over the message bus
+    if let Ok(id) = world
+        .entity_component_manager()
+        .component_store()
+        .get::<String>("id", window)
+    {
+        message_bus.register_window(
+            id.clone(),
+            context_provider.event_queue.clone(),
+            window,
+            sender,
+        );
+    }
+
     let mut global = Global::default();
     global.theme = theme;
 
@@ -298,21 +707,26 @@ pub fn create_window<F: Fn(&mut BuildContext) -> Entity + 'static>(
         .build();
 
     world
-        .create_system(LayoutSystem::new(context_provider.clone()))
+        .create_system(AnimationSystem::new(context_provider.clone()))
         .with_priority(1)
         .build();
 
+    world
+        .create_system(LayoutSystem::new(context_provider.clone()))
+        .with_priority(2)
+        .build();
+
     world
         .create_system(PostLayoutStateSystem::new(
             context_provider.clone(),
             registry,
         ))
-        .with_priority(2)
+        .with_priority(3)
         .build();
 
     world
         .create_system(RenderSystem::new(context_provider.clone()))
-        .with_priority(3)
+        .with_priority(4)
         .build();
 
     (
@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+/// `AnimationCompletedEvent` occurs when all tracks of a storyboard are finished. It is sent direct to the widget
+/// that has started the storyboard.
+#[derive(Event)]
+pub struct AnimationCompletedEvent {
+    /// The id of the storyboard.
+    pub id: u32,
+}
+
+/// Defines the animation completed handler function.
+pub type AnimationCompletedHandlerFunction = dyn Fn(&mut StatesContext) + 'static;
+
+/// Used to handle the completion of a storyboard. It is inserted by `Context::start_storyboard` and removed after the
+/// storyboard is completed or stopped.
+#[derive(IntoHandler)]
+pub struct AnimationCompletedEventHandler {
+    pub id: u32,
+    pub handler: Rc<AnimationCompletedHandlerFunction>,
+}
+
+impl EventHandler for AnimationCompletedEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<AnimationCompletedEvent>() {
+            if event.id == self.id {
+                (self.handler)(state_context);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<AnimationCompletedEvent>()
+            .map_or(false, |event| event.id == self.id)
+    }
+}
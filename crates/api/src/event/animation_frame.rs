@@ -0,0 +1,117 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+/// Describes the time of an animation frame.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AnimationFrame {
+    /// The time stamp of the frame in milliseconds.
+    pub time_stamp: f64,
+
+    /// The elapsed time since the last frame in milliseconds. Is `0.0` on the first frame after the window was idle.
+    pub delta: f64,
+}
+
+/// `AnimationFrameEvent` occurs on each frame a widget has requested with `Context::request_animation_frame`. It is
+/// sent direct to the widget.
+#[derive(Event)]
+pub struct AnimationFrameEvent {
+    /// The time of the frame.
+    pub frame: AnimationFrame,
+}
+
+/// Defines the animation frame handler function.
+pub type AnimationFrameHandlerFunction = dyn Fn(&mut StatesContext, AnimationFrame) + 'static;
+
+/// Used to handle an animation frame. It is inserted by `Context::request_animation_frame` and removed after the
+/// frame is handled.
+#[derive(IntoHandler)]
+pub struct AnimationFrameEventHandler {
+    pub handler: Rc<AnimationFrameHandlerFunction>,
+}
+
+impl EventHandler for AnimationFrameEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<AnimationFrameEvent>() {
+            (self.handler)(state_context, event.frame);
+        }
+
+        // all requests of the widget are handled by the same frame
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<AnimationFrameEvent>()
+    }
+}
+
+/// Calculates the time stamps and deltas of consecutive animation frames.
+#[derive(Clone, Debug, Default)]
+pub struct FrameClock {
+    last_time_stamp: Option<f64>,
+}
+
+impl FrameClock {
+    /// Creates a new frame clock.
+    pub fn new() -> Self {
+        FrameClock::default()
+    }
+
+    /// Returns the frame of the given time stamp.
+    pub fn next(&mut self, time_stamp: f64) -> AnimationFrame {
+        let delta = self
+            .last_time_stamp
+            .replace(time_stamp)
+            .map_or(0.0, |last| (time_stamp - last).max(0.0));
+
+        AnimationFrame { time_stamp, delta }
+    }
+
+    /// Stops the clock if no more frames are requested. The delta of the next frame starts again at `0.0`.
+    pub fn stop(&mut self) {
+        self.last_time_stamp = None;
+    }
+
+    /// Checks if the clock is running.
+    pub fn is_running(&self) -> bool {
+        self.last_time_stamp.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_clock() {
+        let mut clock = FrameClock::new();
+        assert!(!clock.is_running());
+
+        assert_eq!(
+            clock.next(100.0),
+            AnimationFrame {
+                time_stamp: 100.0,
+                delta: 0.0
+            }
+        );
+        assert!(clock.is_running());
+
+        assert_eq!(
+            clock.next(116.0),
+            AnimationFrame {
+                time_stamp: 116.0,
+                delta: 16.0
+            }
+        );
+
+        // time stamps that go backwards have no negative delta
+        assert_eq!(clock.next(110.0).delta, 0.0);
+
+        clock.stop();
+        assert!(!clock.is_running());
+        assert_eq!(clock.next(500.0).delta, 0.0);
+    }
+}
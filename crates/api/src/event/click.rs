@@ -0,0 +1,230 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{shell::MouseButton, utils::Point};
+
+/// Returns the current time stamp in milliseconds.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn time_stamp() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |d| d.as_secs_f64() * 1000.0)
+}
+
+/// Returns the current time stamp in milliseconds.
+#[cfg(target_arch = "wasm32")]
+pub fn time_stamp() -> f64 {
+    stdweb::web::Date::now()
+}
+
+/// Describes how mouse presses are combined to multi clicks and when a press becomes a long press.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClickSettings {
+    /// Maximum time in milliseconds between two presses that are counted as one multi click.
+    pub interval: f64,
+
+    /// Maximum distance in pixels between two presses that are counted as one multi click.
+    pub distance: f64,
+
+    /// Time in milliseconds a button must be held down (without moving) to trigger a long press.
+    pub long_press_duration: f64,
+}
+
+impl Default for ClickSettings {
+    fn default() -> Self {
+        ClickSettings {
+            interval: 500.0,
+            distance: 4.0,
+            long_press_duration: 800.0,
+        }
+    }
+}
+
+/// Counts consecutive mouse presses and detects long presses.
+#[derive(Clone, Debug, Default)]
+pub struct ClickCounter {
+    button: Option<MouseButton>,
+    position: Point,
+    time: f64,
+    count: u32,
+    pressed: bool,
+    long_press: bool,
+    long_press_duration: f64,
+}
+
+impl ClickCounter {
+    /// Creates a new click counter.
+    pub fn new() -> Self {
+        ClickCounter::default()
+    }
+
+    /// Registers a button press and returns the current click count (1 for a single click, 2 for a double click, ...).
+    pub fn press(
+        &mut self,
+        settings: &ClickSettings,
+        button: MouseButton,
+        position: Point,
+        time: f64,
+    ) -> u32 {
+        if self.button == Some(button)
+            && time - self.time <= settings.interval
+            && self.position.distance(position) <= settings.distance
+        {
+            self.count += 1;
+        } else {
+            self.count = 1;
+        }
+
+        self.button = Some(button);
+        self.position = position;
+        self.time = time;
+        self.pressed = true;
+        self.long_press = true;
+        self.long_press_duration = settings.long_press_duration;
+
+        self.count
+    }
+
+    /// Registers a button release and returns the click count of the corresponding press.
+    pub fn release(&mut self, button: MouseButton) -> u32 {
+        if self.button != Some(button) {
+            return 1;
+        }
+
+        self.pressed = false;
+        self.long_press = false;
+
+        self.count.max(1)
+    }
+
+    /// Registers a mouse move. A move outside of the click distance resets the click count and cancels the long press.
+    pub fn mouse_move(&mut self, settings: &ClickSettings, position: Point) {
+        if self.position.distance(position) > settings.distance {
+            self.long_press = false;
+
+            if !self.pressed {
+                self.count = 0;
+            }
+        }
+    }
+
+    /// Checks if a long press is still pending.
+    pub fn is_long_press_pending(&self) -> bool {
+        self.long_press
+    }
+
+    /// Returns the time in milliseconds until the pending long press is reached, `None` if no long press is pending.
+    pub fn long_press_remaining(&self, time: f64) -> Option<f64> {
+        if !self.long_press {
+            return None;
+        }
+
+        Some((self.time + self.long_press_duration - time).max(0.0))
+    }
+
+    /// Returns the button and the position of the long press if the button is held down long enough. The long
+    /// press is only reported once.
+    pub fn long_press(
+        &mut self,
+        settings: &ClickSettings,
+        time: f64,
+    ) -> Option<(MouseButton, Point)> {
+        if !self.long_press || time - self.time < settings.long_press_duration {
+            return None;
+        }
+
+        self.long_press = false;
+        self.count = 0;
+        self.button.map(|button| (button, self.position))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_press() {
+        let settings = ClickSettings::default();
+        let mut counter = ClickCounter::new();
+
+        assert_eq!(
+            counter.press(&settings, MouseButton::Left, Point::new(10.0, 10.0), 0.0),
+            1
+        );
+        assert_eq!(counter.release(MouseButton::Left), 1);
+        assert_eq!(
+            counter.press(&settings, MouseButton::Left, Point::new(11.0, 10.0), 200.0),
+            2
+        );
+        assert_eq!(counter.release(MouseButton::Left), 2);
+        assert_eq!(
+            counter.press(&settings, MouseButton::Left, Point::new(11.0, 11.0), 400.0),
+            3
+        );
+
+        // too slow
+        assert_eq!(
+            counter.press(&settings, MouseButton::Left, Point::new(11.0, 11.0), 1000.0),
+            1
+        );
+
+        // too far away
+        assert_eq!(
+            counter.press(&settings, MouseButton::Left, Point::new(50.0, 11.0), 1100.0),
+            1
+        );
+
+        // other button
+        assert_eq!(
+            counter.press(
+                &settings,
+                MouseButton::Right,
+                Point::new(50.0, 11.0),
+                1200.0
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fn test_mouse_move() {
+        let settings = ClickSettings::default();
+        let mut counter = ClickCounter::new();
+
+        counter.press(&settings, MouseButton::Left, Point::new(10.0, 10.0), 0.0);
+        counter.release(MouseButton::Left);
+        counter.mouse_move(&settings, Point::new(40.0, 10.0));
+
+        assert_eq!(
+            counter.press(&settings, MouseButton::Left, Point::new(10.0, 10.0), 100.0),
+            1
+        );
+    }
+
+    #[test]
+    fn test_long_press() {
+        let settings = ClickSettings::default();
+        let mut counter = ClickCounter::new();
+
+        counter.press(&settings, MouseButton::Left, Point::new(10.0, 10.0), 0.0);
+        assert!(counter.is_long_press_pending());
+        assert_eq!(counter.long_press_remaining(100.0), Some(700.0));
+        assert_eq!(counter.long_press_remaining(900.0), Some(0.0));
+        assert_eq!(counter.long_press(&settings, 100.0), None);
+        assert_eq!(
+            counter.long_press(&settings, 800.0),
+            Some((MouseButton::Left, Point::new(10.0, 10.0)))
+        );
+        assert_eq!(counter.long_press(&settings, 900.0), None);
+        assert_eq!(counter.long_press_remaining(900.0), None);
+
+        counter.press(&settings, MouseButton::Left, Point::new(10.0, 10.0), 1000.0);
+        counter.mouse_move(&settings, Point::new(30.0, 10.0));
+        assert!(!counter.is_long_press_pending());
+
+        counter.press(&settings, MouseButton::Left, Point::new(10.0, 10.0), 2000.0);
+        counter.release(MouseButton::Left);
+        assert_eq!(counter.long_press(&settings, 3000.0), None);
+    }
+}
@@ -0,0 +1,477 @@
+use std::{any::Any, fmt, path::PathBuf, rc::Rc};
+
+use dces::prelude::Entity;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// Offset of the drag image to the mouse position. The image is not placed directly under the mouse to keep
+/// the drop targets visible.
+pub const DRAG_IMAGE_OFFSET: f64 = 8.0;
+
+/// Contains the payload of a drag and drop operation. The payload could be of any type.
+#[derive(Clone)]
+pub struct DragData {
+    data: Rc<dyn Any>,
+}
+
+impl DragData {
+    /// Creates a new drag data object from the given payload.
+    pub fn new<T: Any>(data: T) -> Self {
+        DragData {
+            data: Rc::new(data),
+        }
+    }
+
+    /// Returns a reference of the payload if it is of the given type.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.data.downcast_ref::<T>()
+    }
+
+    /// Checks if the payload is of the given type.
+    pub fn is<T: Any>(&self) -> bool {
+        self.data.is::<T>()
+    }
+}
+
+impl fmt::Debug for DragData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "DragData")
+    }
+}
+
+/// Describes a running drag and drop operation.
+#[derive(Clone, Debug)]
+pub struct DragSession {
+    /// The widget that started the operation.
+    pub source: Entity,
+
+    /// The payload of the operation.
+    pub data: DragData,
+
+    /// The drag image that is displayed on the overlay.
+    pub image: Option<Entity>,
+
+    /// The drop target under the mouse.
+    pub target: Option<Entity>,
+
+    /// Indicates if the current target accepts the payload.
+    pub accepted: bool,
+}
+
+impl DragSession {
+    /// Creates a new drag session.
+    pub fn new(source: Entity, data: DragData, image: Option<Entity>) -> Self {
+        DragSession {
+            source,
+            data,
+            image,
+            target: None,
+            accepted: false,
+        }
+    }
+}
+
+/// `DragEnterEvent` occurs when a dragged payload enters a drop target.
+#[derive(Event)]
+pub struct DragEnterEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+
+    /// The dragged payload.
+    pub data: DragData,
+}
+
+/// `DragOverEvent` occurs when a dragged payload is moved over a drop target.
+#[derive(Event)]
+pub struct DragOverEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+
+    /// The dragged payload.
+    pub data: DragData,
+}
+
+/// `DragLeaveEvent` occurs when a dragged payload leaves a drop target.
+#[derive(Event)]
+pub struct DragLeaveEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `DropEvent` occurs when a dragged payload is dropped on a drop target that accepted it.
+#[derive(Event)]
+pub struct DropEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+
+    /// The dropped payload.
+    pub data: DragData,
+}
+
+/// `DragEndEvent` is sent to the source of a drag and drop operation after the operation is finished.
+#[derive(Event)]
+pub struct DragEndEvent {
+    /// Indicates if the payload was dropped on a target.
+    pub dropped: bool,
+}
+
+/// `FileHoverEvent` occurs when files are dragged from outside of the application over a widget.
+#[derive(Event)]
+pub struct FileHoverEvent {
+    /// The paths of the hovered files.
+    pub paths: Vec<PathBuf>,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `FileHoverCancelledEvent` occurs when dragged files leave the window without being dropped.
+#[derive(Event)]
+pub struct FileHoverCancelledEvent;
+
+/// `DropFileEvent` occurs when files are dropped from outside of the application on a widget.
+#[derive(Event)]
+pub struct DropFileEvent {
+    /// The paths of the dropped files.
+    pub paths: Vec<PathBuf>,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// Registers the events of files that are dragged over the window. It is used by the window adapter and could
+/// also be used to simulate native file drag and drop without a shell.
+pub fn register_file_hover(
+    event_queue: &mut EventQueue,
+    paths: Vec<PathBuf>,
+    position: Point,
+    root: Entity,
+) {
+    event_queue.register_event(FileHoverEvent { paths, position }, root);
+}
+
+/// Registers the event of dragged files that leave the window. The event is send to all widgets from root to leaf.
+pub fn register_file_hover_cancelled(event_queue: &mut EventQueue, root: Entity) {
+    event_queue.register_event_with_strategy(FileHoverCancelledEvent, EventStrategy::TopDown, root);
+}
+
+/// Registers the event of files that are dropped on the window. It is used by the window adapter and could also
+/// be used to simulate native file drag and drop without a shell.
+pub fn register_file_drop(
+    event_queue: &mut EventQueue,
+    paths: Vec<PathBuf>,
+    position: Point,
+    root: Entity,
+) {
+    event_queue.register_event(DropFileEvent { paths, position }, root);
+}
+
+/// Defines the drag handler function. Returns `true` if the payload is accepted.
+pub type DragHandlerFunction = dyn Fn(&mut StatesContext, &DragData, Point) -> bool + 'static;
+
+/// Defines the drag end handler function.
+pub type DragEndHandlerFunction = dyn Fn(&mut StatesContext, bool) + 'static;
+
+/// Used to handle drag enter events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragEnterEventHandler {
+    handler: Rc<DragHandlerFunction>,
+}
+
+impl EventHandler for DragEnterEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragEnterEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.data, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragEnterEvent>()
+    }
+}
+
+/// Used to handle drag over events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragOverEventHandler {
+    handler: Rc<DragHandlerFunction>,
+}
+
+impl EventHandler for DragOverEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragOverEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.data, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragOverEvent>()
+    }
+}
+
+/// Used to handle drag leave events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragLeaveEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for DragLeaveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragLeaveEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragLeaveEvent>()
+    }
+}
+
+/// Used to handle drop events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DropEventHandler {
+    handler: Rc<DragHandlerFunction>,
+}
+
+impl EventHandler for DropEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DropEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.data, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DropEvent>()
+    }
+}
+
+/// Used to handle drag end events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DragEndEventHandler {
+    handler: Rc<DragEndHandlerFunction>,
+}
+
+impl EventHandler for DragEndEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DragEndEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.dropped);
+                false
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DragEndEvent>()
+    }
+}
+
+/// Defines the file handler function.
+pub type FileHandlerFunction = dyn Fn(&mut StatesContext, &[PathBuf], Point) -> bool + 'static;
+
+/// Used to handle file hover events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct FileHoverEventHandler {
+    handler: Rc<FileHandlerFunction>,
+}
+
+impl EventHandler for FileHoverEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<FileHoverEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.paths, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<FileHoverEvent>()
+    }
+}
+
+/// Used to handle file hover cancelled events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct FileHoverCancelledEventHandler {
+    handler: Rc<TriggerHandler>,
+}
+
+impl EventHandler for FileHoverCancelledEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        (self.handler)(state_context, event.source);
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<FileHoverCancelledEvent>()
+    }
+}
+
+/// Used to handle drop file events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DropFileEventHandler {
+    handler: Rc<FileHandlerFunction>,
+}
+
+impl EventHandler for DropFileEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<DropFileEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, &event.paths, event.position)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<DropFileEvent>()
+    }
+}
+
+/// Adds drag and drop handling to a widget. A widget with a drop handler is a drop target. Without drag enter and
+/// drag over handlers it accepts all payloads.
+pub trait DragDropHandler: Sized + Widget {
+    /// Inserts a drag enter handler. Return `true` to accept the payload.
+    fn on_drag_enter<H: Fn(&mut StatesContext, &DragData, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a drag over handler. Return `true` to accept the payload at the given position.
+    fn on_drag_over<H: Fn(&mut StatesContext, &DragData, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DragOverEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a drag leave handler.
+    fn on_drag_leave<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a drop handler. Return `true` if the payload is dropped successfully.
+    fn on_drop<H: Fn(&mut StatesContext, &DragData, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DropEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called on the drag source after the operation is finished.
+    fn on_drag_end<H: Fn(&mut StatesContext, bool) + 'static>(self, handler: H) -> Self {
+        self.insert_handler(DragEndEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called if files are dragged from outside over the widget. Return `true` to
+    /// stop the event from bubbling up.
+    fn on_file_hover<H: Fn(&mut StatesContext, &[PathBuf], Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(FileHoverEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called if dragged files leave the window without being dropped.
+    fn on_file_hover_cancelled<H: Fn(&mut StatesContext, Entity) + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(FileHoverCancelledEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a handler that is called if files are dropped from outside on the widget. Return `true` to stop
+    /// the event from bubbling up.
+    fn on_drop_file<H: Fn(&mut StatesContext, &[PathBuf], Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DropFileEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drag_data() {
+        let data = DragData::new(String::from("item"));
+
+        assert!(data.is::<String>());
+        assert!(!data.is::<usize>());
+        assert_eq!(data.get::<String>(), Some(&String::from("item")));
+        assert_eq!(data.get::<usize>(), None);
+    }
+
+    #[test]
+    fn test_register_file_drop() {
+        let mut event_queue = EventQueue::new();
+        let root = Entity(0);
+
+        register_file_hover(
+            &mut event_queue,
+            vec![PathBuf::from("image.png")],
+            Point::new(10.0, 20.0),
+            root,
+        );
+        register_file_hover_cancelled(&mut event_queue, root);
+        register_file_drop(
+            &mut event_queue,
+            vec![PathBuf::from("image.png"), PathBuf::from("text.txt")],
+            Point::new(10.0, 20.0),
+            root,
+        );
+
+        assert_eq!(event_queue.len(), 3);
+
+        let hover = event_queue.dequeue().unwrap();
+        assert_eq!(hover.strategy, EventStrategy::BottomUp);
+        assert_eq!(
+            hover.downcast_ref::<FileHoverEvent>().unwrap().paths,
+            vec![PathBuf::from("image.png")]
+        );
+
+        let cancelled = event_queue.dequeue().unwrap();
+        assert!(cancelled.is_type::<FileHoverCancelledEvent>());
+        assert_eq!(cancelled.strategy, EventStrategy::TopDown);
+
+        let drop = event_queue.dequeue().unwrap();
+        let event = drop.downcast_ref::<DropFileEvent>().unwrap();
+        assert_eq!(event.paths.len(), 2);
+        assert_eq!(event.position, Point::new(10.0, 20.0));
+        assert_eq!(drop.source, root);
+    }
+}
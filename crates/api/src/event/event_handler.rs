@@ -1,4 +1,6 @@
-use crate::{event::EventBox, widget_base::StatesContext};
+use std::rc::Rc;
+
+use crate::{event::EventBox, proc_macros::IntoHandler, widget_base::StatesContext};
 
 /// This trait is used to define an event handler.
 pub trait EventHandler {
@@ -7,4 +9,39 @@ pub trait EventHandler {
 
     /// Check if the handler could handle the given event box.
     fn handles_event(&self, event: &EventBox) -> bool;
+
+    /// Returns `true` if the handler is called in the preview (tunneling) phase. Preview handlers are called from
+    /// the root to the leaf before the event bubbles up.
+    fn is_preview(&self) -> bool {
+        false
+    }
+}
+
+/// Wraps an event handler to call it in the preview (tunneling) phase of an event.
+#[derive(IntoHandler)]
+pub struct PreviewEventHandler {
+    handler: Rc<dyn EventHandler>,
+}
+
+impl PreviewEventHandler {
+    /// Creates a new preview handler from the given handler.
+    pub fn new(handler: impl Into<Rc<dyn EventHandler>>) -> Self {
+        PreviewEventHandler {
+            handler: handler.into(),
+        }
+    }
+}
+
+impl EventHandler for PreviewEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        self.handler.handle_event(state_context, event)
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        self.handler.handles_event(event)
+    }
+
+    fn is_preview(&self) -> bool {
+        true
+    }
 }
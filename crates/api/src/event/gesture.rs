@@ -0,0 +1,387 @@
+use crate::utils::Point;
+
+/// Describes how touch points are recognized as gestures.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct GestureSettings {
+    /// Maximum distance in pixels a finger could move to be recognized as tap.
+    pub tap_distance: f64,
+
+    /// Maximum time in milliseconds a finger could touch the screen to be recognized as tap.
+    pub tap_duration: f64,
+
+    /// Minimum velocity in pixels per millisecond of a lifted finger to be recognized as swipe.
+    pub swipe_velocity: f64,
+
+    /// Deceleration in pixels per square millisecond of the kinetic panning after a swipe.
+    pub kinetic_deceleration: f64,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        GestureSettings {
+            tap_distance: 10.0,
+            tap_duration: 300.0,
+            swipe_velocity: 0.5,
+            kinetic_deceleration: 0.002,
+        }
+    }
+}
+
+/// Represents a recognized gesture.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Gesture {
+    /// A short touch without moving.
+    Tap { position: Point },
+
+    /// A finger is moved on the screen, also used for kinetic panning after a swipe.
+    Pan { position: Point, delta: Point },
+
+    /// Two fingers are moved towards or away from each other. `scale` is relative to the last pinch gesture.
+    Pinch { center: Point, scale: f64 },
+
+    /// A finger is lifted while it moves fast. `velocity` is measured in pixels per millisecond.
+    Swipe { position: Point, velocity: Point },
+}
+
+#[derive(Copy, Clone, Debug)]
+struct TouchPoint {
+    id: u64,
+    start_position: Point,
+    start_time: f64,
+    position: Point,
+    time: f64,
+    velocity: Point,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct KineticPan {
+    position: Point,
+    velocity: Point,
+    time: f64,
+}
+
+/// Recognizes taps, pans, pinches and swipes from touch points and calculates the kinetic panning after a swipe.
+#[derive(Clone, Debug, Default)]
+pub struct GestureRecognizer {
+    touches: Vec<TouchPoint>,
+    panning: bool,
+    multi_touch: bool,
+    pinch_distance: Option<f64>,
+    kinetic_pan: Option<KineticPan>,
+}
+
+impl GestureRecognizer {
+    /// Creates a new gesture recognizer.
+    pub fn new() -> Self {
+        GestureRecognizer::default()
+    }
+
+    /// Registers a new touch point. A new touch stops the kinetic panning.
+    pub fn touch_start(&mut self, id: u64, position: Point, time: f64) {
+        self.kinetic_pan = None;
+        self.touches.retain(|t| t.id != id);
+        self.touches.push(TouchPoint {
+            id,
+            start_position: position,
+            start_time: time,
+            position,
+            time,
+            velocity: Point::default(),
+        });
+
+        if self.touches.len() > 1 {
+            self.multi_touch = true;
+        }
+
+        if self.touches.len() == 2 {
+            self.pinch_distance = Some(self.touches[0].position.distance(self.touches[1].position));
+        }
+    }
+
+    /// Registers the move of a touch point and returns the recognized pan or pinch gesture.
+    pub fn touch_move(
+        &mut self,
+        settings: &GestureSettings,
+        id: u64,
+        position: Point,
+        time: f64,
+    ) -> Vec<Gesture> {
+        let touch = match self.touches.iter_mut().find(|t| t.id == id) {
+            Some(touch) => touch,
+            None => return vec![],
+        };
+
+        let delta = position - touch.position;
+        let elapsed = time - touch.time;
+
+        if elapsed > 0.0 {
+            // smooths the velocity to be more robust against single outliers
+            touch.velocity = Point::new(
+                0.8 * delta.x() / elapsed + 0.2 * touch.velocity.x(),
+                0.8 * delta.y() / elapsed + 0.2 * touch.velocity.y(),
+            );
+        }
+
+        touch.position = position;
+        touch.time = time;
+        let start_position = touch.start_position;
+
+        if self.touches.len() == 2 {
+            let distance = self.touches[0].position.distance(self.touches[1].position);
+            let center = Point::new(
+                (self.touches[0].position.x() + self.touches[1].position.x()) / 2.0,
+                (self.touches[0].position.y() + self.touches[1].position.y()) / 2.0,
+            );
+            let last_distance = self.pinch_distance.replace(distance).unwrap_or(distance);
+
+            if last_distance > 0.0 && (distance - last_distance).abs() > f64::EPSILON {
+                return vec![Gesture::Pinch {
+                    center,
+                    scale: distance / last_distance,
+                }];
+            }
+
+            return vec![];
+        }
+
+        if self.touches.len() > 2 {
+            return vec![];
+        }
+
+        if !self.panning && start_position.distance(position) > settings.tap_distance {
+            self.panning = true;
+
+            return vec![Gesture::Pan {
+                position,
+                delta: position - start_position,
+            }];
+        }
+
+        if self.panning {
+            return vec![Gesture::Pan { position, delta }];
+        }
+
+        vec![]
+    }
+
+    /// Registers a lifted touch point and returns the recognized tap or swipe gesture. A swipe starts the kinetic
+    /// panning.
+    pub fn touch_end(
+        &mut self,
+        settings: &GestureSettings,
+        id: u64,
+        position: Point,
+        time: f64,
+    ) -> Vec<Gesture> {
+        let index = match self.touches.iter().position(|t| t.id == id) {
+            Some(index) => index,
+            None => return vec![],
+        };
+
+        let touch = self.touches.remove(index);
+        let mut gestures = vec![];
+
+        // a remaining finger continues panning from its current position after a pinch
+        if self.touches.len() < 2 && self.pinch_distance.take().is_some() {
+            for touch in &mut self.touches {
+                touch.start_position = touch.position;
+            }
+        }
+
+        // taps and swipes are only recognized for single finger gestures
+        if self.touches.is_empty() && !self.multi_touch {
+            let velocity = touch.velocity;
+            let speed = Point::default().distance(velocity);
+
+            if !self.panning
+                && time - touch.start_time <= settings.tap_duration
+                && touch.start_position.distance(position) <= settings.tap_distance
+            {
+                gestures.push(Gesture::Tap { position });
+            } else if self.panning && speed >= settings.swipe_velocity {
+                gestures.push(Gesture::Swipe { position, velocity });
+                self.kinetic_pan = Some(KineticPan {
+                    position,
+                    velocity,
+                    time,
+                });
+            }
+        }
+
+        if self.touches.is_empty() {
+            self.panning = false;
+            self.multi_touch = false;
+        }
+
+        gestures
+    }
+
+    /// Removes a touch point without recognizing a gesture.
+    pub fn touch_cancel(&mut self, id: u64) {
+        self.touches.retain(|t| t.id != id);
+
+        if self.touches.len() < 2 {
+            self.pinch_distance = None;
+        }
+
+        if self.touches.is_empty() {
+            self.panning = false;
+            self.multi_touch = false;
+        }
+    }
+
+    /// Checks if the kinetic panning after a swipe is running.
+    pub fn is_kinetic_pan_pending(&self) -> bool {
+        self.kinetic_pan.is_some()
+    }
+
+    /// Returns the next step of the kinetic panning. The velocity decreases over time until the panning stops.
+    pub fn kinetic_pan(&mut self, settings: &GestureSettings, time: f64) -> Option<Gesture> {
+        let kinetic_pan = self.kinetic_pan.as_mut()?;
+
+        let elapsed = time - kinetic_pan.time;
+
+        if elapsed <= 0.0 {
+            return None;
+        }
+
+        let speed = Point::default().distance(kinetic_pan.velocity);
+        let new_speed = (speed - settings.kinetic_deceleration * elapsed).max(0.0);
+
+        // moves with the average speed of the elapsed time
+        let factor = (speed + new_speed) / 2.0 / speed * elapsed;
+        let delta = Point::new(
+            kinetic_pan.velocity.x() * factor,
+            kinetic_pan.velocity.y() * factor,
+        );
+        let position = kinetic_pan.position;
+
+        kinetic_pan.velocity = Point::new(
+            kinetic_pan.velocity.x() * new_speed / speed,
+            kinetic_pan.velocity.y() * new_speed / speed,
+        );
+        kinetic_pan.time = time;
+
+        if new_speed <= 0.0 {
+            self.kinetic_pan = None;
+        }
+
+        Some(Gesture::Pan { position, delta })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tap() {
+        let settings = GestureSettings::default();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_start(0, Point::new(10.0, 10.0), 0.0);
+        assert!(recognizer
+            .touch_move(&settings, 0, Point::new(12.0, 10.0), 50.0)
+            .is_empty());
+        assert_eq!(
+            recognizer.touch_end(&settings, 0, Point::new(12.0, 10.0), 100.0),
+            vec![Gesture::Tap {
+                position: Point::new(12.0, 10.0)
+            }]
+        );
+
+        // too long
+        recognizer.touch_start(1, Point::new(10.0, 10.0), 1000.0);
+        assert!(recognizer
+            .touch_end(&settings, 1, Point::new(10.0, 10.0), 2000.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_pan_and_swipe() {
+        let settings = GestureSettings::default();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_start(0, Point::new(100.0, 100.0), 0.0);
+        assert_eq!(
+            recognizer.touch_move(&settings, 0, Point::new(100.0, 80.0), 10.0),
+            vec![Gesture::Pan {
+                position: Point::new(100.0, 80.0),
+                delta: Point::new(0.0, -20.0)
+            }]
+        );
+        assert_eq!(
+            recognizer.touch_move(&settings, 0, Point::new(100.0, 60.0), 20.0),
+            vec![Gesture::Pan {
+                position: Point::new(100.0, 60.0),
+                delta: Point::new(0.0, -20.0)
+            }]
+        );
+
+        let gestures = recognizer.touch_end(&settings, 0, Point::new(100.0, 60.0), 20.0);
+        assert_eq!(gestures.len(), 1);
+
+        match gestures[0] {
+            Gesture::Swipe { velocity, .. } => assert!(velocity.y() < -1.0),
+            _ => panic!("swipe expected"),
+        }
+
+        assert!(recognizer.is_kinetic_pan_pending());
+
+        match recognizer.kinetic_pan(&settings, 36.0) {
+            Some(Gesture::Pan { delta, .. }) => assert!(delta.y() < 0.0),
+            _ => panic!("kinetic pan expected"),
+        }
+
+        // the kinetic panning stops after some time
+        assert!(recognizer.kinetic_pan(&settings, 10000.0).is_some());
+        assert!(!recognizer.is_kinetic_pan_pending());
+        assert_eq!(recognizer.kinetic_pan(&settings, 10016.0), None);
+
+        // a new touch stops the kinetic panning
+        recognizer.touch_start(1, Point::new(100.0, 100.0), 20000.0);
+        recognizer.touch_move(&settings, 1, Point::new(100.0, 40.0), 20010.0);
+        recognizer.touch_end(&settings, 1, Point::new(100.0, 40.0), 20010.0);
+        assert!(recognizer.is_kinetic_pan_pending());
+        recognizer.touch_start(2, Point::new(100.0, 100.0), 20020.0);
+        assert!(!recognizer.is_kinetic_pan_pending());
+    }
+
+    #[test]
+    fn test_pinch() {
+        let settings = GestureSettings::default();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_start(0, Point::new(100.0, 100.0), 0.0);
+        recognizer.touch_start(1, Point::new(200.0, 100.0), 0.0);
+
+        assert_eq!(
+            recognizer.touch_move(&settings, 1, Point::new(300.0, 100.0), 10.0),
+            vec![Gesture::Pinch {
+                center: Point::new(200.0, 100.0),
+                scale: 2.0
+            }]
+        );
+
+        // no tap or swipe after a pinch
+        assert!(recognizer
+            .touch_end(&settings, 1, Point::new(300.0, 100.0), 20.0)
+            .is_empty());
+        assert!(recognizer
+            .touch_end(&settings, 0, Point::new(100.0, 100.0), 20.0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_touch_cancel() {
+        let settings = GestureSettings::default();
+        let mut recognizer = GestureRecognizer::new();
+
+        recognizer.touch_start(0, Point::new(10.0, 10.0), 0.0);
+        recognizer.touch_cancel(0);
+
+        assert!(recognizer
+            .touch_end(&settings, 0, Point::new(10.0, 10.0), 50.0)
+            .is_empty());
+    }
+}
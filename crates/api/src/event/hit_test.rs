@@ -0,0 +1,226 @@
+use dces::prelude::*;
+
+use crate::{tree::Tree, utils::*};
+
+// Checks if the given position is inside of the global bounds of the given widget.
+fn contains_position(position: Point, entity: Entity, store: &StringComponentStore) -> bool {
+    let bounds = if let Ok(bounds) = store.get::<Rectangle>("bounds", entity) {
+        bounds
+    } else {
+        return false;
+    };
+
+    let global_position = store
+        .get::<Point>("position", entity)
+        .map_or(Point::default(), |p| *p);
+
+    Rectangle::new(
+        (global_position.x(), global_position.y()),
+        (bounds.width(), bounds.height()),
+    )
+    .contains(position)
+}
+
+// Checks if the widget could receive pointer input (it is enabled and visible).
+fn is_hit_testable(entity: Entity, store: &StringComponentStore) -> bool {
+    if let Ok(enabled) = store.get::<bool>("enabled", entity) {
+        if !*enabled {
+            return false;
+        }
+    }
+
+    if let Ok(visibility) = store.get::<Visibility>("visibility", entity) {
+        if *visibility != Visibility::Visible {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Returns the parent of the given widget. The parent of the overlay is the root of the tree.
+fn parent_of(tree: &Tree, entity: Entity) -> Option<Entity> {
+    if tree.overlay == Some(entity) {
+        return tree.root;
+    }
+
+    tree.parent.get(&entity).cloned().flatten()
+}
+
+// Collects the hit widgets of the given subtree in render order.
+fn collect_hits(
+    position: Point,
+    entity: Entity,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+    hits: &mut Vec<Entity>,
+) {
+    let store = ecm.component_store();
+    let tree = ecm.entity_store();
+
+    if !is_hit_testable(entity, store) {
+        return;
+    }
+
+    let contains = contains_position(position, entity, store);
+
+    // the overlay itself is only a container, its children are hit.
+    if contains && tree.overlay != Some(entity) {
+        hits.push(entity);
+    }
+
+    // children of a clipping widget are only visible inside of its bounds.
+    if !contains && *store.get::<bool>("clip", entity).unwrap_or(&false) {
+        return;
+    }
+
+    if let Some(children) = tree.children.get(&entity) {
+        for child in children {
+            // the overlay is tested separately on top of the tree
+            if Some(*child) == tree.overlay {
+                continue;
+            }
+
+            collect_hits(position, *child, ecm, hits);
+        }
+    }
+}
+
+/// Returns all widgets that are located under the given `position`, ordered by z-order from the topmost
+/// to the bottommost widget.
+///
+/// Disabled and not visible widgets are skipped together with their children. Children of clipping widgets
+/// are only hit inside of the bounds of the clipping widget. The children of the overlay are always on top
+/// of the rest of the tree.
+pub fn hit_test(
+    position: Point,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Vec<Entity> {
+    let mut hits = vec![];
+    let tree = ecm.entity_store();
+
+    collect_hits(position, tree.root(), ecm, &mut hits);
+
+    if let Some(overlay) = tree.overlay {
+        collect_hits(position, overlay, ecm, &mut hits);
+    }
+
+    hits.reverse();
+    hits
+}
+
+/// Returns the topmost widget under the given `position` followed by all its parents. Children of the overlay
+/// are handled as children of the root.
+pub fn hit_path(
+    position: Point,
+    ecm: &EntityComponentManager<Tree, StringComponentStore>,
+) -> Vec<Entity> {
+    if let Some(top) = hit_test(position, ecm).first() {
+        return path_to_root(ecm.entity_store(), *top);
+    }
+
+    vec![]
+}
+
+/// Returns the given `entity` followed by all its parents up to the root.
+pub fn path_to_root(tree: &Tree, entity: Entity) -> Vec<Entity> {
+    let mut path = vec![];
+    let mut current = Some(entity);
+
+    while let Some(node) = current {
+        path.push(node);
+        current = parent_of(tree, node);
+    }
+
+    path
+}
+
+/// Checks if the given `entity` is the given `ancestor` or one of its children (recursive).
+pub fn is_descendant_or_self(tree: &Tree, entity: Entity, ancestor: Entity) -> bool {
+    let mut current = Some(entity);
+
+    while let Some(node) = current {
+        if node == ancestor {
+            return true;
+        }
+
+        current = parent_of(tree, node);
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::RenderContext2D;
+
+    fn create_widget(
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        bounds: (f64, f64, f64, f64),
+    ) -> Entity {
+        let entity = ecm.create_entity().build();
+        let store = ecm.component_store_mut();
+        store.register(
+            "bounds",
+            entity,
+            Rectangle::from((0.0, 0.0, bounds.2, bounds.3)),
+        );
+        store.register("position", entity, Point::new(bounds.0, bounds.1));
+        store.register("enabled", entity, true);
+        store.register("clip", entity, false);
+        store.register("visibility", entity, Visibility::Visible);
+        entity
+    }
+
+    #[test]
+    fn test_hit_test() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        let root = create_widget(ecm, (0.0, 0.0, 100.0, 100.0));
+        let first = create_widget(ecm, (0.0, 0.0, 50.0, 50.0));
+        let second = create_widget(ecm, (25.0, 25.0, 50.0, 50.0));
+        ecm.entity_store_mut().set_root(root);
+        ecm.entity_store_mut().append_child(root, first).unwrap();
+        ecm.entity_store_mut().append_child(root, second).unwrap();
+
+        assert_eq!(
+            hit_test(Point::new(30.0, 30.0), ecm),
+            vec![second, first, root]
+        );
+        assert_eq!(hit_test(Point::new(10.0, 10.0), ecm), vec![first, root]);
+        assert!(hit_test(Point::new(200.0, 10.0), ecm).is_empty());
+
+        *ecm.component_store_mut()
+            .get_mut::<bool>("enabled", second)
+            .unwrap() = false;
+        assert_eq!(hit_test(Point::new(30.0, 30.0), ecm), vec![first, root]);
+        assert_eq!(hit_path(Point::new(30.0, 30.0), ecm), vec![first, root]);
+    }
+
+    #[test]
+    fn test_hit_test_clip() {
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+
+        let root = create_widget(ecm, (0.0, 0.0, 100.0, 100.0));
+        let parent = create_widget(ecm, (0.0, 0.0, 20.0, 20.0));
+        let child = create_widget(ecm, (10.0, 10.0, 40.0, 40.0));
+        ecm.entity_store_mut().set_root(root);
+        ecm.entity_store_mut().append_child(root, parent).unwrap();
+        ecm.entity_store_mut().append_child(parent, child).unwrap();
+
+        assert_eq!(hit_test(Point::new(30.0, 30.0), ecm), vec![child, root]);
+
+        *ecm.component_store_mut()
+            .get_mut::<bool>("clip", parent)
+            .unwrap() = true;
+        assert_eq!(hit_test(Point::new(30.0, 30.0), ecm), vec![root]);
+        assert_eq!(
+            hit_test(Point::new(15.0, 15.0), ecm),
+            vec![child, parent, root]
+        );
+    }
+}
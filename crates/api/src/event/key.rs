@@ -52,6 +52,16 @@ pub trait KeyDownHandler: Sized + Widget {
         })
     }
 
+    /// Inserts a handler that is called before the children of the widget receive the event.
+    fn on_preview_key_down<H: Fn(&mut StatesContext, KeyEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(PreviewEventHandler::new(KeyDownEventHandler {
+            handler: Rc::new(handler),
+        }))
+    }
+
     /// Handles events triggered by a specific key.
     fn on_key_down_key<H: Fn() -> bool + 'static>(self, key: Key, handler: H) -> Self {
         self.on_key_down(
@@ -0,0 +1,94 @@
+use std::{any::Any, fmt, rc::Rc};
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+/// Contains the payload of a message that is sent between windows. The payload could be of any type.
+#[derive(Clone)]
+pub struct Message {
+    message: Rc<dyn Any>,
+}
+
+impl Message {
+    /// Creates a new message from the given payload.
+    pub fn new<T: Any>(message: T) -> Self {
+        Message {
+            message: Rc::new(message),
+        }
+    }
+
+    /// Returns a reference of the payload if it is of the given type.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.message.downcast_ref::<T>()
+    }
+
+    /// Checks if the payload is of the given type.
+    pub fn is<T: Any>(&self) -> bool {
+        self.message.is::<T>()
+    }
+}
+
+impl fmt::Debug for Message {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Message")
+    }
+}
+
+/// `MessageEvent` occurs when a message is sent to the window over the `MessageBus`. The event is sent to all
+/// message handlers of the window from the root to the leaf.
+#[derive(Event)]
+pub struct MessageEvent {
+    /// The id of the window that has sent the message.
+    pub sender: Option<String>,
+
+    /// The message.
+    pub message: Message,
+}
+
+/// Defines the message handler function.
+pub type MessageHandlerFunction = dyn Fn(&mut StatesContext, &Message) -> bool + 'static;
+
+/// Used to handle message events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MessageEventHandler {
+    handler: Rc<MessageHandlerFunction>,
+}
+
+impl EventHandler for MessageEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MessageEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, &event.message))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MessageEvent>()
+    }
+}
+
+pub trait MessageHandler: Sized + Widget {
+    /// Inserts a handler that receives the messages of other windows.
+    fn on_message<H: Fn(&mut StatesContext, &Message) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(MessageEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message() {
+        let message = Message::new(42_usize);
+
+        assert!(message.is::<usize>());
+        assert!(!message.is::<String>());
+        assert_eq!(message.get::<usize>(), Some(&42));
+        assert_eq!(message.get::<String>(), None);
+    }
+}
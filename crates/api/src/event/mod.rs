@@ -6,30 +6,51 @@ use dces::entity::Entity;
 
 use crate::widget_base::StatesContext;
 
+pub use self::animation::*;
+pub use self::animation_frame::*;
+pub use self::click::*;
+pub use self::drag_drop::*;
 pub use self::editable::*;
 pub use self::event_handler::*;
 pub use self::event_queue::*;
 pub use self::focus::*;
+pub use self::gesture::*;
+pub use self::hit_test::*;
 pub use self::key::*;
+pub use self::message::*;
+pub use self::modal::*;
 pub use self::mouse::*;
 pub use self::system::*;
+pub use self::text_input::*;
+pub use self::touch::*;
 pub use self::window::*;
 
+mod animation;
+mod animation_frame;
+mod click;
+mod drag_drop;
 mod editable;
 mod event_handler;
 mod event_queue;
 mod focus;
+mod gesture;
+mod hit_test;
 mod key;
+mod message;
+mod modal;
 mod mouse;
 mod system;
+mod text_input;
+mod touch;
 mod window;
 
 /// Defines the strategy of an event how it moves through the tree.
 #[derive(Debug, Clone, PartialEq)]
 pub enum EventStrategy {
-    // /// From root to leaf.
-    // TopDown,
-    /// From leaf to root.
+    /// From root to leaf. All handlers are called in the tunneling phase.
+    TopDown,
+
+    /// From leaf to root. Preview handlers are called from root to leaf before the event bubbles up.
     BottomUp,
 
     /// Occurs direct.
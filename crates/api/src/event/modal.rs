@@ -0,0 +1,48 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+};
+
+/// `ModalResultEvent` occurs when a modal window or a modal overlay is closed. It is sent direct to the widget
+/// that has opened the modal.
+#[derive(Event)]
+pub struct ModalResultEvent {
+    /// The id of the modal.
+    pub id: u32,
+
+    /// The result of the modal. Is `None` if the modal was closed without a result, e.g. by the close button of
+    /// the window.
+    pub result: Option<Message>,
+}
+
+/// Defines the modal result handler function.
+pub type ModalResultHandlerFunction = dyn Fn(&mut StatesContext, Option<&Message>) + 'static;
+
+/// Used to handle the result of a modal. It is inserted by `Context::show_modal_window` and
+/// `Context::show_modal` and removed after the result is handled.
+#[derive(IntoHandler)]
+pub struct ModalResultEventHandler {
+    pub id: u32,
+    pub handler: Rc<ModalResultHandlerFunction>,
+}
+
+impl EventHandler for ModalResultEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        if let Ok(event) = event.downcast_ref::<ModalResultEvent>() {
+            if event.id == self.id {
+                (self.handler)(state_context, event.result.as_ref());
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ModalResultEvent>()
+            .map_or(false, |event| event.id == self.id)
+    }
+}
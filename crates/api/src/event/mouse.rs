@@ -33,6 +33,24 @@ pub struct MouseMoveEvent {
     pub position: Point,
 }
 
+/// `MouseEnterEvent` occurs when the mouse enters a widget or one of its children.
+///
+/// The event is sent directly to the widget. It is dispatched when the topmost widget under the mouse changes.
+#[derive(Event)]
+pub struct MouseEnterEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
+/// `MouseLeaveEvent` occurs when the mouse leaves a widget and all of its children.
+///
+/// The event is sent directly to the widget. It is dispatched when the topmost widget under the mouse changes.
+#[derive(Event)]
+pub struct MouseLeaveEvent {
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
+}
+
 /// `ScrollEvent` occurs when the mouse wheel is moved.
 #[derive(Event)]
 pub struct ScrollEvent {
@@ -48,6 +66,9 @@ pub struct Mouse {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates how many times the button was pressed in a row (1 for a single click, 2 for a double click, ...).
+    pub click_count: u32,
 }
 
 /// `MouseUpEvent` occurs when a mouse button is released.
@@ -58,6 +79,9 @@ pub struct MouseUpEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates how many times the button was pressed in a row (1 for a single click, 2 for a double click, ...).
+    pub click_count: u32,
 }
 
 /// `ClickEvent` occurs when a user clicked on an element.
@@ -65,6 +89,9 @@ pub struct MouseUpEvent {
 pub struct ClickEvent {
     /// Indicates the x and y position of the click event.
     pub position: Point,
+
+    /// Indicates how many times the button was pressed in a row (1 for a single click, 2 for a double click, ...).
+    pub click_count: u32,
 }
 
 /// `MouseDownEvent` occurs when a mouse button is pressed.
@@ -75,6 +102,19 @@ pub struct MouseDownEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates how many times the button was pressed in a row (1 for a single click, 2 for a double click, ...).
+    pub click_count: u32,
+}
+
+/// `LongPressEvent` occurs when a mouse button is held down without moving for the configured long press duration.
+#[derive(Event)]
+pub struct LongPressEvent {
+    /// Indicates the mouse button that is pressed.
+    pub button: MouseButton,
+
+    /// Indicates position of the mouse on the window.
+    pub position: Point,
 }
 
 /// `GlobalMouseUpEvent` occurs when a mouse button is released.
@@ -87,6 +127,9 @@ pub struct GlobalMouseUpEvent {
 
     /// Indicates position of the mouse on the window.
     pub position: Point,
+
+    /// Indicates how many times the button was pressed in a row (1 for a single click, 2 for a double click, ...).
+    pub click_count: u32,
 }
 
 /// Defines the mouse handler function.
@@ -139,6 +182,7 @@ impl EventHandler for MouseDownEventHandler {
                     Mouse {
                         button: event.button,
                         position: event.position,
+                        click_count: event.click_count,
                     },
                 )
             })
@@ -166,6 +210,7 @@ impl EventHandler for GlobalMouseUpEventHandler {
                     Mouse {
                         button: event.button,
                         position: event.position,
+                        click_count: event.click_count,
                     },
                 );
                 false
@@ -194,6 +239,7 @@ impl EventHandler for MouseUpEventHandler {
                     Mouse {
                         button: event.button,
                         position: event.position,
+                        click_count: event.click_count,
                     },
                 )
             })
@@ -223,6 +269,92 @@ impl EventHandler for MouseMoveEventHandler {
     }
 }
 
+/// Used to handle mouse enter events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseEnterEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseEnterEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseEnterEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseEnterEvent>()
+    }
+}
+
+/// Used to handle mouse leave events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct MouseLeaveEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for MouseLeaveEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<MouseLeaveEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<MouseLeaveEvent>()
+    }
+}
+
+/// Used to handle double click events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct DoubleClickEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for DoubleClickEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<ClickEvent>()
+            .ok()
+            .filter(|event| event.click_count == 2)
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<ClickEvent>()
+    }
+}
+
+/// Used to handle long press events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct LongPressEventHandler {
+    handler: Rc<MouseHandlerFunction>,
+}
+
+impl EventHandler for LongPressEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<LongPressEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(
+                    state_context,
+                    Mouse {
+                        button: event.button,
+                        position: event.position,
+                        click_count: 1,
+                    },
+                )
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<LongPressEvent>()
+    }
+}
+
 /// Used to handle scroll events. Could be attached to a widget.
 #[derive(IntoHandler)]
 pub struct ScrollEventHandler {
@@ -250,6 +382,23 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Inserts a double click handler.
+    fn on_double_click<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(DoubleClickEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a long press handler.
+    fn on_long_press<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(LongPressEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse down handler.
     fn on_mouse_down<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(MouseDownEventHandler {
@@ -264,6 +413,36 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Insert a mouse down handler that is called before the children of the widget receive the event.
+    fn on_preview_mouse_down<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(PreviewEventHandler::new(MouseDownEventHandler {
+            handler: Rc::new(handler),
+        }))
+    }
+
+    /// Insert a mouse up handler that is called before the children of the widget receive the event.
+    fn on_preview_mouse_up<H: Fn(&mut StatesContext, Mouse) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(PreviewEventHandler::new(MouseUpEventHandler {
+            handler: Rc::new(handler),
+        }))
+    }
+
+    /// Insert a mouse move handler that is called before the children of the widget receive the event.
+    fn on_preview_mouse_move<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(PreviewEventHandler::new(MouseMoveEventHandler {
+            handler: Rc::new(handler),
+        }))
+    }
+
     /// Insert a mouse handler for global up event.
     fn on_global_mouse_up<H: Fn(&mut StatesContext, Mouse) + 'static>(self, handler: H) -> Self {
         self.insert_handler(GlobalMouseUpEventHandler {
@@ -278,6 +457,26 @@ pub trait MouseHandler: Sized + Widget {
         })
     }
 
+    /// Insert a mouse enter handler.
+    fn on_mouse_enter<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseEnterEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Insert a mouse leave handler.
+    fn on_mouse_leave<H: Fn(&mut StatesContext, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(MouseLeaveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
     /// Insert a mouse up handler.
     fn on_scroll<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
         self.insert_handler(ScrollEventHandler {
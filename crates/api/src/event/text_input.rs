@@ -0,0 +1,47 @@
+use std::rc::Rc;
+
+use crate::{prelude::*, proc_macros::*, shell::ImeEvent};
+
+use super::{EventBox, EventHandler};
+
+/// `TextInputEvent` occurs if an input method editor (IME), a dead key or a compose sequence changes the
+/// composed text or commits text.
+#[derive(Event)]
+pub struct TextInputEvent {
+    pub event: ImeEvent,
+}
+
+pub type TextInputHandlerFunction = dyn Fn(&mut StatesContext, ImeEvent) -> bool + 'static;
+
+/// Used to handle text input events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TextInputEventHandler {
+    handler: Rc<TextInputHandlerFunction>,
+}
+
+impl EventHandler for TextInputEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TextInputEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.event.clone())
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TextInputEvent>()
+    }
+}
+
+pub trait TextInputHandler: Sized + Widget {
+    /// Inserts a handler that receives the preedit and the committed text of input methods.
+    fn on_text_input<H: Fn(&mut StatesContext, ImeEvent) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TextInputEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -0,0 +1,286 @@
+use std::rc::Rc;
+
+use crate::{
+    prelude::*,
+    proc_macros::{Event, IntoHandler},
+    utils::*,
+};
+
+/// `TouchStartEvent` occurs when a finger touches the screen.
+#[derive(Event)]
+pub struct TouchStartEvent {
+    /// Unique id of the touch point.
+    pub id: u64,
+
+    /// Indicates position of the touch point on the window.
+    pub position: Point,
+}
+
+/// `TouchMoveEvent` occurs when a finger moves on the screen.
+#[derive(Event)]
+pub struct TouchMoveEvent {
+    /// Unique id of the touch point.
+    pub id: u64,
+
+    /// Indicates position of the touch point on the window.
+    pub position: Point,
+}
+
+/// `TouchEndEvent` occurs when a finger is lifted from the screen.
+#[derive(Event)]
+pub struct TouchEndEvent {
+    /// Unique id of the touch point.
+    pub id: u64,
+
+    /// Indicates position of the touch point on the window.
+    pub position: Point,
+}
+
+/// `TouchCancelEvent` occurs when the system cancels the tracking of a touch point.
+#[derive(Event)]
+pub struct TouchCancelEvent {
+    /// Unique id of the touch point.
+    pub id: u64,
+
+    /// Indicates the last position of the touch point on the window.
+    pub position: Point,
+}
+
+/// `TapEvent` occurs when a finger touches the screen shortly without moving.
+#[derive(Event)]
+pub struct TapEvent {
+    /// Indicates position of the tap on the window.
+    pub position: Point,
+}
+
+/// `PanEvent` occurs when a finger moves on the screen. It also occurs during the kinetic panning after a swipe.
+#[derive(Event)]
+pub struct PanEvent {
+    /// Indicates position of the finger on the window.
+    pub position: Point,
+
+    /// The moved distance since the last pan event.
+    pub delta: Point,
+}
+
+/// `PinchEvent` occurs when two fingers are moved towards or away from each other.
+#[derive(Event)]
+pub struct PinchEvent {
+    /// Indicates the center between the two fingers on the window.
+    pub center: Point,
+
+    /// The scale factor relative to the last pinch event.
+    pub scale: f64,
+}
+
+/// `SwipeEvent` occurs when a finger is lifted while it moves fast.
+#[derive(Event)]
+pub struct SwipeEvent {
+    /// Indicates position of the lifted finger on the window.
+    pub position: Point,
+
+    /// The velocity of the finger in pixels per millisecond.
+    pub velocity: Point,
+}
+
+/// Represents a touch point of a touch event.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Touch {
+    /// Unique id of the touch point.
+    pub id: u64,
+
+    /// Indicates position of the touch point on the window.
+    pub position: Point,
+}
+
+/// Defines the touch handler function.
+pub type TouchHandlerFunction = dyn Fn(&mut StatesContext, Touch) -> bool + 'static;
+
+/// Defines the pan and swipe handler function. The second point is the delta of a pan or the velocity of a swipe.
+pub type PanHandlerFunction = dyn Fn(&mut StatesContext, Point, Point) -> bool + 'static;
+
+/// Defines the pinch handler function.
+pub type PinchHandlerFunction = dyn Fn(&mut StatesContext, Point, f64) -> bool + 'static;
+
+macro_rules! touch_event_handler {
+    ($handler:ident, $event:ident) => {
+        /// Used to handle touch events. Could be attached to a widget.
+        #[derive(IntoHandler)]
+        pub struct $handler {
+            handler: Rc<TouchHandlerFunction>,
+        }
+
+        impl EventHandler for $handler {
+            fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+                event.downcast_ref::<$event>().ok().map_or(false, |event| {
+                    (self.handler)(
+                        state_context,
+                        Touch {
+                            id: event.id,
+                            position: event.position,
+                        },
+                    )
+                })
+            }
+
+            fn handles_event(&self, event: &EventBox) -> bool {
+                event.is_type::<$event>()
+            }
+        }
+    };
+}
+
+touch_event_handler!(TouchStartEventHandler, TouchStartEvent);
+touch_event_handler!(TouchMoveEventHandler, TouchMoveEvent);
+touch_event_handler!(TouchEndEventHandler, TouchEndEvent);
+touch_event_handler!(TouchCancelEventHandler, TouchCancelEvent);
+
+/// Used to handle tap events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct TapEventHandler {
+    handler: Rc<PositionHandlerFunction>,
+}
+
+impl EventHandler for TapEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<TapEvent>()
+            .ok()
+            .map_or(false, |event| (self.handler)(state_context, event.position))
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<TapEvent>()
+    }
+}
+
+/// Used to handle pan events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PanEventHandler {
+    handler: Rc<PanHandlerFunction>,
+}
+
+impl EventHandler for PanEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<PanEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.position, event.delta)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PanEvent>()
+    }
+}
+
+/// Used to handle pinch events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct PinchEventHandler {
+    handler: Rc<PinchHandlerFunction>,
+}
+
+impl EventHandler for PinchEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<PinchEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.center, event.scale)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<PinchEvent>()
+    }
+}
+
+/// Used to handle swipe events. Could be attached to a widget.
+#[derive(IntoHandler)]
+pub struct SwipeEventHandler {
+    handler: Rc<PanHandlerFunction>,
+}
+
+impl EventHandler for SwipeEventHandler {
+    fn handle_event(&self, state_context: &mut StatesContext, event: &EventBox) -> bool {
+        event
+            .downcast_ref::<SwipeEvent>()
+            .ok()
+            .map_or(false, |event| {
+                (self.handler)(state_context, event.position, event.velocity)
+            })
+    }
+
+    fn handles_event(&self, event: &EventBox) -> bool {
+        event.is_type::<SwipeEvent>()
+    }
+}
+
+/// Adds touch and gesture handling to a widget.
+pub trait TouchHandler: Sized + Widget {
+    /// Inserts a touch start handler.
+    fn on_touch_start<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TouchStartEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch move handler.
+    fn on_touch_move<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TouchMoveEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch end handler.
+    fn on_touch_end<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TouchEndEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a touch cancel handler.
+    fn on_touch_cancel<H: Fn(&mut StatesContext, Touch) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(TouchCancelEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a tap handler.
+    fn on_tap<H: Fn(&mut StatesContext, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(TapEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a pan handler. The handler gets the position of the finger and the moved distance.
+    fn on_pan<H: Fn(&mut StatesContext, Point, Point) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(PanEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a pinch handler. The handler gets the center of the fingers and the relative scale factor.
+    fn on_pinch<H: Fn(&mut StatesContext, Point, f64) -> bool + 'static>(self, handler: H) -> Self {
+        self.insert_handler(PinchEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+
+    /// Inserts a swipe handler. The handler gets the position and the velocity of the lifted finger.
+    fn on_swipe<H: Fn(&mut StatesContext, Point, Point) -> bool + 'static>(
+        self,
+        handler: H,
+    ) -> Self {
+        self.insert_handler(SwipeEventHandler {
+            handler: Rc::new(handler),
+        })
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     properties::Constraint,
     render::Image,
     render::RenderContext2D,
+    services::Icon,
     theming::*,
     tree::Tree,
     utils::prelude::*,
@@ -86,6 +87,18 @@ impl Layout for FixedSizeLayout {
                     }
                 })
             })
+            .or_else(|| match widget.try_get::<Option<Icon>>("resolved_icon")? {
+                Some(Icon::Image(image)) => Some((image.width(), image.height())),
+                Some(Icon::Glyph { glyph, font }) => {
+                    let text_metrics = render_context_2_d.measure(
+                        glyph,
+                        *widget.get::<f64>("icon_size"),
+                        font.as_str(),
+                    );
+                    Some((text_metrics.width, text_metrics.height))
+                }
+                None => None,
+            })
             .or_else(|| {
                 widget
                     .try_clone::<String>("icon")
@@ -67,6 +67,18 @@ macro_rules! into_property_source {
 /// });
 /// ```
 ///
+/// Properties listed in the `theme_properties` section could be set by the styles of the theme. The types of the
+/// properties must implement `ThemeProperty`.
+/// ```
+/// widget!(Gauge {
+///     value: f64,
+///     track_brush: Brush,
+///     thumb_size: f64
+///
+///     theme_properties: { track_brush, thumb_size }
+/// });
+/// ```
+///
 /// [`Widget`]: ./widget/trait.Widget.html
 /// [`builder`]: https://doc.rust-lang.org/1.0.0/style/ownership/builders.html
 /// [`builder pattern`]: https://en.wikipedia.org/wiki/Builder_pattern
@@ -75,6 +87,7 @@ macro_rules! widget {
     ( $(#[$widget_doc:meta])* $widget:ident $(<$state:ident>)* $(: $( $handler:ident ),*)*
             $( { $($(#[$prop_doc:meta])* $property:ident: $property_type:tt ),*
                 $( attached_properties: { $($(#[$att_prop_doc:meta])* $att_property:ident: $att_property_type:tt ),* } )*
+                $( theme_properties: { $($theme_property:ident),* } )*
              } )* ) => {
         $(#[$widget_doc])*
         #[derive(Default, WidgetCtx)]
@@ -98,6 +111,7 @@ macro_rules! widget {
             name: Option<String>,
             style: Option<String>,
             id: Option<String>,
+            theme_scope: Option<ThemeScope>,
             #[property(Alignment)]
             h_align: Alignment,
             #[property(Alignment)]
@@ -155,7 +169,8 @@ macro_rules! widget {
                 self
             }
 
-            /// Sets the style selector (replaces the old selector property).
+            /// Sets the style selector (replaces the old selector property). Several style classes are separated by
+            /// whitespace, e.g. `"button primary large"`.
             pub fn style(mut self, style: impl Into<String>) -> Self {
                 if !self.style.is_none() {
                     return self;
@@ -165,6 +180,14 @@ macro_rules! widget {
                 self
             }
 
+            /// Attaches a theme to the widget that styles the widget and its descendants, e.g. `.theme_scope(dark_theme())`
+            /// or `.theme_scope(ThemeScope::overlay(high_contrast))`. Widgets the theme has no style for are styled
+            /// by the theme of the window.
+            pub fn theme_scope(mut self, theme_scope: impl Into<ThemeScope>) -> Self {
+                self.theme_scope = Some(theme_scope.into());
+                self
+            }
+
             /// Sets or shares the position of the widget. (Be careful the position could be adjusted by layouts).
             pub fn position(self, position: impl IntoPropertySource<Point>) -> Self {
                 self.set_property("position", position)
@@ -415,6 +438,15 @@ macro_rules! widget {
             }
 
             fn build(self, ctx: &mut BuildContext) -> Entity {
+                // register properties that could be set by the theme
+                $(
+                    $(
+                        $(
+                            register_theme_property_of(stringify!($theme_property), &self.$theme_property);
+                        )*
+                    )*
+                )*
+
                 let entity = ctx.create_entity();
 
                 let this = self.template(entity, ctx);
@@ -443,14 +475,24 @@ macro_rules! widget {
                 ctx.register_property("type_name", entity, std::any::type_name::<$widget>().to_string());
                 ctx.register_property("dirty", entity, false);
 
+                let mut selector = if let Some(style) = this.style {
+                    Selector::new(style)
+                } else {
+                    this.selector
+                };
+
+                // used to match id and type rules of the theme
+                selector.id = this.id.clone();
+                selector.name = this.name.clone();
+
                 if let Some(id) = this.id {
                     ctx.register_property("id", entity, id);
                 }
 
-                if let Some(style) = this.style {
-                    ctx.register_property("selector", entity, Selector::new(style));
-                } else {
-                    ctx.register_property("selector", entity, this.selector);
+                ctx.register_property("selector", entity, selector);
+
+                if this.theme_scope.is_some() {
+                    ctx.register_property("theme_scope", entity, this.theme_scope);
                 }
 
                 let mut constraint = this.constraint;
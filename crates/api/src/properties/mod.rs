@@ -6,7 +6,7 @@ use dces::prelude::{Component, Entity, StringComponentStore};
 
 pub use self::layout::*;
 pub use self::widget::*;
-use crate::{into_property_source, render, theming, utils};
+use crate::{into_property_source, render, services::Icon, theming, utils};
 
 mod layout;
 mod widget;
@@ -123,6 +123,7 @@ into_property_source!(render::Image: &str, String, (u32, u32, Vec<u32>));
 into_property_source!(Columns: ColumnsBuilder);
 into_property_source!(Constraint: ConstraintBuilder);
 into_property_source!(DefaultRenderPipeline);
+into_property_source!(Option<Icon>);
 into_property_source!(Rows: RowsBuilder);
 into_property_source!(ScrollViewerMode: (&str, &str));
 into_property_source!(SelectedEntities: HashSet<Entity>);
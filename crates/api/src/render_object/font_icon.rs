@@ -1,5 +1,6 @@
 use crate::{
     render_object::*,
+    services::Icon,
     utils::{Brush, Point, Rectangle},
 };
 
@@ -13,7 +14,7 @@ impl Into<Box<dyn RenderObject>> for FontIconRenderObject {
 
 impl RenderObject for FontIconRenderObject {
     fn render_self(&self, ctx: &mut Context, global_position: &Point) {
-        let (bounds, icon, icon_brush, icon_font, icon_size) = {
+        let (bounds, mut icon, icon_brush, mut icon_font, icon_size, resolved_icon) = {
             let widget = ctx.widget();
             (
                 *widget.get::<Rectangle>("bounds"),
@@ -21,9 +22,28 @@ impl RenderObject for FontIconRenderObject {
                 widget.get::<Brush>("icon_brush").clone(),
                 widget.get::<String>("icon_font").clone(),
                 *widget.get::<f64>("icon_size"),
+                widget.try_clone::<Option<Icon>>("resolved_icon").flatten(),
             )
         };
 
+        let x = global_position.x() + bounds.x();
+        let y = global_position.y() + bounds.y();
+
+        match resolved_icon {
+            Some(Icon::Image(image)) => {
+                if bounds.width() > 0.0 && bounds.height() > 0.0 {
+                    ctx.render_context_2_d().draw_image(&image, x, y);
+                }
+
+                return;
+            }
+            Some(Icon::Glyph { glyph, font }) => {
+                icon = glyph;
+                icon_font = font;
+            }
+            None => {}
+        }
+
         if bounds.width() == 0.0
             || bounds.height() == 0.0
             || icon_brush.is_transparent()
@@ -33,18 +53,11 @@ impl RenderObject for FontIconRenderObject {
             return;
         }
 
-        if !icon.is_empty() {
-            ctx.render_context_2_d().begin_path();
-            ctx.render_context_2_d().set_font_family(icon_font);
-            ctx.render_context_2_d().set_font_size(icon_size);
-            ctx.render_context_2_d().set_fill_style(icon_brush);
-
-            ctx.render_context_2_d().fill_text(
-                &icon,
-                global_position.x() + bounds.x(),
-                global_position.y() + bounds.y(),
-            );
-            ctx.render_context_2_d().close_path();
-        }
+        ctx.render_context_2_d().begin_path();
+        ctx.render_context_2_d().set_font_family(icon_font);
+        ctx.render_context_2_d().set_font_size(icon_size);
+        ctx.render_context_2_d().set_fill_style(icon_brush);
+        ctx.render_context_2_d().fill_text(&icon, x, y);
+        ctx.render_context_2_d().close_path();
     }
 }
@@ -0,0 +1,322 @@
+use std::{cell::Cell, rc::Rc, sync::mpsc, time::Duration};
+
+use crate::{
+    shell::{system_color_scheme, ColorScheme},
+    theming::{Density, Theme},
+};
+
+/// Interval that is recommended to poll the color scheme of the desktop with `SystemColorScheme::polling`.
+pub const COLOR_SCHEME_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Provides the color scheme the user prefers.
+pub trait ColorSchemeSource {
+    /// Returns the current preferred color scheme or `None` if it is unknown. It is called on each update of a
+    /// window, so it should not block.
+    fn color_scheme(&mut self) -> Option<ColorScheme>;
+
+    /// Returns the interval the windows should be updated in to read changes of the source. `None` means the
+    /// changes are read on the next update of the windows.
+    fn poll_interval(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// Reads the preferred color scheme of the desktop. By default the color scheme is detected once on startup, later
+/// changes are reported by the shell. Shells that could not report changes could poll the desktop with
+/// `SystemColorScheme::polling`.
+#[derive(Default)]
+pub struct SystemColorScheme {
+    color_scheme: Option<ColorScheme>,
+    detected: bool,
+    interval: Option<Duration>,
+    receiver: Option<mpsc::Receiver<Option<ColorScheme>>>,
+}
+
+impl SystemColorScheme {
+    /// Creates a new system color scheme source that detects the color scheme once.
+    pub fn new() -> Self {
+        SystemColorScheme::default()
+    }
+
+    /// Creates a new system color scheme source that repeats the detection in the given interval. The detection
+    /// could start a process, so it is repeated on a separate thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Application::new().color_scheme_source(SystemColorScheme::polling(COLOR_SCHEME_POLL_INTERVAL));
+    /// ```
+    pub fn polling(interval: Duration) -> Self {
+        SystemColorScheme {
+            interval: Some(interval),
+            ..SystemColorScheme::default()
+        }
+    }
+}
+
+impl ColorSchemeSource for SystemColorScheme {
+    fn color_scheme(&mut self) -> Option<ColorScheme> {
+        if !self.detected {
+            self.detected = true;
+            self.color_scheme = system_color_scheme();
+            self.receiver = self.interval.and_then(spawn_detection);
+        }
+
+        if let Some(receiver) = &self.receiver {
+            if let Some(color_scheme) = receiver.try_iter().last() {
+                self.color_scheme = color_scheme;
+            }
+        }
+
+        self.color_scheme
+    }
+
+    fn poll_interval(&self) -> Option<Duration> {
+        self.interval
+    }
+}
+
+// Repeats the detection of the color scheme on a separate thread until the receiver is dropped.
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_detection(interval: Duration) -> Option<mpsc::Receiver<Option<ColorScheme>>> {
+    let (sender, receiver) = mpsc::channel();
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(interval);
+
+        if sender.send(system_color_scheme()).is_err() {
+            break;
+        }
+    });
+
+    Some(receiver)
+}
+
+// The web has no threads and the color scheme could not be detected there.
+#[cfg(target_arch = "wasm32")]
+fn spawn_detection(_: Duration) -> Option<mpsc::Receiver<Option<ColorScheme>>> {
+    None
+}
+
+/// A color scheme source that is changed by hand, e.g. to test the switching of the themes without a desktop. Clones
+/// share the same color scheme.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let source = StubColorScheme::new(ColorScheme::Light);
+///
+/// Application::new().color_scheme_source(source.clone());
+///
+/// // windows switch to the dark theme on their next update
+/// source.set(ColorScheme::Dark);
+/// ```
+#[derive(Clone, Default)]
+pub struct StubColorScheme {
+    color_scheme: Rc<Cell<Option<ColorScheme>>>,
+}
+
+impl StubColorScheme {
+    /// Creates a new stub with the given color scheme.
+    pub fn new(color_scheme: ColorScheme) -> Self {
+        StubColorScheme {
+            color_scheme: Rc::new(Cell::new(Some(color_scheme))),
+        }
+    }
+
+    /// Changes the color scheme.
+    pub fn set(&self, color_scheme: impl Into<Option<ColorScheme>>) {
+        self.color_scheme.set(color_scheme.into());
+    }
+}
+
+impl ColorSchemeSource for StubColorScheme {
+    fn color_scheme(&mut self) -> Option<ColorScheme> {
+        self.color_scheme.get()
+    }
+}
+
+/// Service that picks the theme of the application by the preferred color scheme. It is shared by all windows of the
+/// application and could be accessed from the registry with the key `"color_scheme"`. Windows switch their theme if
+/// the color scheme changes.
+pub struct ColorSchemeThemes {
+    source: Box<dyn ColorSchemeSource>,
+    light: Theme,
+    dark: Theme,
+    fallback: ColorScheme,
+    system: Option<ColorScheme>,
+    polled: Option<ColorScheme>,
+    manual: Option<ColorScheme>,
+    enabled: bool,
+}
+
+impl ColorSchemeThemes {
+    /// Creates a new service with the themes for both color schemes that follows the color scheme of the desktop.
+    /// The fallback is used if the preferred color scheme is unknown.
+    pub fn new(light: Theme, dark: Theme, fallback: ColorScheme) -> Self {
+        ColorSchemeThemes {
+            source: Box::new(SystemColorScheme::new()),
+            light,
+            dark,
+            fallback,
+            system: None,
+            polled: None,
+            manual: None,
+            enabled: true,
+        }
+    }
+
+    /// Replaces the source of the preferred color scheme.
+    pub fn set_source(&mut self, source: impl ColorSchemeSource + 'static) {
+        self.source = Box::new(source);
+        self.system = None;
+        self.polled = None;
+    }
+
+    /// Overrides the preferred color scheme by hand. `None` follows the source again.
+    pub fn set_manual(&mut self, color_scheme: Option<ColorScheme>) {
+        self.manual = color_scheme;
+    }
+
+    /// Returns the color scheme that is overridden by hand.
+    pub fn manual(&self) -> Option<ColorScheme> {
+        self.manual
+    }
+
+    /// Enables or disables the switching of the theme, e.g. if the application uses its own theme.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Checks if the theme is switched by the color scheme.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Checks if the service reads the preferred color scheme from its source.
+    pub fn is_following(&self) -> bool {
+        self.enabled && self.manual.is_none()
+    }
+
+    /// Sets the density of the themes of both color schemes.
+    pub fn set_density(&mut self, density: Density) {
+        self.light.set_density(density);
+        self.dark.set_density(density);
+    }
+
+    /// Returns the interval the windows should be updated in to read changes of the source or `None` if the
+    /// changes are reported by the shell.
+    pub fn poll_interval(&self) -> Option<Duration> {
+        if self.is_following() {
+            self.source.poll_interval()
+        } else {
+            None
+        }
+    }
+
+    /// Is called if the shell reports a changed color scheme of the desktop.
+    pub fn system_changed(&mut self, color_scheme: ColorScheme) {
+        self.system = Some(color_scheme);
+    }
+
+    /// Reads the preferred color scheme from the source. Only a change of the source overrides the color scheme
+    /// that is reported by the shell.
+    pub fn poll(&mut self) {
+        if !self.is_following() {
+            return;
+        }
+
+        let color_scheme = self.source.color_scheme();
+
+        if color_scheme.is_some() && color_scheme != self.polled {
+            self.system = color_scheme;
+        }
+
+        self.polled = color_scheme;
+    }
+
+    /// Returns the current color scheme. The manual color scheme is preferred over the one of the source.
+    pub fn color_scheme(&self) -> ColorScheme {
+        self.manual.or(self.system).unwrap_or(self.fallback)
+    }
+
+    /// Returns the theme of the current color scheme.
+    pub fn theme(&self) -> &Theme {
+        match self.color_scheme() {
+            ColorScheme::Light => &self.light,
+            ColorScheme::Dark => &self.dark,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::theming::{config::ThemeConfig, Selector};
+
+    fn theme(background: &str) -> Theme {
+        Theme::from_config(ThemeConfig::from(
+            format!(
+                r#"Theme ( styles: {{ "window": ( properties: {{ "background": "{}" }} ) }} )"#,
+                background
+            )
+            .as_str(),
+        ))
+    }
+
+    #[test]
+    fn test_switch() {
+        let source = StubColorScheme::default();
+        let mut themes = ColorSchemeThemes::new(theme("white"), theme("black"), ColorScheme::Dark);
+        themes.set_source(source.clone());
+
+        let background = |themes: &ColorSchemeThemes| {
+            themes.theme().properties(&Selector::new("window")).unwrap()["background"]
+                .clone()
+                .into_rust::<String>()
+                .unwrap()
+        };
+
+        // the fallback is used until the color scheme is known
+        themes.poll();
+        assert_eq!(themes.color_scheme(), ColorScheme::Dark);
+
+        source.set(ColorScheme::Light);
+        themes.poll();
+        assert_eq!(themes.color_scheme(), ColorScheme::Light);
+        assert_eq!(background(&themes), "white");
+
+        // the manual color scheme wins over the source
+        themes.set_manual(Some(ColorScheme::Dark));
+        themes.poll();
+        assert_eq!(background(&themes), "black");
+
+        // a change reported by the shell is kept until the source changes
+        themes.set_manual(None);
+        themes.system_changed(ColorScheme::Dark);
+        themes.poll();
+        assert_eq!(themes.color_scheme(), ColorScheme::Dark);
+
+        source.set(ColorScheme::Dark);
+        themes.poll();
+        source.set(ColorScheme::Light);
+        themes.poll();
+        assert_eq!(themes.color_scheme(), ColorScheme::Light);
+    }
+
+    #[test]
+    fn test_poll_interval() {
+        let mut themes = ColorSchemeThemes::new(theme("white"), theme("black"), ColorScheme::Dark);
+
+        // changes of the desktop are reported by the shell
+        assert_eq!(themes.poll_interval(), None);
+
+        themes.set_source(SystemColorScheme::polling(COLOR_SCHEME_POLL_INTERVAL));
+        assert_eq!(themes.poll_interval(), Some(COLOR_SCHEME_POLL_INTERVAL));
+
+        // a manual color scheme is not polled
+        themes.set_manual(Some(ColorScheme::Light));
+        assert_eq!(themes.poll_interval(), None);
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use crate::render::Image;
+
+/// Separates the icon set from the name of the icon, e.g. `app.save`.
+pub const ICON_SET_SEPARATOR: char = '.';
+
+/// Separates the name of the icon from its color variant, e.g. `app.save:light`.
+pub const ICON_VARIANT_SEPARATOR: char = ':';
+
+/// Describes how an icon of the `IconRegistry` is drawn.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Icon {
+    /// A glyph of an icon font. It is drawn with the icon brush of the widget.
+    Glyph { glyph: String, font: String },
+
+    /// An image that is drawn in its own size and colors.
+    Image(Image),
+}
+
+/// Provides the icons of an icon set.
+pub trait IconProvider {
+    /// Returns the icon with the given name (without the name of the set) that fits best to the given size and color
+    /// variant or `None` if the set does not contain the icon.
+    fn icon(&self, name: &str, size: f64, variant: Option<&str>) -> Option<Icon>;
+}
+
+/// Closures could be used as provider, e.g. to rasterize svg icons on demand.
+impl<F> IconProvider for F
+where
+    F: Fn(&str, f64, Option<&str>) -> Option<Icon>,
+{
+    fn icon(&self, name: &str, size: f64, variant: Option<&str>) -> Option<Icon> {
+        self(name, size, variant)
+    }
+}
+
+/// Provides the glyphs of an icon font by their names.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let provider = FontIconProvider::new("MaterialIcons-Regular")
+///     .glyph("save", material_icons_font::MD_SAVE);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FontIconProvider {
+    font: String,
+    glyphs: HashMap<String, String>,
+    table: &'static [(&'static str, &'static str)],
+}
+
+impl FontIconProvider {
+    /// Creates a new provider for the given icon font.
+    pub fn new(font: impl Into<String>) -> Self {
+        FontIconProvider {
+            font: font.into(),
+            glyphs: HashMap::new(),
+            table: &[],
+        }
+    }
+
+    /// Creates a new provider for the given icon font that reads the glyphs from a static table of names and glyphs,
+    /// which must be sorted by name, e.g. `MATERIAL_ICONS`. Glyphs that are added to the provider override the table.
+    pub fn with_table(
+        font: impl Into<String>,
+        table: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        FontIconProvider {
+            table,
+            ..FontIconProvider::new(font)
+        }
+    }
+
+    /// Builder method that adds the glyph of the icon with the given name.
+    pub fn glyph(mut self, name: impl Into<String>, glyph: impl Into<String>) -> Self {
+        self.insert(name, glyph);
+        self
+    }
+
+    /// Builder method that adds the given glyphs by their names.
+    pub fn glyphs<N, G>(mut self, glyphs: impl IntoIterator<Item = (N, G)>) -> Self
+    where
+        N: Into<String>,
+        G: Into<String>,
+    {
+        for (name, glyph) in glyphs {
+            self.insert(name, glyph);
+        }
+
+        self
+    }
+
+    /// Adds the glyph of the icon with the given name.
+    pub fn insert(&mut self, name: impl Into<String>, glyph: impl Into<String>) {
+        self.glyphs.insert(name.into(), glyph.into());
+    }
+
+    /// Gets the icon font.
+    pub fn font(&self) -> &str {
+        &self.font
+    }
+}
+
+impl IconProvider for FontIconProvider {
+    fn icon(&self, name: &str, _: f64, _: Option<&str>) -> Option<Icon> {
+        let glyph = match self.glyphs.get(name) {
+            Some(glyph) => glyph.as_str(),
+            None => {
+                let index = self
+                    .table
+                    .binary_search_by_key(&name, |(name, _)| *name)
+                    .ok()?;
+                self.table[index].1
+            }
+        };
+
+        Some(Icon::Glyph {
+            glyph: glyph.to_string(),
+            font: self.font.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct IconImage {
+    variant: Option<String>,
+    image: Image,
+}
+
+/// Provides icons that are drawn as images, e.g. loaded from png files. An icon could have images of different sizes
+/// and color variants. The smallest image that is at least as large as the requested size is picked.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let provider = ImageIconProvider::new()
+///     .image("save", "res/icons/save_16.png")
+///     .image("save", "res/icons/save_32.png")
+///     .variant("save", "light", "res/icons/save_light_16.png");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ImageIconProvider {
+    images: HashMap<String, Vec<IconImage>>,
+}
+
+impl ImageIconProvider {
+    /// Creates a new provider without icons.
+    pub fn new() -> Self {
+        ImageIconProvider::default()
+    }
+
+    /// Builder method that adds an image of the icon with the given name.
+    pub fn image(mut self, name: impl Into<String>, image: impl Into<Image>) -> Self {
+        self.insert(name, None, image);
+        self
+    }
+
+    /// Builder method that adds an image of a color variant of the icon, e.g. `light` for dark backgrounds.
+    pub fn variant(
+        mut self,
+        name: impl Into<String>,
+        variant: &str,
+        image: impl Into<Image>,
+    ) -> Self {
+        self.insert(name, Some(variant), image);
+        self
+    }
+
+    /// Adds an image of the icon with the given name and color variant.
+    pub fn insert(
+        &mut self,
+        name: impl Into<String>,
+        variant: Option<&str>,
+        image: impl Into<Image>,
+    ) {
+        self.images
+            .entry(name.into())
+            .or_insert_with(Vec::new)
+            .push(IconImage {
+                variant: variant.map(str::to_string),
+                image: image.into(),
+            });
+    }
+}
+
+impl IconProvider for ImageIconProvider {
+    fn icon(&self, name: &str, size: f64, variant: Option<&str>) -> Option<Icon> {
+        let mut candidates = candidates(self.images.get(name)?, variant);
+        candidates.sort_by(|a, b| a.image.width().partial_cmp(&b.image.width()).unwrap());
+
+        candidates
+            .iter()
+            .find(|image| image.image.width() >= size)
+            .or_else(|| candidates.last())
+            .map(|image| Icon::Image(image.image.clone()))
+    }
+}
+
+// Returns the images of the requested variant. Falls back to the images without variant and at last to all images.
+fn candidates<'a>(images: &'a [IconImage], variant: Option<&str>) -> Vec<&'a IconImage> {
+    for variant in &[variant, None] {
+        let candidates: Vec<_> = images
+            .iter()
+            .filter(|image| image.variant.as_deref() == *variant)
+            .collect();
+
+        if !candidates.is_empty() {
+            return candidates;
+        }
+    }
+
+    images.iter().collect()
+}
+
+/// Service that resolves icons by their names, e.g. `app.save`. The first part of the name selects the icon set,
+/// an optional suffix selects a color variant, e.g. `app.save:light`. It is shared by all windows of the application
+/// and could be accessed from the registry with the key `"icons"`.
+///
+/// Widgets like `FontIconBlock` and `Button` resolve icon names of their `icon` property, so icons could be
+/// referenced from themes and widgets. Other values of the property are drawn as glyphs of the icon font.
+///
+/// The set `material` provides the glyphs of the Material Icons font, e.g. `material.save`.
+#[derive(Default)]
+pub struct IconRegistry {
+    sets: HashMap<String, Box<dyn IconProvider>>,
+}
+
+impl IconRegistry {
+    /// Creates a new registry without icon sets.
+    pub fn new() -> Self {
+        IconRegistry::default()
+    }
+
+    /// Registers the provider of the given icon set. Replaces the provider that is already registered for the set.
+    pub fn register(&mut self, set: impl Into<String>, provider: impl IconProvider + 'static) {
+        self.sets.insert(set.into(), Box::new(provider));
+    }
+
+    /// Removes the provider of the given icon set.
+    pub fn unregister(&mut self, set: &str) -> bool {
+        self.sets.remove(set).is_some()
+    }
+
+    /// Checks if a provider is registered for the given icon set.
+    pub fn contains_set(&self, set: &str) -> bool {
+        self.sets.contains_key(set)
+    }
+
+    /// Returns the icon of the given name in the given size or `None` if the name does not reference an icon of a
+    /// registered set.
+    pub fn icon(&self, name: &str, size: f64) -> Option<Icon> {
+        let (name, variant) = match name.find(ICON_VARIANT_SEPARATOR) {
+            Some(index) => (&name[..index], Some(&name[index + 1..])),
+            None => (name, None),
+        };
+
+        let index = name.find(ICON_SET_SEPARATOR)?;
+
+        self.sets
+            .get(&name[..index])?
+            .icon(&name[index + 1..], size, variant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(size: u32) -> Image {
+        Image::from((size, size, vec![0; (size * size) as usize]))
+    }
+
+    fn width(icon: Option<Icon>) -> f64 {
+        match icon {
+            Some(Icon::Image(image)) => image.width(),
+            _ => 0.0,
+        }
+    }
+
+    #[test]
+    fn test_icon() {
+        let mut icons = IconRegistry::new();
+        icons.register("font", FontIconProvider::new("Icons").glyph("save", "s"));
+        icons.register(
+            "table",
+            FontIconProvider::with_table("Icons", &[("open", "o"), ("save", "s")])
+                .glyph("open", "O"),
+        );
+        icons.register(
+            "image",
+            ImageIconProvider::new()
+                .image("save", image(16))
+                .image("save", image(32))
+                .variant("save", "light", image(24)),
+        );
+
+        assert_eq!(
+            icons.icon("font.save", 12.0),
+            Some(Icon::Glyph {
+                glyph: "s".to_string(),
+                font: "Icons".to_string()
+            })
+        );
+        assert_eq!(icons.icon("font.open", 12.0), None);
+
+        // glyphs that are added override the table
+        let glyph = |name| match icons.icon(name, 12.0) {
+            Some(Icon::Glyph { glyph, .. }) => glyph,
+            _ => String::new(),
+        };
+        assert_eq!(glyph("table.save"), "s");
+        assert_eq!(glyph("table.open"), "O");
+        assert_eq!(glyph("table.close"), "");
+        assert_eq!(icons.icon("missing.save", 12.0), None);
+
+        // glyphs of the icon font are no names
+        assert_eq!(icons.icon("\u{e161}", 12.0), None);
+
+        // the smallest image that is large enough is picked
+        assert_eq!(width(icons.icon("image.save", 12.0)), 16.0);
+        assert_eq!(width(icons.icon("image.save", 20.0)), 32.0);
+        assert_eq!(width(icons.icon("image.save", 64.0)), 32.0);
+
+        // unknown variants fall back to the images without variant
+        assert_eq!(width(icons.icon("image.save:light", 12.0)), 24.0);
+        assert_eq!(width(icons.icon("image.save:dark", 12.0)), 16.0);
+
+        assert!(icons.unregister("image"));
+        assert!(!icons.contains_set("image"));
+    }
+}
@@ -1,5 +1,9 @@
 //! This module contains global services.
 //!
+pub use self::color_scheme::*;
+pub use self::icons::*;
 pub use self::settings::*;
 
+mod color_scheme;
+mod icons;
 mod settings;
@@ -0,0 +1,64 @@
+use dces::prelude::*;
+
+use crate::{prelude::*, render::RenderContext2D, shell::WindowRequest, tree::Tree};
+
+/// The `AnimationSystem` advances the running storyboards and updates the animated properties before the layout is
+/// calculated and the widgets are drawn.
+#[derive(Constructor)]
+pub struct AnimationSystem {
+    context_provider: ContextProvider,
+}
+
+impl System<Tree, StringComponentStore, RenderContext2D> for AnimationSystem {
+    fn run_with_context(
+        &self,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        _: &mut RenderContext2D,
+    ) {
+        let mut animator = self.context_provider.animator.borrow_mut();
+
+        if animator.is_empty() {
+            return;
+        }
+
+        let root = ecm.entity_store().root();
+
+        let theme = ecm
+            .component_store()
+            .get::<Global>("global", root)
+            .unwrap()
+            .theme
+            .clone();
+
+        let event_queue = &self.context_provider.event_queue;
+
+        let completed = animator.advance(time_stamp(), |target, track, start, time| {
+            // the widget could be removed while it is animated
+            if ecm.component_store().get::<bool>("dirty", target).is_err() {
+                return;
+            }
+
+            let mut widget = WidgetContainer::new(target, ecm, &theme, Some(event_queue));
+
+            if start {
+                track.start(&mut widget);
+            }
+
+            track.apply(&mut widget, time);
+        });
+
+        for (id, caller) in completed {
+            event_queue.borrow_mut().register_event_with_strategy(
+                AnimationCompletedEvent { id },
+                EventStrategy::Direct,
+                caller,
+            );
+        }
+
+        // keeps the window updating until all storyboards are completed
+        self.context_provider
+            .window_sender
+            .send(WindowRequest::Redraw)
+            .expect("AnimationSystem: could not send request to window.");
+    }
+}
@@ -83,7 +83,222 @@ impl EventStateSystem {
         false
     }
 
-    fn process_bottom_up_event(
+    // Checks if the given widget has a handler for the given event.
+    fn has_handler(&self, entity: Entity, event: &EventBox) -> bool {
+        self.context_provider
+            .handler_map
+            .borrow()
+            .get(&entity)
+            .map_or(false, |handlers| {
+                handlers.iter().any(|handler| handler.handles_event(event))
+            })
+    }
+
+    // Sends mouse leave and mouse enter events if the topmost widget under the mouse is changed.
+    fn update_hover(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        // positions of the widgets are not calculated before the first run
+        if self.context_provider.first_run.get() {
+            return false;
+        }
+
+        let hovered_widgets = hit_path(position, ecm);
+        let old_hovered_widgets = self
+            .context_provider
+            .hovered_widgets
+            .replace(hovered_widgets.clone());
+
+        let mut update = false;
+
+        for entity in old_hovered_widgets
+            .iter()
+            .filter(|e| !hovered_widgets.contains(e))
+        {
+            update = self.process_direct(
+                &EventBox::new(MouseLeaveEvent { position }, EventStrategy::Direct, *entity),
+                ecm,
+            ) || update;
+        }
+
+        for entity in hovered_widgets
+            .iter()
+            .rev()
+            .filter(|e| !old_hovered_widgets.contains(e))
+        {
+            update = self.process_direct(
+                &EventBox::new(MouseEnterEvent { position }, EventStrategy::Direct, *entity),
+                ecm,
+            ) || update;
+        }
+
+        update
+    }
+
+    // Calls the handlers of the given widget for the given drag and drop event. Returns `None` if the widget has no
+    // handler for the event otherwise if a handler accepts the event.
+    fn send_drag_event<E: Event>(
+        &self,
+        event: E,
+        entity: Entity,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<bool> {
+        let event = EventBox::new(event, EventStrategy::Direct, entity);
+
+        if !self.has_handler(entity, &event) {
+            return None;
+        }
+
+        self.context_provider
+            .handler_map
+            .borrow()
+            .get(&entity)
+            .map(|handlers| {
+                handlers
+                    .iter()
+                    .filter(|handler| handler.handles_event(&event))
+                    .fold(false, |accepted, handler| {
+                        handler.handle_event(
+                            &mut StatesContext::new(
+                                &mut *self.context_provider.states.borrow_mut(),
+                                ecm,
+                            ),
+                            &event,
+                        ) || accepted
+                    })
+            })
+    }
+
+    // Moves the drag image and sends drag enter, over and leave events to the drop targets under the mouse.
+    fn update_drag(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> bool {
+        let mut session = match self.context_provider.drag_session.borrow().clone() {
+            Some(session) => session,
+            None => return false,
+        };
+
+        if let Some(image) = session.image {
+            if let Ok(margin) = ecm
+                .component_store_mut()
+                .get_mut::<Thickness>("margin", image)
+            {
+                margin.set_left(position.x() + DRAG_IMAGE_OFFSET);
+                margin.set_top(position.y() + DRAG_IMAGE_OFFSET);
+            }
+            mark_as_dirty("margin", image, ecm);
+        }
+
+        // the topmost widget with a drop handler is the drop target
+        let drop_event = EventBox::new(
+            DropEvent {
+                position,
+                data: session.data.clone(),
+            },
+            EventStrategy::Direct,
+            session.source,
+        );
+        let target = hit_test(position, ecm)
+            .into_iter()
+            .find(|node| self.has_handler(*node, &drop_event));
+
+        if target != session.target {
+            if let Some(old_target) = session.target {
+                self.send_drag_event(DragLeaveEvent { position }, old_target, ecm);
+            }
+
+            // a target without drag enter and drag over handlers accepts all payloads
+            session.accepted = target.map_or(false, |target| {
+                self.send_drag_event(
+                    DragEnterEvent {
+                        position,
+                        data: session.data.clone(),
+                    },
+                    target,
+                    ecm,
+                )
+                .unwrap_or(true)
+            });
+            session.target = target;
+        }
+
+        if let Some(target) = target {
+            if let Some(accepted) = self.send_drag_event(
+                DragOverEvent {
+                    position,
+                    data: session.data.clone(),
+                },
+                target,
+                ecm,
+            ) {
+                session.accepted = accepted;
+            }
+        }
+
+        *self.context_provider.drag_session.borrow_mut() = Some(session);
+
+        true
+    }
+
+    // Drops the payload on the current target if it is accepted and finishes the drag and drop operation. Returns
+    // the drag image that should be removed.
+    fn finish_drag(
+        &self,
+        position: Point,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> Option<Entity> {
+        let session = self.context_provider.drag_session.borrow_mut().take()?;
+        let mut dropped = false;
+
+        if let Some(target) = session.target {
+            if session.accepted {
+                dropped = self
+                    .send_drag_event(
+                        DropEvent {
+                            position,
+                            data: session.data.clone(),
+                        },
+                        target,
+                        ecm,
+                    )
+                    .unwrap_or(false);
+            } else {
+                self.send_drag_event(DragLeaveEvent { position }, target, ecm);
+            }
+        }
+
+        self.send_drag_event(DragEndEvent { dropped }, session.source, ecm);
+
+        session.image
+    }
+
+    // Removes the given widget and all of its children.
+    fn remove_widget_tree(
+        &self,
+        entity: Entity,
+        theme: &Theme,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        render_context: &mut RenderContext2D,
+    ) {
+        let mut children = vec![];
+        get_all_children(&mut children, entity, ecm.entity_store());
+
+        // remove children of target widget.
+        for child in children.iter().rev() {
+            self.remove_widget(*child, theme, ecm, render_context);
+        }
+
+        // remove target widget
+        self.remove_widget(entity, theme, ecm, render_context);
+    }
+
+    // Routes the event through the tree. Bottom up events are first tunneled to the preview handlers from the root
+    // to the leaf and then bubbled up to the other handlers. Top down events are only tunneled.
+    fn process_routed_event(
         &self,
         mouse_position: Point,
         event: &EventBox,
@@ -92,16 +307,7 @@ impl EventStateSystem {
         let mut matching_nodes = vec![];
         let mut update = false;
 
-        let mut current_node = event.source;
         let root = ecm.entity_store().root();
-        let mut disabled_parents = vec![];
-
-        let theme = ecm
-            .component_store()
-            .get::<Global>("global", root)
-            .unwrap()
-            .theme
-            .clone();
 
         // global key handling
         if let Ok(event) = event.downcast_ref::<KeyDownEvent>() {
@@ -118,202 +324,154 @@ impl EventStateSystem {
             }
         }
 
-        let mut unknown_event = true;
-        let mut clipped_parent = vec![];
-
-        loop {
-            if !disabled_parents.is_empty() {
-                if let Some(parent) = ecm.entity_store().parent[&current_node] {
-                    if disabled_parents.contains(&parent) {
-                        disabled_parents.push(current_node);
-                    } else {
-                        disabled_parents.clear();
-                    }
-                }
-            }
-            if let Ok(enabled) = ecm.component_store().get::<bool>("enabled", current_node) {
-                if !enabled {
-                    disabled_parents.push(current_node);
-                }
-            }
-
-            if let Ok(visibility) = ecm
-                .component_store()
-                .get::<Visibility>("visibility", current_node)
-            {
-                if *visibility != Visibility::Visible {
-                    disabled_parents.push(current_node);
-                }
-            }
-
-            if disabled_parents.is_empty() {
-                let mut has_handler = false;
-                if let Some(handlers) = self
-                    .context_provider
-                    .handler_map
-                    .borrow()
-                    .get(&current_node)
+        if let Some(position) = pointer_position(mouse_position, event) {
+            // pointer events are only send to the widgets under the mouse, topmost first
+            for node in hit_test(position, ecm) {
+                if self.has_handler(node, event)
+                    && is_descendant_or_self(ecm.entity_store(), node, event.source)
                 {
-                    if handlers.iter().any(|handler| handler.handles_event(event)) {
-                        has_handler = true;
-                    }
-                }
-                if let Some(cp) = clipped_parent.last() {
-                    if ecm.entity_store().parent[&current_node] == Some(*cp) {
-                        clipped_parent.push(current_node);
-                    } else {
-                        clipped_parent.pop();
-                    }
+                    matching_nodes.push(node);
                 }
+            }
+        } else {
+            let mut current_node = event.source;
+            let mut disabled_parents = vec![];
 
-                // scroll handling
-                if event.downcast_ref::<ScrollEvent>().is_ok() {
-                    if check_mouse_condition(
-                        mouse_position,
-                        &WidgetContainer::new(
-                            current_node,
-                            ecm,
-                            &theme,
-                            Some(&self.context_provider.event_queue),
-                        ),
-                    ) && has_handler
-                    {
-                        matching_nodes.push(current_node);
-                    }
-                    unknown_event = false;
-                }
-                // click handling
-                if let Ok(event) = event.downcast_ref::<ClickEvent>() {
-                    if check_mouse_condition(
-                        event.position,
-                        &WidgetContainer::new(
-                            current_node,
-                            ecm,
-                            &theme,
-                            Some(&self.context_provider.event_queue),
-                        ),
-                    ) {
-                        let mut add = true;
-                        if let Some(op) = clipped_parent.get(0) {
-                            if !check_mouse_condition(
-                                event.position,
-                                &WidgetContainer::new(
-                                    *op,
-                                    ecm,
-                                    &theme,
-                                    Some(&self.context_provider.event_queue),
-                                ),
-                            ) {
-                                add = false;
-                            }
-                        }
-                        if add && has_handler {
-                            matching_nodes.push(current_node);
+            loop {
+                if !disabled_parents.is_empty() {
+                    if let Some(parent) = ecm.entity_store().parent[&current_node] {
+                        if disabled_parents.contains(&parent) {
+                            disabled_parents.push(current_node);
+                        } else {
+                            disabled_parents.clear();
                         }
                     }
-                    unknown_event = false;
                 }
-                // mouse down handling
-                if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
-                    if check_mouse_condition(
-                        event.position,
-                        &WidgetContainer::new(
-                            current_node,
-                            ecm,
-                            &theme,
-                            Some(&self.context_provider.event_queue),
-                        ),
-                    ) {
-                        let mut add = true;
-                        if let Some(op) = clipped_parent.get(0) {
-                            // todo: improve check path if exists
-                            if !check_mouse_condition(
-                                event.position,
-                                &WidgetContainer::new(
-                                    *op,
-                                    ecm,
-                                    &theme,
-                                    Some(&self.context_provider.event_queue),
-                                ),
-                            ) && has_handler
-                            {
-                                add = false;
-                            }
-                        }
-                        if add {
-                            matching_nodes.push(current_node);
-                        }
+                if let Ok(enabled) = ecm.component_store().get::<bool>("enabled", current_node) {
+                    if !enabled {
+                        disabled_parents.push(current_node);
                     }
-                    unknown_event = false;
                 }
-                // mouse move handling
-                if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
-                    if check_mouse_condition(
-                        event.position,
-                        &WidgetContainer::new(
-                            current_node,
-                            ecm,
-                            &theme,
-                            Some(&self.context_provider.event_queue),
-                        ),
-                    ) {
-                        let mut add = true;
-                        if let Some(op) = clipped_parent.get(0) {
-                            // todo: improve check path if exists
-                            if !check_mouse_condition(
-                                event.position,
-                                &WidgetContainer::new(
-                                    *op,
-                                    ecm,
-                                    &theme,
-                                    Some(&self.context_provider.event_queue),
-                                ),
-                            ) {
-                                add = false;
-                            }
-                        }
-                        if add && has_handler {
-                            matching_nodes.push(current_node);
-                        }
+
+                if let Ok(visibility) = ecm
+                    .component_store()
+                    .get::<Visibility>("visibility", current_node)
+                {
+                    if *visibility != Visibility::Visible {
+                        disabled_parents.push(current_node);
                     }
-                    unknown_event = false;
                 }
 
-                if unknown_event
-                    && *WidgetContainer::new(
-                        current_node,
-                        ecm,
-                        &theme,
-                        Some(&self.context_provider.event_queue),
-                    )
-                    .get::<bool>("enabled")
-                    && has_handler
-                {
+                if disabled_parents.is_empty() && self.has_handler(current_node, event) {
                     matching_nodes.push(current_node);
                 }
-                if let Ok(clip) = ecm.component_store().get::<bool>("clip", current_node) {
-                    if *clip {
-                        clipped_parent.clear();
-                        clipped_parent.push(current_node);
-                    }
+
+                let mut it = ecm.entity_store().start_node(current_node).into_iter();
+                it.next();
+
+                if let Some(node) = it.next() {
+                    current_node = node;
+                } else {
+                    break;
                 }
             }
 
-            let mut it = ecm.entity_store().start_node(current_node).into_iter();
-            it.next();
+            // handle the event from the leaf to the root
+            matching_nodes.reverse();
+        }
 
-            if let Some(node) = it.next() {
-                current_node = node;
-            } else {
-                break;
+        // input is only sent to the topmost modal on the overlay
+        if let Some(modal) = self.context_provider.modal_overlays.borrow().last() {
+            if pointer_position(mouse_position, event).is_some() || is_key_input(event) {
+                matching_nodes
+                    .retain(|node| is_descendant_or_self(ecm.entity_store(), *node, modal.layer));
+            }
+        }
+
+        // the widget that captured the mouse receives all move and up events, also outside of its bounds
+        if event.is_type::<MouseMoveEvent>() || event.is_type::<MouseUpEvent>() {
+            if let Some(captured) = self.context_provider.mouse_capture.get() {
+                if ecm.entity_store().parent.contains_key(&captured) {
+                    let mut captured_path: Vec<Entity> = path_to_root(ecm.entity_store(), captured)
+                        .into_iter()
+                        .filter(|node| self.has_handler(*node, event))
+                        .collect();
+
+                    // mouse up events are also sent to the other widgets, e.g. to reset the pressed state
+                    if event.is_type::<MouseUpEvent>() {
+                        captured_path.extend(
+                            matching_nodes
+                                .iter()
+                                .filter(|node| !captured_path.contains(*node))
+                                .cloned()
+                                .collect::<Vec<Entity>>(),
+                        );
+                    }
+
+                    matching_nodes = captured_path;
+                } else {
+                    // the captured widget was removed
+                    self.context_provider.mouse_capture.set(None);
+                }
             }
         }
 
-        let mut handled = false;
+        if event.strategy == EventStrategy::TopDown {
+            // top down events are send to all handlers from the root to the leaf
+            let (_, should_update) =
+                self.dispatch(matching_nodes.iter().rev(), event, |_| true, ecm);
+            return update || should_update;
+        }
+
+        // tunneling phase: preview handlers are called from the root to the leaf
+        let (handled, should_update) = self.dispatch(
+            matching_nodes.iter().rev(),
+            event,
+            |handler| handler.is_preview(),
+            ecm,
+        );
+        update = update || should_update;
+
+        if handled {
+            return update;
+        }
+
+        // bubbling phase: the other handlers are called from the leaf to the root
+        let (_, should_update) = self.dispatch(
+            matching_nodes.iter(),
+            event,
+            |handler| !handler.is_preview(),
+            ecm,
+        );
+
+        update || should_update
+    }
+
+    // Calls the handlers of the given nodes that matches the filter until the event is handled. Returns if the event
+    // is handled and if an update is needed.
+    fn dispatch<'a, F: Fn(&dyn EventHandler) -> bool>(
+        &self,
+        nodes: impl Iterator<Item = &'a Entity>,
+        event: &EventBox,
+        filter: F,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+    ) -> (bool, bool) {
+        let mut update = false;
 
-        for node in matching_nodes.iter().rev() {
+        for node in nodes {
             if let Some(handlers) = self.context_provider.handler_map.borrow().get(node) {
-                handled = handlers.iter().any(|handler| {
+                let mut handlers = handlers
+                    .iter()
+                    .filter(|handler| filter(handler.as_ref()) && handler.handles_event(event))
+                    .peekable();
+
+                if handlers.peek().is_none() {
+                    continue;
+                }
+
+                update = true;
+
+                if handlers.any(|handler| {
                     handler.handle_event(
                         &mut StatesContext::new(
                             &mut *self.context_provider.states.borrow_mut(),
@@ -321,17 +479,13 @@ impl EventStateSystem {
                         ),
                         event,
                     )
-                });
-
-                update = true;
-            }
-
-            if handled {
-                break;
+                }) {
+                    return (true, update);
+                }
             }
         }
 
-        update
+        (false, update)
     }
 }
 
@@ -344,6 +498,9 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
         let mut update = false;
 
         loop {
+            // drag images of finished drag and drop operations
+            let mut drag_images = vec![];
+
             {
                 let mouse_position = self.context_provider.mouse_position.get();
                 for event in self.context_provider.event_queue.borrow_mut().into_iter() {
@@ -356,21 +513,48 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                         }
                     }
 
+                    if let Ok(move_event) = event.downcast_ref::<MouseMoveEvent>() {
+                        update = self.update_hover(move_event.position, ecm) || update;
+                        update = self.update_drag(move_event.position, ecm) || update;
+                    }
+
+                    if let Ok(up_event) = event.downcast_ref::<MouseUpEvent>() {
+                        if self.context_provider.drag_session.borrow().is_some() {
+                            update = true;
+                            if let Some(image) = self.finish_drag(up_event.position, ecm) {
+                                drag_images.push(image);
+                            }
+                        }
+                    }
+
                     match event.strategy {
                         EventStrategy::Direct => {
                             if event.strategy == EventStrategy::Direct {
                                 update = self.process_direct(&event, ecm) || update;
                             }
                         }
-                        // EventStrategy::TopDown => {
-                        //     self.process_top_down_event(&event, ecm);
-                        // }
-                        EventStrategy::BottomUp => {
+                        EventStrategy::TopDown | EventStrategy::BottomUp => {
                             let should_update =
-                                self.process_bottom_up_event(mouse_position, &event, ecm);
+                                self.process_routed_event(mouse_position, &event, ecm);
                             update = update || should_update;
                         }
                     }
+
+                    // the result handlers of modals and animations and the handlers of an animation frame are only
+                    // called once
+                    if event.is_type::<ModalResultEvent>()
+                        || event.is_type::<AnimationCompletedEvent>()
+                        || event.is_type::<AnimationFrameEvent>()
+                    {
+                        if let Some(handlers) = self
+                            .context_provider
+                            .handler_map
+                            .borrow_mut()
+                            .get_mut(&event.source)
+                        {
+                            handlers.retain(|handler| !handler.handles_event(&event));
+                        }
+                    }
                 }
             }
 
@@ -387,6 +571,13 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                 .theme
                 .clone();
 
+            for image in drag_images {
+                if let Some(overlay) = ecm.entity_store().overlay {
+                    mark_as_dirty("bounds", overlay, ecm);
+                }
+                self.remove_widget_tree(image, &theme, ecm, render_context);
+            }
+
             let mut remove_widget_list: Vec<Entity> = vec![];
 
             let mut dirty_index = 0;
@@ -457,16 +648,7 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
                     }
 
                     while let Some(remove_widget) = remove_widget_list.pop() {
-                        let mut children = vec![];
-                        get_all_children(&mut children, remove_widget, ecm.entity_store());
-
-                        // remove children of target widget.
-                        for entity in children.iter().rev() {
-                            self.remove_widget(*entity, &theme, ecm, render_context);
-                        }
-
-                        // remove target widget
-                        self.remove_widget(remove_widget, &theme, ecm, render_context);
+                        self.remove_widget_tree(remove_widget, &theme, ecm, render_context);
                     }
                 }
 
@@ -481,3 +663,378 @@ impl System<Tree, StringComponentStore, RenderContext2D> for EventStateSystem {
         }
     }
 }
+
+// -- Helpers --
+
+// Checks if the event is keyboard or text input.
+fn is_key_input(event: &EventBox) -> bool {
+    event.is_type::<KeyDownEvent>()
+        || event.is_type::<KeyUpEvent>()
+        || event.is_type::<TextInputEvent>()
+}
+
+// Returns the position of the mouse for events that should be handled by the widgets under the mouse.
+fn pointer_position(mouse_position: Point, event: &EventBox) -> Option<Point> {
+    if event.is_type::<ScrollEvent>() {
+        return Some(mouse_position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<ClickEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<LongPressEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<MouseDownEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<MouseMoveEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TouchStartEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TouchMoveEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TouchEndEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TouchCancelEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<TapEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<PanEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<PinchEvent>() {
+        return Some(event.center);
+    }
+
+    if let Ok(event) = event.downcast_ref::<SwipeEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<FileHoverEvent>() {
+        return Some(event.position);
+    }
+
+    if let Ok(event) = event.downcast_ref::<DropFileEvent>() {
+        return Some(event.position);
+    }
+
+    None
+}
+
+// -- Helpers --
+
+#[cfg(test)]
+mod tests {
+    use std::{marker::PhantomData, sync::mpsc};
+
+    use super::*;
+    use crate::proc_macros::Event;
+
+    #[derive(Event)]
+    struct TestEvent;
+
+    // Records its calls and handles the event if `handled` is set.
+    struct TestHandler<E: Event> {
+        name: &'static str,
+        handled: bool,
+        calls: Rc<RefCell<Vec<&'static str>>>,
+        event: PhantomData<E>,
+    }
+
+    impl<E: Event> EventHandler for TestHandler<E> {
+        fn handle_event(&self, _: &mut StatesContext, _: &EventBox) -> bool {
+            self.calls.borrow_mut().push(self.name);
+            self.handled
+        }
+
+        fn handles_event(&self, event: &EventBox) -> bool {
+            event.is_type::<E>()
+        }
+    }
+
+    fn handler<E: Event>(
+        name: &'static str,
+        handled: bool,
+        calls: &Rc<RefCell<Vec<&'static str>>>,
+    ) -> TestHandler<E> {
+        TestHandler {
+            name,
+            handled,
+            calls: calls.clone(),
+            event: PhantomData,
+        }
+    }
+
+    fn create_system() -> EventStateSystem {
+        let (window_sender, _) = mpsc::channel();
+        let (shell_sender, _) = mpsc::channel();
+        let registry = Rc::new(RefCell::new(Registry::new()));
+
+        EventStateSystem::new(
+            ContextProvider::new(
+                window_sender,
+                shell_sender,
+                "test",
+                registry.clone(),
+                MessageBus::new(),
+            ),
+            registry,
+        )
+    }
+
+    fn add_handler(
+        system: &EventStateSystem,
+        entity: Entity,
+        handler: impl EventHandler + 'static,
+        preview: bool,
+    ) {
+        let handler: Rc<dyn EventHandler> = if preview {
+            Rc::new(PreviewEventHandler::new(
+                Rc::new(handler) as Rc<dyn EventHandler>
+            ))
+        } else {
+            Rc::new(handler)
+        };
+
+        system
+            .context_provider
+            .handler_map
+            .borrow_mut()
+            .entry(entity)
+            .or_default()
+            .push(handler);
+    }
+
+    // Creates the tree root -> parent -> child. Each widget gets a preview and a bubbling handler.
+    fn create_tree(
+        system: &EventStateSystem,
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        calls: &Rc<RefCell<Vec<&'static str>>>,
+        handled_by: &str,
+    ) -> Entity {
+        let root = ecm.create_entity().build();
+        let parent = ecm.create_entity().build();
+        let child = ecm.create_entity().build();
+        ecm.entity_store_mut().set_root(root);
+        ecm.entity_store_mut().append_child(root, parent).unwrap();
+        ecm.entity_store_mut().append_child(parent, child).unwrap();
+
+        for (entity, preview, name) in [
+            (root, true, "root preview"),
+            (parent, true, "parent preview"),
+            (child, true, "child preview"),
+            (root, false, "root"),
+            (parent, false, "parent"),
+            (child, false, "child"),
+        ] {
+            add_handler(
+                system,
+                entity,
+                handler::<TestEvent>(name, name == handled_by, calls),
+                preview,
+            );
+        }
+
+        root
+    }
+
+    fn route(handled_by: &str, strategy: EventStrategy) -> Vec<&'static str> {
+        let system = create_system();
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+        let calls = Rc::new(RefCell::new(vec![]));
+        let root = create_tree(&system, ecm, &calls, handled_by);
+
+        assert!(system.process_routed_event(
+            Point::default(),
+            &EventBox::new(TestEvent, strategy, root),
+            ecm
+        ));
+
+        calls.replace(vec![])
+    }
+
+    #[test]
+    fn test_preview() {
+        // preview handlers run from the root to the target before the event bubbles up
+        assert_eq!(
+            route("", EventStrategy::BottomUp),
+            vec![
+                "root preview",
+                "parent preview",
+                "child preview",
+                "child",
+                "parent",
+                "root"
+            ]
+        );
+
+        // a handled preview event stops the tunneling and the bubbling
+        assert_eq!(
+            route("parent preview", EventStrategy::BottomUp),
+            vec!["root preview", "parent preview"]
+        );
+
+        // a handled event stops the bubbling
+        assert_eq!(
+            route("parent", EventStrategy::BottomUp),
+            vec![
+                "root preview",
+                "parent preview",
+                "child preview",
+                "child",
+                "parent"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_top_down() {
+        // all handlers of a widget are called from the root to the leaf
+        assert_eq!(
+            route("", EventStrategy::TopDown),
+            vec![
+                "root preview",
+                "root",
+                "parent preview",
+                "parent",
+                "child preview",
+                "child"
+            ]
+        );
+
+        assert_eq!(
+            route("parent preview", EventStrategy::TopDown),
+            vec!["root preview", "root", "parent preview"]
+        );
+    }
+
+    fn create_widget(
+        ecm: &mut EntityComponentManager<Tree, StringComponentStore>,
+        bounds: (f64, f64, f64, f64),
+    ) -> Entity {
+        let entity = ecm.create_entity().build();
+        let store = ecm.component_store_mut();
+        store.register(
+            "bounds",
+            entity,
+            Rectangle::from((0.0, 0.0, bounds.2, bounds.3)),
+        );
+        store.register("position", entity, Point::new(bounds.0, bounds.1));
+        store.register("enabled", entity, true);
+        store.register("clip", entity, false);
+        store.register("visibility", entity, Visibility::Visible);
+        entity
+    }
+
+    #[test]
+    fn test_drag_drop() {
+        let system = create_system();
+        let mut world: World<Tree, StringComponentStore, RenderContext2D> =
+            World::from_stores(Tree::default(), StringComponentStore::default());
+        let ecm = world.entity_component_manager();
+        let calls = Rc::new(RefCell::new(vec![]));
+
+        let root = create_widget(ecm, (0.0, 0.0, 100.0, 100.0));
+        let first = create_widget(ecm, (0.0, 0.0, 50.0, 50.0));
+        let second = create_widget(ecm, (50.0, 0.0, 50.0, 50.0));
+        ecm.entity_store_mut().set_root(root);
+        ecm.entity_store_mut().append_child(root, first).unwrap();
+        ecm.entity_store_mut().append_child(root, second).unwrap();
+
+        // the first target accepts the payload on enter but rejects it on over, the second one only has a drop
+        // handler
+        add_handler(
+            &system,
+            first,
+            handler::<DragEnterEvent>("first enter", true, &calls),
+            false,
+        );
+        add_handler(
+            &system,
+            first,
+            handler::<DragOverEvent>("first over", false, &calls),
+            false,
+        );
+        add_handler(
+            &system,
+            first,
+            handler::<DragLeaveEvent>("first leave", false, &calls),
+            false,
+        );
+        add_handler(
+            &system,
+            first,
+            handler::<DropEvent>("first drop", true, &calls),
+            false,
+        );
+        add_handler(
+            &system,
+            second,
+            handler::<DropEvent>("second drop", true, &calls),
+            false,
+        );
+        add_handler(
+            &system,
+            root,
+            handler::<DragEndEvent>("end", false, &calls),
+            false,
+        );
+
+        let session = |system: &EventStateSystem| {
+            system
+                .context_provider
+                .drag_session
+                .borrow()
+                .clone()
+                .unwrap()
+        };
+
+        *system.context_provider.drag_session.borrow_mut() =
+            Some(DragSession::new(root, DragData::new(1), None));
+
+        assert!(system.update_drag(Point::new(10.0, 10.0), ecm));
+        assert_eq!(calls.replace(vec![]), vec!["first enter", "first over"]);
+        assert_eq!(session(&system).target, Some(first));
+        assert!(!session(&system).accepted);
+
+        // a target without drag enter and drag over handlers accepts the payload
+        system.update_drag(Point::new(60.0, 10.0), ecm);
+        assert_eq!(calls.replace(vec![]), vec!["first leave"]);
+        assert_eq!(session(&system).target, Some(second));
+        assert!(session(&system).accepted);
+
+        assert_eq!(system.finish_drag(Point::new(60.0, 10.0), ecm), None);
+        assert_eq!(calls.replace(vec![]), vec!["second drop", "end"]);
+        assert!(system.context_provider.drag_session.borrow().is_none());
+
+        // a rejected payload is not dropped
+        *system.context_provider.drag_session.borrow_mut() =
+            Some(DragSession::new(root, DragData::new(1), None));
+
+        system.update_drag(Point::new(10.0, 10.0), ecm);
+        system.finish_drag(Point::new(10.0, 10.0), ecm);
+        assert_eq!(
+            calls.replace(vec![]),
+            vec!["first enter", "first over", "first leave", "end"]
+        );
+    }
+}
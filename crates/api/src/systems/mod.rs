@@ -1,6 +1,7 @@
 //! Contains all system used in OrbTk. Systems are meant as systems in OrbTks Entity Component System.
 //! These are used for event handling, building layout and drawing.
 
+pub use self::animation_system::*;
 pub use self::cleanup_system::*;
 pub use self::event_state_system::*;
 pub use self::init_system::*;
@@ -8,6 +9,7 @@ pub use self::layout_system::*;
 pub use self::post_layout_state_system::*;
 pub use self::render_system::*;
 
+mod animation_system;
 mod cleanup_system;
 mod event_state_system;
 mod init_system;
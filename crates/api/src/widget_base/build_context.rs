@@ -2,7 +2,12 @@ use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
 
 use dces::prelude::*;
 
-use crate::{prelude::*, render_object::RenderObject, theming::Theme, tree::Tree};
+use crate::{
+    prelude::*,
+    render_object::RenderObject,
+    theming::{Theme, ThemeScope},
+    tree::Tree,
+};
 
 use super::State;
 
@@ -42,6 +47,36 @@ impl<'a> BuildContext<'a> {
             .entity_store_mut()
             .append_child(parent, child)
             .unwrap();
+
+        // the styles of the child could depend on its new ancestors
+        if self.theme.has_descendant_rules() || self.is_in_theme_scope(parent) {
+            self.get_widget(child).update(true);
+        }
+    }
+
+    // Checks if a theme scope is attached to the given widget or one of its ancestors.
+    fn is_in_theme_scope(&self, entity: Entity) -> bool {
+        let mut current = Some(entity);
+
+        while let Some(entity) = current {
+            if let Ok(Some(_)) = self
+                .ecm
+                .component_store()
+                .get::<Option<ThemeScope>>("theme_scope", entity)
+            {
+                return true;
+            }
+
+            current = self
+                .ecm
+                .entity_store()
+                .parent
+                .get(&entity)
+                .copied()
+                .flatten();
+        }
+
+        false
     }
 
     /// Appends a child to overlay (on the top of the main tree). If the overlay does not exists an
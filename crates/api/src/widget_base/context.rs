@@ -1,9 +1,11 @@
-use std::{collections::BTreeMap, sync::mpsc};
+use std::{any::Any, collections::BTreeMap, sync::mpsc};
 
 use dces::prelude::*;
 
 use crate::{
-    application::{create_window, ContextProvider},
+    application::{
+        create_window, ContextProvider, MessageBus, ModalLayer, ModalOverlay, ModalParent,
+    },
     prelude::*,
     render::RenderContext2D,
     shell::{ShellRequest, WindowRequest},
@@ -65,6 +67,7 @@ impl<'a> Context<'a> {
             &self.theme,
             Some(&self.provider.event_queue),
         )
+        .with_animator(&self.provider.animator)
     }
 
     /// Returns the widget of the current state ctx.
@@ -429,12 +432,78 @@ impl<'a> Context<'a> {
             .register_event_with_strategy(event, strategy, entity);
     }
 
+    // -- Mouse capture --
+
+    /// Captures the mouse for the current widget. Until the capture is released the widget and its parents receive
+    /// all mouse move and mouse up events, also if the mouse is outside of its bounds.
+    pub fn capture_mouse(&mut self) {
+        self.provider.mouse_capture.set(Some(self.entity));
+    }
+
+    /// Releases the mouse capture if it is hold by the current widget.
+    pub fn release_mouse_capture(&mut self) {
+        if self.has_mouse_capture() {
+            self.provider.mouse_capture.set(None);
+        }
+    }
+
+    /// Checks if the current widget has captured the mouse.
+    pub fn has_mouse_capture(&self) -> bool {
+        self.provider.mouse_capture.get() == Some(self.entity)
+    }
+
+    // -- Drag and drop --
+
+    /// Starts a drag and drop operation with the current widget as source. The operation ends on the next mouse up.
+    pub fn start_drag(&mut self, data: DragData) {
+        *self.provider.drag_session.borrow_mut() = Some(DragSession::new(self.entity, data, None));
+    }
+
+    /// Starts a drag and drop operation and displays the given widget as drag image on the overlay next to the mouse.
+    pub fn start_drag_with_image<W: Widget>(&mut self, data: DragData, image: W) {
+        let overlay = if let Some(overlay) = self.ecm.entity_store().overlay {
+            overlay
+        } else {
+            self.start_drag(data);
+            return;
+        };
+
+        let image = image.build(&mut self.build_context());
+        self.append_child_entity_to(image, overlay);
+
+        let position = self.provider.mouse_position.get();
+        let store = self.ecm.component_store_mut();
+        store.register("h_align", image, Alignment::Start);
+        store.register("v_align", image, Alignment::Start);
+        store.register(
+            "margin",
+            image,
+            Thickness::new(
+                position.x() + DRAG_IMAGE_OFFSET,
+                position.y() + DRAG_IMAGE_OFFSET,
+                0.0,
+                0.0,
+            ),
+        );
+
+        *self.provider.drag_session.borrow_mut() =
+            Some(DragSession::new(self.entity, data, Some(image)));
+    }
+
+    /// Checks if a drag and drop operation is running.
+    pub fn is_dragging(&self) -> bool {
+        self.provider.drag_session.borrow().is_some()
+    }
+
     /// Creates and show a new window.
     pub fn show_window<F: Fn(&mut BuildContext) -> Entity + 'static>(&mut self, create_fn: F) {
         let (adapter, settings, receiver) = create_window(
             self.provider.application_name.clone(),
             self.theme.clone(),
             self.provider.shell_sender.clone(),
+            self.provider.registry.clone(),
+            self.provider.message_bus.clone(),
+            None,
             create_fn,
         );
         self.provider
@@ -443,6 +512,280 @@ impl<'a> Context<'a> {
             .expect("Context.show_window: Could not send shell request.");
     }
 
+    // -- Modals --
+
+    /// Creates and shows a modal window centered on the current window. Input to the current window is blocked until
+    /// the modal window is closed. The result of `Context::close_modal` is passed to `on_result`, it is `None` if the
+    /// window is closed without a result. On the web the modal is displayed on the overlay of the current window.
+    pub fn show_modal_window<F, R, H>(&mut self, create_fn: F, on_result: H)
+    where
+        F: Fn(&mut BuildContext) -> Entity + 'static,
+        R: Any + Clone,
+        H: Fn(&mut StatesContext, Option<R>) + 'static,
+    {
+        #[cfg(target_arch = "wasm32")]
+        {
+            self.show_modal(create_fn, on_result);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let id = self.insert_modal_result_handler(on_result);
+            let size = self.window().clone::<Rectangle>("bounds");
+            let parent = ModalParent::new(
+                id,
+                self.entity,
+                self.provider.event_queue.clone(),
+                self.provider.window_sender.clone(),
+                self.provider.modal_windows.clone(),
+                Rectangle::new(
+                    self.provider.window_position.get(),
+                    (size.width(), size.height()),
+                ),
+            );
+
+            let (adapter, settings, receiver) = create_window(
+                self.provider.application_name.clone(),
+                self.theme.clone(),
+                self.provider.shell_sender.clone(),
+                self.provider.registry.clone(),
+                self.provider.message_bus.clone(),
+                Some(parent),
+                create_fn,
+            );
+            self.provider
+                .shell_sender
+                .send(ShellRequest::CreateWindow(adapter, settings, receiver))
+                .expect("Context.show_modal_window: Could not send shell request.");
+        }
+    }
+
+    /// Shows a modal on the overlay of the current window. The content is centered on a `ModalLayer` that blocks
+    /// input to the other widgets of the window until the modal is closed. The result of `Context::close_modal` is
+    /// passed to `on_result`.
+    pub fn show_modal<F, R, H>(&mut self, create_fn: F, on_result: H)
+    where
+        F: Fn(&mut BuildContext) -> Entity + 'static,
+        R: Any + Clone,
+        H: Fn(&mut StatesContext, Option<R>) + 'static,
+    {
+        let overlay = if let Some(overlay) = self.ecm.entity_store().overlay {
+            overlay
+        } else {
+            return;
+        };
+
+        let id = self.insert_modal_result_handler(on_result);
+
+        let (layer, content) = {
+            let bctx = &mut self.build_context();
+            let content = create_fn(bctx);
+            let layer = ModalLayer::new().build(bctx);
+            bctx.append_child(layer, content);
+            (layer, content)
+        };
+
+        let store = self.ecm.component_store_mut();
+        store.register("h_align", content, Alignment::Center);
+        store.register("v_align", content, Alignment::Center);
+
+        self.append_child_entity_to(layer, overlay);
+        self.provider.mouse_capture.set(None);
+        self.provider
+            .modal_overlays
+            .borrow_mut()
+            .push(ModalOverlay {
+                id,
+                caller: self.entity,
+                layer,
+            });
+    }
+
+    /// Closes the modal that contains the current widget and sends the result to the widget that has opened it.
+    pub fn close_modal<R: Any>(&mut self, result: R) {
+        self.finish_modal(Some(Message::new(result)));
+    }
+
+    /// Closes the modal that contains the current widget without a result.
+    pub fn cancel_modal(&mut self) {
+        self.finish_modal(None);
+    }
+
+    /// Checks if input of the current window is blocked by a modal.
+    pub fn is_modal_open(&self) -> bool {
+        self.provider.modal_windows.get() > 0 || !self.provider.modal_overlays.borrow().is_empty()
+    }
+
+    fn finish_modal(&mut self, result: Option<Message>) {
+        let index = {
+            let tree = self.ecm.entity_store();
+            self.provider
+                .modal_overlays
+                .borrow()
+                .iter()
+                .rposition(|modal| is_descendant_or_self(tree, self.entity, modal.layer))
+        };
+
+        if let Some(index) = index {
+            let modal = self.provider.modal_overlays.borrow_mut().remove(index);
+            self.remove_child_from_overlay(modal.layer).ok();
+            self.provider
+                .event_queue
+                .borrow_mut()
+                .register_event_with_strategy(
+                    ModalResultEvent {
+                        id: modal.id,
+                        result,
+                    },
+                    EventStrategy::Direct,
+                    modal.caller,
+                );
+            return;
+        }
+
+        let parent = self.provider.modal_parent.borrow_mut().take();
+
+        if let Some(parent) = parent {
+            parent.finish(result);
+            self.send_window_request(WindowRequest::Close);
+        }
+    }
+
+    // Inserts a handler on the current widget that receives the result of a modal. Returns the id of the modal.
+    fn insert_modal_result_handler<R, H>(&mut self, on_result: H) -> u32
+    where
+        R: Any + Clone,
+        H: Fn(&mut StatesContext, Option<R>) + 'static,
+    {
+        let id = self.provider.next_modal_id.get();
+        self.provider.next_modal_id.set(id + 1);
+
+        self.provider
+            .handler_map
+            .borrow_mut()
+            .entry(self.entity)
+            .or_insert_with(Vec::new)
+            .push(Rc::new(ModalResultEventHandler {
+                id,
+                handler: Rc::new(move |ctx: &mut StatesContext, result: Option<&Message>| {
+                    on_result(ctx, result.and_then(|result| result.get::<R>().cloned()))
+                }),
+            }));
+
+        id
+    }
+
+    // -- Animation frames --
+
+    /// Requests a new frame for the current widget. Before the next frame is drawn `on_frame` is called with its time
+    /// stamp and the elapsed time since the last frame, and the widget is updated and redrawn. The request is
+    /// handled once, request again e.g. in `State::update` to draw continuously. The window keeps updating only while
+    /// frames are requested.
+    pub fn request_animation_frame<H: Fn(&mut StatesContext, AnimationFrame) + 'static>(
+        &mut self,
+        on_frame: H,
+    ) {
+        self.provider
+            .handler_map
+            .borrow_mut()
+            .entry(self.entity)
+            .or_insert_with(Vec::new)
+            .push(Rc::new(AnimationFrameEventHandler {
+                handler: Rc::new(on_frame),
+            }));
+
+        let mut animation_frames = self.provider.animation_frames.borrow_mut();
+
+        if !animation_frames.contains(&self.entity) {
+            animation_frames.push(self.entity);
+        }
+
+        self.send_window_request(WindowRequest::Redraw);
+    }
+
+    /// Checks if the current widget has requested a frame that is not handled yet.
+    pub fn is_animation_frame_requested(&self) -> bool {
+        self.provider
+            .animation_frames
+            .borrow()
+            .contains(&self.entity)
+    }
+
+    // -- Animations --
+
+    /// Starts the given storyboard with the next frame and returns its id. The completed handler of the storyboard
+    /// is called on the current widget.
+    pub fn start_storyboard(&mut self, mut storyboard: Storyboard) -> u32 {
+        let on_completed = storyboard.take_completed_handler();
+        let id = self
+            .provider
+            .animator
+            .borrow_mut()
+            .start(self.entity, storyboard);
+
+        if let Some(handler) = on_completed {
+            self.provider
+                .handler_map
+                .borrow_mut()
+                .entry(self.entity)
+                .or_insert_with(Vec::new)
+                .push(Rc::new(AnimationCompletedEventHandler { id, handler }));
+        }
+
+        self.send_window_request(WindowRequest::Redraw);
+
+        id
+    }
+
+    /// Starts a storyboard with the given animation of the target widget and returns its id.
+    pub fn animate(&mut self, target: Entity, animation: impl AnimationTrack + 'static) -> u32 {
+        self.start_storyboard(Storyboard::new().animate(target, animation))
+    }
+
+    /// Stops the storyboard with the given id. The animated properties keep their current values and the completed
+    /// handler is not called.
+    pub fn stop_storyboard(&mut self, id: u32) {
+        let caller = match self.provider.animator.borrow_mut().stop(id) {
+            Some(caller) => caller,
+            None => return,
+        };
+
+        if let Some(handlers) = self.provider.handler_map.borrow_mut().get_mut(&caller) {
+            let event = EventBox::new(
+                AnimationCompletedEvent { id },
+                EventStrategy::Direct,
+                caller,
+            );
+            handlers.retain(|handler| !handler.handles_event(&event));
+        }
+    }
+
+    /// Checks if the storyboard with the given id is running.
+    pub fn is_storyboard_running(&self, id: u32) -> bool {
+        self.provider.animator.borrow().is_running(id)
+    }
+
+    /// Returns the message bus that is shared by all windows of the application.
+    pub fn message_bus(&self) -> MessageBus {
+        self.provider.message_bus.clone()
+    }
+
+    /// Sends a message to the window with the given id. Returns `false` if there is no window with the id.
+    pub fn send_message<M: Any>(&mut self, window_id: &str, message: M) -> bool {
+        let sender = self.window().try_clone::<String>("id");
+        self.provider
+            .message_bus
+            .send(sender.as_deref(), window_id, Message::new(message))
+    }
+
+    /// Sends a message to all other windows of the application.
+    pub fn broadcast_message<M: Any>(&mut self, message: M) {
+        let sender = self.window().try_clone::<String>("id");
+        self.provider
+            .message_bus
+            .broadcast(sender.as_deref(), Message::new(message));
+    }
+
     /// Returns a mutable reference of the 2d render ctx.
     pub fn render_context_2_d(&mut self) -> &mut RenderContext2D {
         self.render_context
@@ -461,6 +804,12 @@ impl<'a> Context<'a> {
         self.provider.window_sender.clone()
     }
 
+    /// Gets the scale factor of the window. Sizes and positions of widgets are logical units, physical pixels are
+    /// logical units multiplied by the scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        self.provider.scale_factor.get()
+    }
+
     /// Returns a keys collection of new added states.
     pub fn new_states_keys(&self) -> Vec<Entity> {
         self.new_states.keys().cloned().collect()
@@ -475,6 +824,52 @@ impl<'a> Context<'a> {
         // update on window to update all widgets in the tree
         self.window().update_dirty(true);
     }
+
+    /// Switches the density of the current theme, e.g. `Density::Compact` for data-heavy screens. Paddings, margins,
+    /// font sizes and control heights of all widgets are rescaled. The density is kept if the theme is switched by the
+    /// color scheme or a theme watcher.
+    pub fn set_density(&mut self, density: Density) {
+        let theme = self.theme.clone().with_density(density);
+        self.switch_theme(theme);
+    }
+
+    /// Attaches a theme scope to the given widget that styles the widget and its descendants or removes the scope if it
+    /// is `None`. The widget and its descendants are restyled.
+    pub fn set_theme_scope(&mut self, entity: Entity, theme_scope: Option<ThemeScope>) {
+        self.ecm
+            .component_store_mut()
+            .register("theme_scope", entity, theme_scope);
+
+        self.get_widget(entity).update_dirty(true);
+    }
+
+    /// Watches the theme file of the given watcher and switches to the theme each time the file is changed. Errors of
+    /// the file are logged as warnings and the current theme is kept, the last error is returned by
+    /// `theme_watcher_error`. Should be used only during development.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// ctx.watch_theme(ThemeWatcher::new("assets/my_app.ron").extend(ThemeConfig::from(COLORS_RON)));
+    /// ```
+    pub fn watch_theme(&mut self, watcher: ThemeWatcher) {
+        *self.provider.theme_watcher.borrow_mut() = Some(watcher);
+        self.send_window_request(WindowRequest::Redraw);
+    }
+
+    /// Returns the error of the watched theme file if the theme could not be reloaded.
+    pub fn theme_watcher_error(&self) -> Option<String> {
+        self.provider
+            .theme_watcher
+            .borrow()
+            .as_ref()
+            .and_then(|watcher| watcher.last_error().map(String::from))
+    }
+
+    /// Stops watching the theme file. The current theme is kept.
+    pub fn stop_watching_theme(&mut self) {
+        *self.provider.theme_watcher.borrow_mut() = None;
+    }
 }
 
 // -- Helpers --
@@ -10,6 +10,7 @@ pub use self::registry::*;
 pub use self::state::*;
 pub use self::states_context::*;
 pub use self::template::*;
+pub use self::theme_property::*;
 pub use self::widget_container::*;
 
 mod build_context;
@@ -18,9 +19,11 @@ mod registry;
 mod state;
 mod states_context;
 mod template;
+mod theme_property;
 mod widget_container;
 
-/// Toggles the selector state`.
+/// Adds the flag as state to the selector if the flag property is `true`, otherwise the state is removed. Other
+/// states of the selector are kept.
 pub fn toggle_flag(flag: &str, widget: &mut WidgetContainer) {
     if !widget.has::<bool>(flag) {
         return;
@@ -29,11 +32,7 @@ pub fn toggle_flag(flag: &str, widget: &mut WidgetContainer) {
     let value = *widget.get::<bool>(flag);
 
     if let Some(selector) = widget.try_get_mut::<Selector>("selector") {
-        if value {
-            selector.set_state(flag);
-        } else {
-            selector.clear_state();
-        }
+        selector.set_state_active(flag, value);
     }
 }
 
@@ -0,0 +1,344 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+};
+
+use dces::prelude::Component;
+
+use crate::{
+    animation::Interpolate, properties::PropertySource, theming::Transition, utils::prelude::*,
+};
+
+use super::WidgetContainer;
+
+/// Sets the property with the given key of a widget from a value of the theme. The transition is the transition the
+/// style of the widget declares for the property.
+pub type ThemePropertyHandler = fn(&mut WidgetContainer, &str, Value, Option<&Transition>);
+
+/// Used to convert a value of a theme to a property type. Properties of a type that implements the trait could be
+/// registered as theme property with `register_theme_property` or in the `theme_properties` section of the
+/// `widget!` macro.
+pub trait ThemeProperty: Component + Clone + PartialEq {
+    /// Converts the value of the theme to the property type.
+    fn from_theme(value: Value) -> Self;
+
+    /// Returns the handler that sets the property. The handler of types that could be interpolated animates the
+    /// change if the style declares a transition for the property.
+    fn handler() -> ThemePropertyHandler {
+        set_property::<Self>
+    }
+}
+
+fn set_property<T: ThemeProperty>(
+    widget: &mut WidgetContainer,
+    key: &str,
+    value: Value,
+    _: Option<&Transition>,
+) {
+    widget.update_value::<T, T>(key, T::from_theme(value));
+}
+
+fn set_animated_property<T: ThemeProperty + Interpolate>(
+    widget: &mut WidgetContainer,
+    key: &str,
+    value: Value,
+    transition: Option<&Transition>,
+) {
+    widget.update_value_with_transition(key, T::from_theme(value), transition);
+}
+
+fn set_constraint(widget: &mut WidgetContainer, key: &str, value: Value, _: Option<&Transition>) {
+    widget.update_constraint(key, value);
+}
+
+fn set_padding(widget: &mut WidgetContainer, key: &str, value: Value, _: Option<&Transition>) {
+    widget.update_padding(key, value);
+}
+
+impl ThemeProperty for Brush {
+    fn from_theme(value: Value) -> Self {
+        Brush::from(value)
+    }
+
+    fn handler() -> ThemePropertyHandler {
+        set_animated_property::<Self>
+    }
+}
+
+impl ThemeProperty for f64 {
+    fn from_theme(value: Value) -> Self {
+        value.get::<f64>()
+    }
+
+    fn handler() -> ThemePropertyHandler {
+        set_animated_property::<Self>
+    }
+}
+
+impl ThemeProperty for f32 {
+    fn from_theme(value: Value) -> Self {
+        value.get::<f32>()
+    }
+
+    fn handler() -> ThemePropertyHandler {
+        set_animated_property::<Self>
+    }
+}
+
+impl ThemeProperty for Thickness {
+    fn from_theme(value: Value) -> Self {
+        Thickness::from(value)
+    }
+
+    fn handler() -> ThemePropertyHandler {
+        set_animated_property::<Self>
+    }
+}
+
+impl ThemeProperty for bool {
+    fn from_theme(value: Value) -> Self {
+        value.get::<bool>()
+    }
+}
+
+impl ThemeProperty for usize {
+    fn from_theme(value: Value) -> Self {
+        value.get::<usize>()
+    }
+}
+
+impl ThemeProperty for String {
+    fn from_theme(value: Value) -> Self {
+        value.get::<String>()
+    }
+}
+
+impl ThemeProperty for String16 {
+    fn from_theme(value: Value) -> Self {
+        String16::from(value.get::<String>())
+    }
+}
+
+impl ThemeProperty for Alignment {
+    fn from_theme(value: Value) -> Self {
+        Alignment::from(value.get::<String>().as_str())
+    }
+}
+
+impl ThemeProperty for Visibility {
+    fn from_theme(value: Value) -> Self {
+        Visibility::from(value.get::<String>().as_str())
+    }
+}
+
+impl ThemeProperty for Orientation {
+    fn from_theme(value: Value) -> Self {
+        Orientation::from(value.get::<String>().as_str())
+    }
+}
+
+/// Maps the keys of the theme properties to the handlers that set the properties of the widgets.
+pub struct ThemeProperties {
+    handlers: HashMap<String, ThemePropertyHandler>,
+    reported: HashSet<(String, String)>,
+}
+
+impl Default for ThemeProperties {
+    fn default() -> Self {
+        let mut properties = ThemeProperties {
+            handlers: HashMap::new(),
+            reported: HashSet::new(),
+        };
+
+        for key in &["foreground", "background", "icon_brush", "border_brush"] {
+            properties.register::<Brush>(*key);
+        }
+
+        for key in &["font_size", "icon_size", "spacing", "border_radius"] {
+            properties.register::<f64>(*key);
+        }
+
+        for key in &["padding", "border_width", "margin"] {
+            properties.register::<Thickness>(*key);
+        }
+
+        for key in &["font_family", "icon_family"] {
+            properties.register::<String>(*key);
+        }
+
+        for key in &["h_align", "v_align"] {
+            properties.register::<Alignment>(*key);
+        }
+
+        for key in &[
+            "width",
+            "height",
+            "min_width",
+            "min_height",
+            "max_width",
+            "max_height",
+        ] {
+            properties.register_handler(*key, set_constraint);
+        }
+
+        for key in &[
+            "padding_left",
+            "padding_top",
+            "padding_right",
+            "padding_bottom",
+        ] {
+            properties.register_handler(*key, set_padding);
+        }
+
+        properties.register::<f32>("opacity");
+        properties.register::<Visibility>("visibility");
+
+        properties
+    }
+}
+
+impl ThemeProperties {
+    /// Creates the theme properties with the properties of the default widgets.
+    pub fn new() -> Self {
+        ThemeProperties::default()
+    }
+
+    /// Registers a property of type `T`. A property that is registered before with the same key is replaced.
+    pub fn register<T: ThemeProperty>(&mut self, key: impl Into<String>) {
+        self.register_handler(key, T::handler());
+    }
+
+    /// Registers a handler that sets the property with the given key.
+    pub fn register_handler(&mut self, key: impl Into<String>, handler: ThemePropertyHandler) {
+        self.handlers.insert(key.into(), handler);
+    }
+
+    /// Returns the handler of the property with the given key.
+    pub fn handler(&self, key: &str) -> Option<ThemePropertyHandler> {
+        self.handlers.get(key).copied()
+    }
+
+    /// Checks if a property with the given key is registered.
+    pub fn contains(&self, key: &str) -> bool {
+        self.handlers.contains_key(key)
+    }
+
+    // Returns `true` only the first time an unknown key of a style is reported.
+    fn report(&mut self, style: &str, key: &str) -> bool {
+        self.reported.insert((style.to_string(), key.to_string()))
+    }
+
+    // Returns the reported styles and keys sorted by style.
+    fn reported(&self) -> Vec<(String, String)> {
+        let mut reported: Vec<_> = self.reported.iter().cloned().collect();
+        reported.sort();
+        reported
+    }
+}
+
+thread_local! {
+    static THEME_PROPERTIES: RefCell<ThemeProperties> = RefCell::new(ThemeProperties::default());
+}
+
+/// Registers a property of type `T` that could be set by the themes of the application.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// register_theme_property::<Brush>("track_brush");
+/// ```
+pub fn register_theme_property<T: ThemeProperty>(key: impl Into<String>) {
+    register_theme_property_handler(key, T::handler());
+}
+
+/// Registers a handler that sets the property with the given key from the themes of the application.
+pub fn register_theme_property_handler(key: impl Into<String>, handler: ThemePropertyHandler) {
+    THEME_PROPERTIES.with(|properties| properties.borrow_mut().register_handler(key, handler));
+}
+
+/// Checks if a theme property with the given key is registered.
+pub fn is_theme_property(key: &str) -> bool {
+    THEME_PROPERTIES.with(|properties| properties.borrow().contains(key))
+}
+
+/// Registers a property of the `widget!` macro as theme property. The type is taken from the property.
+#[doc(hidden)]
+pub fn register_theme_property_of<T>(key: &str, _: &Option<PropertySource<T>>)
+where
+    T: ThemeProperty + Debug,
+{
+    if !is_theme_property(key) {
+        register_theme_property::<T>(key);
+    }
+}
+
+pub(crate) fn theme_property_handler(key: &str) -> Option<ThemePropertyHandler> {
+    THEME_PROPERTIES.with(|properties| properties.borrow().handler(key))
+}
+
+/// Returns the styles and keys of the theme that are ignored because the keys are not registered as theme
+/// properties, sorted by style.
+pub fn unknown_theme_properties() -> Vec<(String, String)> {
+    THEME_PROPERTIES.with(|properties| properties.borrow().reported())
+}
+
+// Logs a warning for a key of a style that is not registered as theme property.
+pub(crate) fn report_unknown_theme_property(style: &str, key: &str) {
+    THEME_PROPERTIES.with(|properties| {
+        if properties.borrow_mut().report(style, key) {
+            crate::shell::CONSOLE.log(format!(
+                "Warning: Property \"{}\" of style \"{}\" is not registered as theme property and will be ignored.",
+                key, style
+            ));
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let mut properties = ThemeProperties::new();
+        assert!(properties.contains("background"));
+        assert!(properties.contains("max_width"));
+        assert!(!properties.contains("track_brush"));
+
+        properties.register::<Brush>("track_brush");
+        assert!(properties.contains("track_brush"));
+        assert!(properties.handler("track_brush").is_some());
+    }
+
+    #[test]
+    fn test_report() {
+        let mut properties = ThemeProperties::new();
+        assert!(properties.report("button", "font_sze"));
+        assert!(!properties.report("button", "font_sze"));
+        assert!(properties.report("text_box", "font_sze"));
+
+        report_unknown_theme_property("text_box", "font_sze");
+        report_unknown_theme_property("button", "font_sze");
+        report_unknown_theme_property("button", "font_sze");
+        assert_eq!(
+            unknown_theme_properties(),
+            vec![
+                ("button".to_string(), "font_sze".to_string()),
+                ("text_box".to_string(), "font_sze".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_theme() {
+        assert_eq!(
+            Alignment::from_theme(Value(ron::Value::String("center".to_string()))),
+            Alignment::Center
+        );
+        assert_eq!(
+            Visibility::from_theme(Value(ron::Value::String("collapsed".to_string()))),
+            Visibility::Collapsed
+        );
+    }
+}
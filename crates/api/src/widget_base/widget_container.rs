@@ -3,9 +3,17 @@ use std::{any::type_name, cell::RefCell, rc::Rc};
 use dces::prelude::*;
 
 use crate::{
-    event::ChangedEvent, event::*, properties::Constraint, theming::*, tree::*, utils::prelude::*,
+    animation::{Animator, Interpolate, PropertyAnimation},
+    event::ChangedEvent,
+    event::*,
+    properties::Constraint,
+    theming::*,
+    tree::*,
+    utils::prelude::*,
 };
 
+use super::{report_unknown_theme_property, theme_property_handler};
+
 /// Mark the widget and shared widgets as dirty.
 pub fn mark_as_dirty(
     key: &str,
@@ -37,6 +45,7 @@ pub struct WidgetContainer<'a> {
     current_node: Entity,
     theme: &'a Theme,
     event_queue: Option<&'a Rc<RefCell<EventQueue>>>,
+    animator: Option<&'a RefCell<Animator>>,
 }
 
 impl<'a> WidgetContainer<'a> {
@@ -52,9 +61,16 @@ impl<'a> WidgetContainer<'a> {
             current_node: root,
             theme,
             event_queue,
+            animator: None,
         }
     }
 
+    /// Sets the animator that runs the transitions of the widget if its state changes.
+    pub fn with_animator(mut self, animator: &'a RefCell<Animator>) -> Self {
+        self.animator = Some(animator);
+        self
+    }
+
     fn mark_as_dirty(&mut self, key: &str) {
         mark_as_dirty(key, self.current_node, self.ecm);
     }
@@ -312,7 +328,7 @@ impl<'a> WidgetContainer<'a> {
         false
     }
 
-    fn update_constraint(&mut self, key: &str, value: Value) {
+    pub(crate) fn update_constraint(&mut self, key: &str, value: Value) {
         let value = if let Ok(value) = value.0.into_rust::<f64>() {
             value
         } else {
@@ -336,7 +352,7 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
-    fn update_padding(&mut self, key: &str, value: Value) {
+    pub(crate) fn update_padding(&mut self, key: &str, value: Value) {
         let value = if let Ok(value) = value.0.into_rust::<f64>() {
             value
         } else {
@@ -354,7 +370,51 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
-    fn update_value<T, V>(&mut self, key: &str, value: V)
+    // Sets a property from the theme. The change is animated from the current value if the style of the widget
+    // declares a transition for the property.
+    pub(crate) fn update_value_with_transition<T>(
+        &mut self,
+        key: &str,
+        value: T,
+        transition: Option<&Transition>,
+    ) where
+        T: Component + Clone + PartialEq + Interpolate,
+    {
+        let animator = match self.animator {
+            Some(animator) => animator,
+            None => return self.update_value::<T, T>(key, value),
+        };
+
+        let transition = match transition {
+            Some(transition) if self.has::<T>(key) => transition,
+            _ => {
+                // a running transition would override the new value
+                animator
+                    .borrow_mut()
+                    .stop_transition(self.current_node, key);
+                return self.update_value::<T, T>(key, value);
+            }
+        };
+
+        if self.get::<T>(key) == &value
+            && !animator
+                .borrow()
+                .is_transition_running(self.current_node, key)
+        {
+            return;
+        }
+
+        animator.borrow_mut().start_transition(
+            self.current_node,
+            key,
+            PropertyAnimation::<T>::new(key, value)
+                .duration(transition.duration)
+                .delay(transition.delay)
+                .easing(transition.easing.as_str()),
+        );
+    }
+
+    pub(crate) fn update_value<T, V>(&mut self, key: &str, value: V)
     where
         T: Component + Clone,
         V: Into<T>,
@@ -380,6 +440,18 @@ impl<'a> WidgetContainer<'a> {
 
     /// Update all properties from theme for the given widget.
     pub fn update_widget(&mut self, entity: Entity, force: bool, should_mark_as_dirty: bool) {
+        let mut scopes = self.theme_scopes(entity);
+        self.update_widget_in_scopes(entity, force, should_mark_as_dirty, &mut scopes);
+    }
+
+    // Updates the widget and its children with the theme scopes of the widget and its ancestors.
+    fn update_widget_in_scopes(
+        &mut self,
+        entity: Entity,
+        force: bool,
+        should_mark_as_dirty: bool,
+        scopes: &mut Vec<ThemeScope>,
+    ) {
         self.current_node = entity;
         if !self.has::<Selector>("selector") {
             return;
@@ -400,30 +472,33 @@ impl<'a> WidgetContainer<'a> {
             return;
         }
 
-        if let Some(props) = self.theme.properties(&selector) {
-            for (key, value) in props {
-                match key.as_str() {
-                    "foreground" | "background" | "icon_brush" | "border_brush" => {
-                        self.update_value::<Brush, Value>(key, Value(value.clone()));
-                    }
-                    "font_size" | "icon_size" | "spacing" | "border_radius" => {
-                        self.update_value::<f64, Value>(key, Value(value.clone()));
-                    }
-                    "padding" | "border_width" => {
-                        self.update_value::<Thickness, Value>(key, Value(value.clone()));
-                    }
-                    "padding_left" | "padding_top" | "padding_right" | "padding_bottom" => {
-                        self.update_padding(key, Value(value.clone()));
-                    }
-                    "font_family" | "icon_family" => {
-                        self.update_value::<String, Value>(key, Value(value.clone()));
-                    }
-                    "opacity" => {
-                        self.update_value::<f32, Value>(key, Value(value.clone()));
-                    }
-                    "width" | "height" | "min_width" | "min_height" | "max_width"
-                    | "max_height" => self.update_constraint(key, Value(value.clone())),
-                    _ => {}
+        let theme = ScopedTheme::new(self.theme, scopes);
+
+        let ancestors = if theme.has_descendant_rules() {
+            self.ancestor_selectors(entity)
+        } else {
+            vec![]
+        };
+
+        // state changes are animated, the initial styling and forced updates e.g. of a new theme are not
+        let transitions = if force {
+            None
+        } else {
+            theme.transitions_in(&selector, &ancestors)
+        };
+
+        if let Some(props) = theme.properties_in(&selector, &ancestors) {
+            for (key, value) in &props {
+                let transition = transitions
+                    .as_ref()
+                    .and_then(|transitions| transitions.get(key));
+
+                match theme_property_handler(key) {
+                    Some(handler) => handler(self, key, Value(value.clone()), transition),
+                    None => report_unknown_theme_property(
+                        selector.style.as_deref().unwrap_or_default(),
+                        key,
+                    ),
                 }
             }
         }
@@ -431,7 +506,15 @@ impl<'a> WidgetContainer<'a> {
         let force = selector.dirty() || force;
 
         for child in &(self.ecm.entity_store().children.clone())[&entity] {
-            self.update_widget(*child, force, should_mark_as_dirty);
+            let scope = self.theme_scope(*child);
+            let scoped = scope.is_some();
+            scopes.extend(scope);
+
+            self.update_widget_in_scopes(*child, force, should_mark_as_dirty, scopes);
+
+            if scoped {
+                scopes.pop();
+            }
         }
 
         self.current_node = entity;
@@ -448,6 +531,50 @@ impl<'a> WidgetContainer<'a> {
         }
     }
 
+    // Returns the theme scope that is attached to the given widget.
+    fn theme_scope(&self, entity: Entity) -> Option<ThemeScope> {
+        self.ecm
+            .component_store()
+            .get::<Option<ThemeScope>>("theme_scope", entity)
+            .ok()
+            .cloned()
+            .flatten()
+    }
+
+    // Returns the theme scopes of the given widget and its ancestors ordered from the outermost to the innermost scope.
+    fn theme_scopes(&self, entity: Entity) -> Vec<ThemeScope> {
+        let mut scopes: Vec<_> = self.theme_scope(entity).into_iter().collect();
+        let mut current = entity;
+
+        while let Some(&Some(parent)) = self.ecm.entity_store().parent.get(&current) {
+            scopes.extend(self.theme_scope(parent));
+            current = parent;
+        }
+
+        scopes.reverse();
+        scopes
+    }
+
+    // Returns the selectors of the ancestors of the given widget ordered from the parent to the root.
+    fn ancestor_selectors(&self, entity: Entity) -> Vec<Selector> {
+        let mut ancestors = vec![];
+        let mut current = entity;
+
+        while let Some(&Some(parent)) = self.ecm.entity_store().parent.get(&current) {
+            if let Ok(selector) = self
+                .ecm
+                .component_store()
+                .get::<Selector>("selector", parent)
+            {
+                ancestors.push(selector.clone());
+            }
+
+            current = parent;
+        }
+
+        ancestors
+    }
+
     fn get_name(&self) -> String {
         if self.has::<String>("name") {
             self.ecm
@@ -25,6 +25,9 @@ enum RenderTask {
         width: f64,
         height: f64,
     },
+    SetScaleFactor {
+        scale_factor: f64,
+    },
     RegisterFont {
         family: String,
         font_file: &'static [u8],
@@ -161,6 +164,7 @@ fn is_single_tasks(task: &RenderTask) -> bool {
         RenderTask::Start() => true,
         RenderTask::SetBackground(_) => true,
         RenderTask::Resize { .. } => true,
+        RenderTask::SetScaleFactor { .. } => true,
         RenderTask::RegisterFont { .. } => true,
         RenderTask::DrawRenderTarget { .. } => true,
         RenderTask::DrawImage { .. } => true,
@@ -204,6 +208,10 @@ impl RenderWorker {
                             render_context_2_d.resize(width, height);
                             continue;
                         }
+                        RenderTask::SetScaleFactor { scale_factor } => {
+                            render_context_2_d.set_scale_factor(scale_factor);
+                            continue;
+                        }
                         RenderTask::RegisterFont { family, font_file } => {
                             render_context_2_d.register_font(family.as_str(), font_file);
                             continue;
@@ -399,7 +407,7 @@ impl Drop for RenderContext2D {
 }
 
 impl RenderContext2D {
-    /// Creates a new render ctx 2d.
+    /// Creates a new render ctx 2d. The width and the height are given in physical pixels.
     pub fn new(width: f64, height: f64) -> Self {
         let (sender, receiver) = mpsc::channel();
 
@@ -455,13 +463,26 @@ impl RenderContext2D {
         self.send_tasks();
     }
 
-    /// Resizes the render ctx.
+    /// Resizes the render ctx to the given physical size.
     pub fn resize(&mut self, width: f64, height: f64) {
         self.sender
             .send(vec![RenderTask::Resize { width, height }])
             .expect("Could not send resize to render thread.");
     }
 
+    /// Sets the scale factor that is used to convert logical units to physical pixels.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.measure_context.set_scale_factor(scale_factor);
+        self.sender
+            .send(vec![RenderTask::SetScaleFactor { scale_factor }])
+            .expect("Could not send scale factor to render thread.");
+    }
+
+    /// Gets the scale factor that is used to convert logical units to physical pixels.
+    pub fn scale_factor(&self) -> f64 {
+        self.measure_context.scale_factor()
+    }
+
     /// Registers a new font file.
     pub fn register_font(&mut self, family: &str, font_file: &'static [u8]) {
         self.measure_context.register_font(family, font_file);
@@ -157,6 +157,16 @@ impl RenderContext2D {
         self.device_pixel_ratio
     }
 
+    /// Sets the scale factor that is used to convert logical units to physical pixels.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.device_pixel_ratio = scale_factor as f32;
+    }
+
+    /// Gets the scale factor that is used to convert logical units to physical pixels.
+    pub fn scale_factor(&self) -> f64 {
+        self.device_pixel_ratio as f64
+    }
+
     /// Draws a rectangle that is stroked (outlined) according to the current strokeStyle and other ctx settings.
     pub fn stroke_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
         let device_pixel_ratio = self.device_pixel_ratio();
@@ -176,7 +186,9 @@ impl RenderContext2D {
 
         self.canvas().fill_text(
             text,
-            vec2f(x as f32, y as f32 + t_m.actual_bounding_box_ascent) * device_pixel_ratio,
+            // the metrics of the canvas are already scaled
+            vec2f(x as f32, y as f32) * device_pixel_ratio
+                + vec2f(0.0, t_m.actual_bounding_box_ascent),
         );
     }
 
@@ -187,20 +199,18 @@ impl RenderContext2D {
         family: impl Into<String>,
     ) -> TextMetrics {
         self.set_font_family(family);
-        self.canvas().set_font_size(font_size as f32);
-        let t_m = self.canvas().measure_text(text);
-        TextMetrics {
-            width: t_m.width as f64,
-            height: t_m.actual_bounding_box_ascent as f64,
-        }
+        self.set_font_size(font_size);
+        self.measure_text(text)
     }
 
-    /// Returns a TextMetrics object.
+    /// Returns a TextMetrics object. The font size of the canvas is scaled, the metrics are converted back to logical
+    /// units.
     pub fn measure_text(&mut self, text: &str) -> TextMetrics {
+        let device_pixel_ratio = self.device_pixel_ratio() as f64;
         let t_m = self.canvas().measure_text(text);
         TextMetrics {
-            width: t_m.width as f64,
-            height: t_m.actual_bounding_box_ascent as f64,
+            width: t_m.width as f64 / device_pixel_ratio,
+            height: t_m.actual_bounding_box_ascent as f64 / device_pixel_ratio,
         }
     }
 
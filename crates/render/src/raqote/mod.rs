@@ -22,10 +22,14 @@ pub struct RenderContext2D {
     clip_rect: Option<Rectangle>,
 
     background: Color,
+
+    // logical units are multiplied by the scale factor to get physical pixels
+    scale_factor: f64,
+    transform: [f64; 6],
 }
 
 impl RenderContext2D {
-    /// Creates a new render ctx 2d.
+    /// Creates a new render ctx 2d. The width and the height are given in physical pixels.
     pub fn new(width: f64, height: f64) -> Self {
         RenderContext2D {
             draw_target: raqote::DrawTarget::new(width as i32, height as i32),
@@ -40,16 +44,56 @@ impl RenderContext2D {
             last_rect: Rectangle::new((0.0, 0.0), (width, height)),
             clip_rect: None,
             background: Color::default(),
+            scale_factor: 1.0,
+            transform: [1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
         }
     }
 
+    /// Sets the scale factor that is used to convert logical units to physical pixels.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.apply_transform();
+    }
+
+    /// Gets the scale factor that is used to convert logical units to physical pixels.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
+    // Applies the transformation of the user, scaled by the scale factor.
+    fn apply_transform(&mut self) {
+        let t = self.transform;
+        let s = self.scale_factor;
+
+        self.draw_target
+            .set_transform(&raqote::Transform::row_major(
+                (t[0] * s) as f32,
+                (t[1] * s) as f32,
+                (t[2] * s) as f32,
+                (t[3] * s) as f32,
+                (t[4] * s) as f32,
+                (t[5] * s) as f32,
+            ));
+    }
+
+    // Converts a logical rectangle to physical pixels.
+    fn to_physical(&self, rect: Rectangle) -> Rectangle {
+        let s = self.scale_factor;
+        Rectangle::new(
+            (rect.x() * s, rect.y() * s),
+            (rect.width() * s, rect.height() * s),
+        )
+    }
+
     /// Set the background of the render context.
     pub fn set_background(&mut self, background: Color) {
         self.background = background;
     }
 
+    /// Resizes the render context to the given physical size.
     pub fn resize(&mut self, width: f64, height: f64) {
         self.draw_target = raqote::DrawTarget::new(width as i32, height as i32);
+        self.apply_transform();
     }
 
     /// Registers a new font file.
@@ -103,35 +147,35 @@ impl RenderContext2D {
             return;
         }
 
+        // the text is rendered directly to the pixels of the draw target
+        let s = self.scale_factor;
+        let font_size = self.config.font_config.font_size * s;
+        let position = (x * s, y * s);
+        let clip_rect = if self.clip {
+            self.clip_rect.map(|rect| self.to_physical(rect))
+        } else {
+            None
+        };
+
         if let Some(font) = self.fonts.get(&self.config.font_config.family) {
             let width = self.draw_target.width() as f64;
 
-            if self.clip {
-                if let Some(rect) = self.clip_rect {
-                    font.render_text_clipped(
-                        text,
-                        self.draw_target.get_data_mut(),
-                        width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
-                        rect,
-                    );
-                } else {
-                    font.render_text(
-                        text,
-                        self.draw_target.get_data_mut(),
-                        width,
-                        (self.config.font_config.font_size, color, self.config.alpha),
-                        (x, y),
-                    );
-                }
+            if let Some(rect) = clip_rect {
+                font.render_text_clipped(
+                    text,
+                    self.draw_target.get_data_mut(),
+                    width,
+                    (font_size, color, self.config.alpha),
+                    position,
+                    rect,
+                );
             } else {
                 font.render_text(
                     text,
                     self.draw_target.get_data_mut(),
                     width,
-                    (self.config.font_config.font_size, color, self.config.alpha),
-                    (x, y),
+                    (font_size, color, self.config.alpha),
+                    position,
                 );
             }
         }
@@ -145,11 +189,13 @@ impl RenderContext2D {
             return text_metrics;
         }
 
+        // measures with the physical font size to get the same metrics as the rendered text
         if let Some(font) = self.fonts.get(&self.config.font_config.family) {
-            let (width, height) = font.measure_text(text, self.config.font_config.font_size);
+            let (width, height) =
+                font.measure_text(text, self.config.font_config.font_size * self.scale_factor);
 
-            text_metrics.width = width;
-            text_metrics.height = height;
+            text_metrics.width = width / self.scale_factor;
+            text_metrics.height = height / self.scale_factor;
         }
 
         text_metrics
@@ -376,7 +422,7 @@ impl RenderContext2D {
 
     // Transformations
 
-    /// Sets the transformation.
+    /// Sets the transformation. It is applied to logical units before they are scaled by the scale factor.
     pub fn set_transform(
         &mut self,
         h_scaling: f64,
@@ -386,15 +432,10 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
-        self.draw_target
-            .set_transform(&raqote::Transform::row_major(
-                h_scaling as f32,
-                h_skewing as f32,
-                v_skewing as f32,
-                v_scaling as f32,
-                h_moving as f32,
-                v_moving as f32,
-            ));
+        self.transform = [
+            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+        ];
+        self.apply_transform();
     }
 
     // Canvas states
@@ -19,6 +19,7 @@ pub struct RenderContext2D {
     saved_config: Option<RenderConfig>,
     export_data: Vec<u32>,
     background: Color,
+    scale_factor: f64,
 }
 
 impl RenderContext2D {
@@ -44,9 +45,28 @@ impl RenderContext2D {
             font_config: FontConfig::default(),
             export_data,
             background: Color::default(),
+            scale_factor: 1.0,
         }
     }
 
+    /// Sets the scale factor that is used to convert logical units to physical pixels.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = scale_factor;
+        self.canvas_render_context_2_d.set_transform(
+            scale_factor,
+            0.0,
+            0.0,
+            scale_factor,
+            0.0,
+            0.0,
+        );
+    }
+
+    /// Gets the scale factor that is used to convert logical units to physical pixels.
+    pub fn scale_factor(&self) -> f64 {
+        self.scale_factor
+    }
+
     /// Set the background of the render context.
     pub fn set_background(&mut self, background: Color) {
         self.background = background;
@@ -68,6 +88,7 @@ impl RenderContext2D {
             font_config: FontConfig::default(),
             export_data,
             background: Color::default(),
+            scale_factor: 1.0,
         }
     }
 
@@ -333,7 +354,7 @@ impl RenderContext2D {
 
     // Transformations
 
-    /// Sets the transformation.
+    /// Sets the transformation. It is applied to logical units before they are scaled by the scale factor.
     pub fn set_transform(
         &mut self,
         h_scaling: f64,
@@ -343,8 +364,14 @@ impl RenderContext2D {
         h_moving: f64,
         v_moving: f64,
     ) {
+        let s = self.scale_factor;
         self.canvas_render_context_2_d.set_transform(
-            h_scaling, h_skewing, v_skewing, v_scaling, h_moving, v_moving,
+            h_scaling * s,
+            h_skewing * s,
+            v_skewing * s,
+            v_scaling * s,
+            h_moving * s,
+            v_moving * s,
         );
     }
 
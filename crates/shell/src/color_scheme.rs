@@ -0,0 +1,139 @@
+//! This module contains the detection of the color scheme the user prefers for the desktop.
+
+use std::env;
+
+use crate::event::ColorScheme;
+
+/// Name of the environment variable that overrides the preferred color scheme of the desktop, e.g.
+/// `ORBTK_COLOR_SCHEME=dark`.
+pub const COLOR_SCHEME_ENV: &str = "ORBTK_COLOR_SCHEME";
+
+/// Returns the color scheme the user prefers for the desktop or `None` if it could not be detected. The value of the
+/// `ORBTK_COLOR_SCHEME` environment variable is preferred over the setting of the desktop.
+///
+/// The detection could start a process, so it should not be called on each frame.
+pub fn system_color_scheme() -> Option<ColorScheme> {
+    if let Ok(value) = env::var(COLOR_SCHEME_ENV) {
+        return parse_color_scheme(&value);
+    }
+
+    platform_color_scheme()
+}
+
+// Parses a color scheme, e.g. `dark` or `prefer-dark`.
+fn parse_color_scheme(value: &str) -> Option<ColorScheme> {
+    let value = value.trim().trim_matches('\'').to_lowercase();
+
+    if value.contains("dark") {
+        Some(ColorScheme::Dark)
+    } else if value.contains("light") || value == "default" {
+        Some(ColorScheme::Light)
+    } else {
+        None
+    }
+}
+
+#[cfg(not(any(target_arch = "wasm32", target_os = "redox")))]
+fn command_output(program: &str, args: &[&str]) -> Option<(bool, String)> {
+    let mut command = std::process::Command::new(program);
+    command.args(args);
+
+    // prevents a console window from popping up
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let output = command.output().ok()?;
+
+    Some((
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+    ))
+}
+
+#[cfg(target_os = "macos")]
+fn platform_color_scheme() -> Option<ColorScheme> {
+    // the key only exists if the dark mode is active
+    let (success, output) = command_output("defaults", &["read", "-g", "AppleInterfaceStyle"])?;
+
+    if success && output.contains("Dark") {
+        Some(ColorScheme::Dark)
+    } else {
+        Some(ColorScheme::Light)
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn platform_color_scheme() -> Option<ColorScheme> {
+    let (_, output) = command_output(
+        "reg",
+        &[
+            "query",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize",
+            "/v",
+            "AppsUseLightTheme",
+        ],
+    )?;
+
+    if output.contains("0x0") {
+        Some(ColorScheme::Dark)
+    } else if output.contains("0x1") {
+        Some(ColorScheme::Light)
+    } else {
+        None
+    }
+}
+
+#[cfg(all(
+    unix,
+    not(target_os = "macos"),
+    not(target_os = "redox"),
+    not(target_arch = "wasm32")
+))]
+fn platform_color_scheme() -> Option<ColorScheme> {
+    if let Some((true, output)) = command_output(
+        "gsettings",
+        &["get", "org.gnome.desktop.interface", "color-scheme"],
+    ) {
+        if let Some(color_scheme) = parse_color_scheme(&output) {
+            return Some(color_scheme);
+        }
+    }
+
+    // e.g. `Adwaita:dark`
+    env::var("GTK_THEME")
+        .ok()
+        .map(|theme| match parse_color_scheme(&theme) {
+            Some(ColorScheme::Dark) => ColorScheme::Dark,
+            _ => ColorScheme::Light,
+        })
+}
+
+#[cfg(not(any(
+    target_os = "macos",
+    target_os = "windows",
+    all(unix, not(target_os = "redox"), not(target_arch = "wasm32"))
+)))]
+fn platform_color_scheme() -> Option<ColorScheme> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_color_scheme() {
+        assert_eq!(parse_color_scheme("dark"), Some(ColorScheme::Dark));
+        assert_eq!(
+            parse_color_scheme("'prefer-dark'\n"),
+            Some(ColorScheme::Dark)
+        );
+        assert_eq!(parse_color_scheme("Light"), Some(ColorScheme::Light));
+        assert_eq!(parse_color_scheme("'default'"), Some(ColorScheme::Light));
+        assert_eq!(parse_color_scheme("blue"), None);
+    }
+}
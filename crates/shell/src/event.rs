@@ -407,3 +407,51 @@ pub struct KeyEvent {
 
     pub text: String,
 }
+
+/// Describes the phase of a touch point.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum TouchPhase {
+    /// A finger touched the screen.
+    Started,
+
+    /// A finger moved on the screen.
+    Moved,
+
+    /// A finger was lifted from the screen.
+    Ended,
+
+    /// The system cancelled the tracking of the touch point.
+    Cancelled,
+}
+
+/// Represents a touch event of a single touch point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TouchEvent {
+    /// Unique id of the touch point. The id is the same for all events of one finger until it is lifted.
+    pub id: u64,
+
+    /// The phase of the touch point.
+    pub phase: TouchPhase,
+
+    /// Position of the touch point on the window.
+    pub position: Point,
+}
+
+/// Represents composed text input of an input method editor (IME), a dead key or a compose sequence.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum ImeEvent {
+    /// The text that is currently composed but not committed yet. `cursor` is the char index of the caret
+    /// inside of the text.
+    Preedit { text: String, cursor: usize },
+
+    /// The final text that should be inserted. It replaces the current preedit text.
+    Commit(String),
+}
+
+/// Describes the color scheme the user prefers for the desktop.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+
+    Dark,
+}
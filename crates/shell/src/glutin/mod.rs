@@ -1,6 +1,9 @@
 //! self module contains a platform specific implementation of the window shell.
 
-use std::sync::mpsc;
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
 
 pub use super::native::*;
 
@@ -15,6 +18,9 @@ mod states;
 mod window;
 mod window_builder;
 
+// Time between two frames while an update is pending.
+const FRAME_DURATION: Duration = Duration::from_millis(16);
+
 /// Does nothing. self function is only use by the web backend.
 pub fn initialize() {}
 
@@ -25,6 +31,7 @@ where
 {
     window_shells: Vec<Window<A>>,
     requests: mpsc::Receiver<ShellRequest<A>>,
+    scale_factor: Option<f64>,
     event_loop: Vec<EventLoop<()>>,
 }
 
@@ -37,10 +44,16 @@ where
         Shell {
             window_shells: vec![],
             requests,
+            scale_factor: None,
             event_loop: vec![EventLoop::new()],
         }
     }
 
+    /// Overrides the scale factor of all windows of the shell, e.g. to test the ui on HiDPI displays.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = Some(scale_factor);
+    }
+
     /// Creates a window builder, that could be used to create a window and add it to the application shell.
     pub fn create_window(&mut self, adapter: A) -> WindowBuilder<A> {
         WindowBuilder::new(self, adapter)
@@ -104,6 +117,24 @@ where
                         break;
                     }
                 }
+
+                // schedules the next frame only while a window has to be updated
+                if *control_flow == ControlFlow::Wait {
+                    if self
+                        .window_shells
+                        .iter()
+                        .any(|window_shell| window_shell.is_update_pending())
+                    {
+                        *control_flow = ControlFlow::WaitUntil(Instant::now() + FRAME_DURATION);
+                    } else if let Some(interval) = self
+                        .window_shells
+                        .iter()
+                        .filter_map(|window_shell| window_shell.poll_interval())
+                        .min()
+                    {
+                        *control_flow = ControlFlow::WaitUntil(Instant::now() + interval);
+                    }
+                }
             });
     }
 }
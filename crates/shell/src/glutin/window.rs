@@ -1,11 +1,16 @@
-use std::sync::mpsc;
+use std::{path::PathBuf, sync::mpsc, time::Duration};
 
-use glutin::{event, event_loop::ControlFlow, window, ContextWrapper, PossiblyCurrent};
+use glutin::{
+    dpi::{LogicalPosition, PhysicalSize},
+    event,
+    event_loop::ControlFlow,
+    window, ContextWrapper, PossiblyCurrent,
+};
 
 use derive_more::Constructor;
 
 use crate::{
-    event::{ButtonState, MouseButton, MouseEvent},
+    event::{ButtonState, ColorScheme, ImeEvent, MouseButton, MouseEvent, TouchEvent, TouchPhase},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
     WindowRequest,
@@ -27,6 +32,9 @@ where
     close: bool,
     mouse_pos: (f64, f64),
     scale_factor: f64,
+    fixed_scale_factor: bool,
+    hovered_files: Vec<PathBuf>,
+    dropped_files: Vec<PathBuf>,
 }
 
 impl<A> Window<A>
@@ -43,6 +51,16 @@ where
         true
     }
 
+    /// Checks if the window is waiting for the next update, e.g. because of a requested animation frame.
+    pub fn is_update_pending(&self) -> bool {
+        self.update
+    }
+
+    /// Returns the interval the adapter wants to be run in also if there are no events.
+    pub fn poll_interval(&self) -> Option<Duration> {
+        self.adapter.poll_interval()
+    }
+
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self, control_flow: &mut ControlFlow, event: &event::Event<()>) {
         match event {
@@ -53,11 +71,39 @@ where
                 if !window_id.eq(&self.id()) {
                     return;
                 }
-                self.adapter.resize(s.width as f64, s.height as f64);
-                self.render_context.resize(s.width as f64, s.height as f64);
-                self.update = true;
+                self.resize(*s);
                 *control_flow = ControlFlow::Wait;
             }
+            event::Event::WindowEvent {
+                event:
+                    event::WindowEvent::ScaleFactorChanged {
+                        scale_factor,
+                        new_inner_size,
+                    },
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                // an overridden scale factor is kept if the window is moved to another monitor
+                if !self.fixed_scale_factor {
+                    self.scale_factor = *scale_factor;
+                    self.render_context.set_scale_factor(*scale_factor);
+                    self.adapter.scale_factor_changed(*scale_factor);
+                    self.resize(**new_inner_size);
+                }
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::Moved(position),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                let position = position.to_logical::<f64>(self.scale_factor);
+                self.adapter.moved(position.x, position.y);
+            }
             event::Event::WindowEvent {
                 event: event::WindowEvent::CloseRequested,
                 window_id,
@@ -73,6 +119,53 @@ where
                 // todo: implement
                 ..
             } => *control_flow = ControlFlow::Wait,
+            event::Event::WindowEvent {
+                event: event::WindowEvent::ReceivedCharacter(character),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                // text of dead keys and compose sequences, the text of input methods is sent as ime event. Control
+                // characters like backspace are handled as key events.
+                if !character.is_control() {
+                    self.adapter
+                        .ime_event(ImeEvent::Commit(character.to_string()));
+                    self.update = true;
+                    self.redraw = true;
+                }
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::Ime(ime),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                let ime_event = match ime {
+                    // the cursor of glutin is the byte range of the caret inside of the preedit text
+                    event::Ime::Preedit(text, cursor) => Some(ImeEvent::Preedit {
+                        text: text.clone(),
+                        cursor: cursor.map_or(text.chars().count(), |(start, _)| {
+                            text[..start].chars().count()
+                        }),
+                    }),
+                    event::Ime::Commit(text) => Some(ImeEvent::Commit(text.clone())),
+                    // the preedit text is removed if the input method is disabled
+                    event::Ime::Disabled => Some(ImeEvent::Preedit {
+                        text: String::new(),
+                        cursor: 0,
+                    }),
+                    event::Ime::Enabled => None,
+                };
+                if let Some(ime_event) = ime_event {
+                    self.adapter.ime_event(ime_event);
+                    self.update = true;
+                    self.redraw = true;
+                }
+                *control_flow = ControlFlow::Wait;
+            }
             event::Event::WindowEvent {
                 event: event::WindowEvent::MouseInput { state, button, .. },
                 ..
@@ -135,10 +228,120 @@ where
                 self.redraw = true;
                 *control_flow = ControlFlow::Wait;
             }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::Touch(touch),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                let position = touch.location.to_logical::<f64>(self.scale_factor);
+                self.adapter.touch_event(TouchEvent {
+                    id: touch.id,
+                    phase: match touch.phase {
+                        event::TouchPhase::Started => TouchPhase::Started,
+                        event::TouchPhase::Moved => TouchPhase::Moved,
+                        event::TouchPhase::Ended => TouchPhase::Ended,
+                        event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+                    },
+                    position: (position.x, position.y).into(),
+                });
+                self.update = true;
+                self.redraw = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::HoveredFile(path),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                // glutin sends one event per file, they are collected and forwarded together
+                self.hovered_files.push(path.clone());
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::HoveredFileCancelled,
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.hovered_files.clear();
+                self.adapter.file_hover_cancelled();
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::DroppedFile(path),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.dropped_files.push(path.clone());
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::WindowEvent {
+                event: event::WindowEvent::ThemeChanged(theme),
+                window_id,
+            } => {
+                if !window_id.eq(&self.id()) {
+                    return;
+                }
+                self.adapter.color_scheme_changed(match theme {
+                    window::Theme::Light => ColorScheme::Light,
+                    window::Theme::Dark => ColorScheme::Dark,
+                });
+                self.update = true;
+                *control_flow = ControlFlow::Wait;
+            }
+            event::Event::MainEventsCleared => {
+                self.flush_files();
+                *control_flow = ControlFlow::Wait;
+            }
+            // the poll interval of the adapter is reached
+            event::Event::NewEvents(event::StartCause::ResumeTimeReached { .. }) => {
+                if self.poll_interval().is_some() {
+                    self.update = true;
+                }
+                *control_flow = ControlFlow::Wait;
+            }
             _ => *control_flow = ControlFlow::Wait,
         }
     }
 
+    // Resizes the render context to the physical size and sends the logical size to the adapter.
+    fn resize(&mut self, size: PhysicalSize<u32>) {
+        let logical_size = size.to_logical::<f64>(self.scale_factor);
+        self.render_context
+            .resize(size.width as f64, size.height as f64);
+        self.adapter.resize(logical_size.width, logical_size.height);
+        self.update = true;
+    }
+
+    // Forwards the collected hovered and dropped files to the adapter.
+    fn flush_files(&mut self) {
+        if self.hovered_files.is_empty() && self.dropped_files.is_empty() {
+            return;
+        }
+
+        let position = self.mouse_pos.into();
+
+        if !self.hovered_files.is_empty() {
+            let paths = self.hovered_files.drain(..).collect();
+            self.adapter.file_hovered(paths, position);
+        }
+
+        if !self.dropped_files.is_empty() {
+            let paths = self.dropped_files.drain(..).collect();
+            self.adapter.file_dropped(paths, position);
+        }
+
+        self.update = true;
+    }
+
     /// Receives window request from the application and handles them.
     pub fn receive_requests(&mut self) {
         if let Some(request_receiver) = &self.request_receiver {
@@ -157,6 +360,12 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    // the input method is enabled the first time a text input requests the candidate window
+                    WindowRequest::SetImePosition(position) => {
+                        let window = self.gl_context.window();
+                        window.set_ime_allowed(true);
+                        window.set_ime_position(LogicalPosition::new(position.x(), position.y()));
+                    }
                 }
             }
         }
@@ -17,8 +17,8 @@ use pathfinder_resources::embedded::EmbeddedResourceLoader;
 use super::{Shell, Window};
 
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    render::RenderContext2D, scale_factor_override, utils::Rectangle,
+    window_adapter::WindowAdapter, WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the minifb backend.
@@ -32,6 +32,7 @@ where
     fonts: HashMap<String, &'static [u8]>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
     bounds: Rectangle,
+    scale_factor: Option<f64>,
 }
 
 impl<'a, A> WindowBuilder<'a, A>
@@ -47,18 +48,28 @@ where
             fonts: HashMap::new(),
             request_receiver: None,
             bounds: Rectangle::default(),
+            scale_factor: None,
         }
     }
 
     /// Creates the window builder from a settings object.
     pub fn from_settings(settings: WindowSettings, shell: &'a mut Shell<A>, adapter: A) -> Self {
-        let logical_size = LogicalSize::new(settings.size.0, settings.size.1);
+        let scale_factor = scale_factor_override(settings.scale_factor.or(shell.scale_factor));
         let window_builder = window::WindowBuilder::new()
             .with_title(settings.title)
             .with_decorations(!settings.borderless)
             .with_resizable(settings.resizeable)
-            .with_always_on_top(settings.always_on_top)
-            .with_inner_size(logical_size);
+            .with_always_on_top(settings.always_on_top);
+
+        // with an overridden scale factor the physical size could be calculated before the window is created
+        let window_builder = if let Some(scale_factor) = scale_factor {
+            window_builder.with_inner_size(PhysicalSize::new(
+                settings.size.0 * scale_factor,
+                settings.size.1 * scale_factor,
+            ))
+        } else {
+            window_builder.with_inner_size(LogicalSize::new(settings.size.0, settings.size.1))
+        };
 
         WindowBuilder {
             shell,
@@ -70,6 +81,7 @@ where
                 (settings.position.0, settings.position.1),
                 (settings.size.0, settings.size.1),
             ),
+            scale_factor,
         }
     }
 
@@ -107,6 +119,12 @@ where
         self
     }
 
+    /// Overrides the scale factor of the window.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Registers a new font with family key.
     pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
         self.fonts.insert(family.into(), font_file);
@@ -120,7 +138,7 @@ where
     }
 
     /// Builds the window shell and add it to the application `Shell`.
-    pub fn build(self) {
+    pub fn build(mut self) {
         // Create an OpenGL 3.x context for Pathfinder to use.
         let gl_context = ContextBuilder::new()
             .with_gl(GlRequest::Latest)
@@ -134,7 +152,9 @@ where
 
         let logical_size = LogicalSize::new(self.bounds.width(), self.bounds.height());
 
-        let scale_factor = gl_context.window().current_monitor().scale_factor();
+        let scale_factor = self
+            .scale_factor
+            .unwrap_or_else(|| gl_context.window().scale_factor());
         let physical_size: PhysicalSize<f64> = logical_size.to_physical(scale_factor);
 
         let framebuffer_size = vec2i(physical_size.width as i32, physical_size.height as i32);
@@ -171,6 +191,9 @@ where
             font_handles,
         );
 
+        render_context.set_scale_factor(scale_factor);
+        self.adapter.scale_factor_changed(scale_factor);
+
         self.shell.window_shells.push(Window::new(
             gl_context,
             self.adapter,
@@ -181,6 +204,9 @@ where
             false,
             (0.0, 0.0),
             scale_factor,
+            self.scale_factor.is_some(),
+            vec![],
+            vec![],
         ))
     }
 }
@@ -27,6 +27,7 @@ runner.run()
 #[macro_use]
 extern crate lazy_static;
 
+pub mod color_scheme;
 pub mod event;
 pub mod prelude;
 pub mod window_adapter;
@@ -54,7 +55,10 @@ pub mod platform;
 
 pub use orbtk_render::prelude as render;
 
-use std::{collections::HashMap, sync::mpsc};
+use std::{collections::HashMap, env, sync::mpsc};
+
+/// Name of the environment variable that overrides the scale factor of all windows, e.g. `ORBTK_SCALE_FACTOR=2`.
+pub const SCALE_FACTOR_ENV: &str = "ORBTK_SCALE_FACTOR";
 
 /// Used to send a request to the window.
 #[derive(Clone, Debug)]
@@ -67,6 +71,9 @@ pub enum WindowRequest {
 
     /// Request to change the title of the `Windows`.
     ChangeTitle(String),
+
+    /// Request to move the candidate window of the input method editor (IME) to the given position.
+    SetImePosition(utils::Point),
 }
 
 /// Used to send a request to the application shell.
@@ -112,4 +119,48 @@ pub struct WindowSettings {
 
     /// List of fonts to register.
     pub fonts: HashMap<String, &'static [u8]>,
+
+    /// Overrides the scale factor reported by the platform, e.g. to test the ui on HiDPI displays. If it is `None`
+    /// the `ORBTK_SCALE_FACTOR` environment variable is checked.
+    pub scale_factor: Option<f64>,
+}
+
+/// Returns the scale factor that overrides the one of the platform. The given `scale_factor` is preferred over the
+/// value of the `ORBTK_SCALE_FACTOR` environment variable.
+pub fn scale_factor_override(scale_factor: Option<f64>) -> Option<f64> {
+    scale_factor
+        .filter(|scale_factor| *scale_factor > 0.0)
+        .or_else(|| {
+            env::var(SCALE_FACTOR_ENV)
+                .ok()
+                .and_then(|value| parse_scale_factor(&value))
+        })
+}
+
+// Parses a scale factor, only positive numbers are valid.
+fn parse_scale_factor(value: &str) -> Option<f64> {
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|scale_factor| scale_factor.is_finite() && *scale_factor > 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_scale_factor() {
+        assert_eq!(parse_scale_factor("2"), Some(2.0));
+        assert_eq!(parse_scale_factor(" 1.5 "), Some(1.5));
+        assert_eq!(parse_scale_factor("0"), None);
+        assert_eq!(parse_scale_factor("-1"), None);
+        assert_eq!(parse_scale_factor("large"), None);
+    }
+
+    #[test]
+    fn test_scale_factor_override() {
+        assert_eq!(scale_factor_override(Some(3.0)), Some(3.0));
+    }
 }
@@ -24,6 +24,7 @@ where
 {
     window_shells: Vec<Window<A>>,
     requests: mpsc::Receiver<ShellRequest<A>>,
+    scale_factor: Option<f64>,
 }
 
 impl<A> Shell<A>
@@ -35,9 +36,15 @@ where
         Shell {
             window_shells: vec![],
             requests,
+            scale_factor: None,
         }
     }
 
+    /// Overrides the scale factor of all windows of the shell, e.g. to test the ui on HiDPI displays.
+    pub fn set_scale_factor(&mut self, scale_factor: f64) {
+        self.scale_factor = Some(scale_factor);
+    }
+
     /// Creates a window builder, that could be used to create a window and add it to the application shell.
     pub fn create_window(&mut self, adapter: A) -> WindowBuilder<A> {
         WindowBuilder::new(self, adapter)
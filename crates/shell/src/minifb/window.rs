@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc, sync::mpsc};
+use std::{cell::RefCell, rc::Rc, sync::mpsc, time::Instant};
 
 use super::{KeyState, MouseState, WindowState};
 use crate::{
@@ -20,11 +20,13 @@ where
     adapter: A,
     render_context: RenderContext2D,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+    scale_factor: f64,
     window_state: WindowState,
     mouse: MouseState,
     update: bool,
     redraw: bool,
     close: bool,
+    polled: Instant,
     key_states: Vec<KeyState>,
     key_events: Rc<RefCell<Vec<KeyEvent>>>,
 }
@@ -38,6 +40,7 @@ where
         adapter: A,
         render_context: RenderContext2D,
         request_receiver: Option<mpsc::Receiver<WindowRequest>>,
+        scale_factor: f64,
         key_states: Vec<KeyState>,
         key_events: Rc<RefCell<Vec<KeyEvent>>>,
     ) -> Self {
@@ -46,11 +49,13 @@ where
             adapter,
             render_context,
             request_receiver,
+            scale_factor,
             window_state: WindowState::default(),
             mouse: MouseState::default(),
             update: true,
             redraw: true,
             close: false,
+            polled: Instant::now(),
             key_states,
             key_events,
         }
@@ -69,7 +74,10 @@ where
         };
 
         self.adapter.mouse_event(MouseEvent {
-            position: Point::new(self.mouse.mouse_pos.0 as f64, self.mouse.mouse_pos.1 as f64),
+            position: Point::new(
+                self.mouse.mouse_pos.0 as f64 / self.scale_factor,
+                self.mouse.mouse_pos.1 as f64 / self.scale_factor,
+            ),
             button,
             state,
         });
@@ -124,10 +132,21 @@ where
     pub fn drain_events(&mut self) {
         self.window.update();
 
+        // updates the adapter periodically if it polls for changes
+        if let Some(interval) = self.adapter.poll_interval() {
+            if self.polled.elapsed() >= interval {
+                self.polled = Instant::now();
+                self.update = true;
+            }
+        }
+
         // mouse move
         if let Some(pos) = self.window.get_mouse_pos(minifb::MouseMode::Discard) {
             if (pos.0.floor(), pos.1.floor()) != self.mouse.mouse_pos {
-                self.adapter.mouse(pos.0 as f64, pos.1 as f64);
+                self.adapter.mouse(
+                    pos.0 as f64 / self.scale_factor,
+                    pos.1 as f64 / self.scale_factor,
+                );
                 self.mouse.mouse_pos = (pos.0.floor(), pos.1.floor());
                 self.update = true;
             }
@@ -182,8 +201,8 @@ where
                 self.window_state.size.1 as f64,
             );
             self.adapter.resize(
-                self.window_state.size.0 as f64,
-                self.window_state.size.1 as f64,
+                self.window_state.size.0 as f64 / self.scale_factor,
+                self.window_state.size.1 as f64 / self.scale_factor,
             );
             self.update = true;
         }
@@ -227,6 +246,8 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    // minifb does not support input methods
+                    WindowRequest::SetImePosition(_) => {}
                 }
             }
         }
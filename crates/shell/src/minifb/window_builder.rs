@@ -4,6 +4,7 @@ use super::{KeyState, Shell, Window};
 use crate::{
     event::{ButtonState, Key, KeyEvent},
     render::RenderContext2D,
+    scale_factor_override,
     utils::Rectangle,
     window_adapter::WindowAdapter,
     WindowRequest, WindowSettings,
@@ -22,6 +23,7 @@ where
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
     bounds: Rectangle,
+    scale_factor: Option<f64>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
 
@@ -40,6 +42,7 @@ where
             borderless: false,
             fonts: HashMap::new(),
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            scale_factor: None,
             request_receiver: None,
         }
     }
@@ -55,6 +58,7 @@ where
             borderless: settings.borderless,
             fonts: settings.fonts,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            scale_factor: settings.scale_factor.or(shell.scale_factor),
             request_receiver: None,
         }
     }
@@ -89,6 +93,12 @@ where
         self
     }
 
+    /// Overrides the scale factor of the window.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Registers a new font with family key.
     pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
         self.fonts.insert(family.into(), font_file);
@@ -102,7 +112,14 @@ where
     }
 
     /// Builds the window shell and add it to the application `Shell`.
-    pub fn build(self) {
+    pub fn build(mut self) {
+        // minifb could not detect the pixel density of the monitor, the scale factor could only be overridden
+        let scale_factor = scale_factor_override(self.scale_factor).unwrap_or(1.0);
+        let physical_size = (
+            (self.bounds.width() * scale_factor).round(),
+            (self.bounds.height() * scale_factor).round(),
+        );
+
         let window_options = minifb::WindowOptions {
             resize: self.resizeable,
             topmost: self.always_on_top,
@@ -114,8 +131,8 @@ where
 
         let mut window = minifb::Window::new(
             self.title.as_str(),
-            self.bounds.width() as usize,
-            self.bounds.height() as usize,
+            physical_size.0 as usize,
+            physical_size.1 as usize,
             window_options,
         )
         .unwrap_or_else(|e| {
@@ -133,7 +150,9 @@ where
 
         window.set_position(self.bounds.x() as isize, self.bounds.y() as isize);
 
-        let mut render_context = RenderContext2D::new(self.bounds.width(), self.bounds.height());
+        let mut render_context = RenderContext2D::new(physical_size.0, physical_size.1);
+        render_context.set_scale_factor(scale_factor);
+        self.adapter.scale_factor_changed(scale_factor);
 
         for (family, font) in self.fonts {
             render_context.register_font(&family, font);
@@ -144,6 +163,7 @@ where
             self.adapter,
             render_context,
             self.request_receiver,
+            scale_factor,
             vec![
                 KeyState::new(minifb::Key::Backspace, Key::Backspace),
                 KeyState::new(minifb::Key::Left, Key::Left),
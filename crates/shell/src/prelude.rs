@@ -1,3 +1,4 @@
 pub use crate::{
-    event::*, platform::*, window_adapter::*, ShellRequest, WindowRequest, WindowSettings,
+    color_scheme::*, event::*, platform::*, window_adapter::*, ShellRequest, WindowRequest,
+    WindowSettings,
 };
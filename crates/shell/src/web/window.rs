@@ -4,14 +4,17 @@ use stdweb::{
     js,
     traits::*,
     unstable::TryInto,
-    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
+    web::{
+        document, event, html_element::CanvasElement, window, CanvasRenderingContext2d, Date,
+        HtmlElement, Touch,
+    },
 };
 
 use derive_more::Constructor;
 
 use super::EventState;
 use crate::{
-    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent},
+    event::{ButtonState, Key, KeyEvent, MouseButton, MouseEvent, TouchEvent, TouchPhase},
     render::RenderContext2D,
     window_adapter::WindowAdapter,
     WindowRequest,
@@ -31,10 +34,14 @@ where
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
     event_state: EventState,
     canvas: CanvasElement,
+    ime_input: HtmlElement,
     old_canvas: Option<CanvasElement>,
     update: bool,
     redraw: bool,
     close: bool,
+    scale_factor: f64,
+    scale_factor_override: Option<f64>,
+    polled: f64,
 }
 
 impl<A> Window<A>
@@ -48,6 +55,15 @@ where
 
     /// Drain events and propagate the events to the adapter.
     pub fn drain_events(&mut self) {
+        // updates the adapter periodically if it polls for changes
+        if let Some(interval) = self.adapter.poll_interval() {
+            let now = Date::now();
+            if now - self.polled >= interval.as_secs_f64() * 1000.0 {
+                self.polled = now;
+                self.update = true;
+            }
+        }
+
         while let Some(event) = self.event_state.mouse_move_events.borrow_mut().pop() {
             self.adapter
                 .mouse(event.client_x() as f64, event.client_y() as f64);
@@ -87,6 +103,7 @@ where
                 button: MouseButton::Left,
                 state: ButtonState::Down,
             });
+            self.touch_events(event.changed_touches(), TouchPhase::Started);
             self.update = true;
         }
 
@@ -99,6 +116,7 @@ where
                 button: MouseButton::Left,
                 state: ButtonState::Up,
             });
+            self.touch_events(event.changed_touches(), TouchPhase::Ended);
 
             self.update = true;
         }
@@ -108,6 +126,12 @@ where
                 event.changed_touches()[0].client_x() as f64,
                 event.changed_touches()[0].client_y() as f64,
             );
+            self.touch_events(event.changed_touches(), TouchPhase::Moved);
+            self.update = true;
+        }
+
+        while let Some(event) = self.event_state.touch_cancel_events.borrow_mut().pop() {
+            self.touch_events(event.changed_touches(), TouchPhase::Cancelled);
             self.update = true;
         }
 
@@ -133,6 +157,13 @@ where
             self.update = true;
         }
 
+        // the composition events are kept in order, the last preedit text is replaced by the committed text
+        let ime_events: Vec<_> = self.event_state.ime_events.borrow_mut().drain(..).collect();
+        for ime_event in ime_events {
+            self.adapter.ime_event(ime_event);
+            self.update = true;
+        }
+
         while let Some(_) = self.event_state.resize_events.borrow_mut().pop() {
             let window_size = (
                 window().inner_width() as f64,
@@ -155,39 +186,18 @@ where
                 @{&canvas}.style.margin = "0";
             }
 
-            let device_pixel_ratio = window().device_pixel_ratio();
             let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
+            let scale_factor = scale_canvas(&canvas, &ctx, self.scale_factor_override);
 
-            let backing_store_ratio = js! {
-                var ctx = @{&ctx};
-                 return ctx.webkitBackingStorePixelRatio ||
-                     ctx.mozBackingStorePixelRatio ||
-                     ctx.msBackingStorePixelRatio ||
-                     ctx.oBackingStorePixelRatio ||
-                     ctx.backingStorePixelRatio || 1;
-            };
-
-            let ratio: f64 = js! {
-                return @{&device_pixel_ratio} / @{&backing_store_ratio};
-            }
-            .try_into()
-            .unwrap();
-
-            if device_pixel_ratio != backing_store_ratio {
-                let old_width = canvas.width();
-                let old_height = canvas.height();
-                canvas.set_width((old_width as f64 * ratio) as u32);
-                canvas.set_height((old_height as f64 * ratio) as u32);
-
-                js! {
-                    @{&canvas}.style.width = @{&old_width} + "px";
-                    @{&canvas}.style.height = @{&old_height} + "px";
-                }
+            self.render_context.set_canvas_render_context_2d(ctx);
+            self.render_context.set_scale_factor(scale_factor);
 
-                ctx.scale(ratio, ratio);
+            // the device pixel ratio changes if the browser is zoomed or moved to another monitor
+            if (scale_factor - self.scale_factor).abs() > f64::EPSILON {
+                self.scale_factor = scale_factor;
+                self.adapter.scale_factor_changed(scale_factor);
             }
 
-            self.render_context.set_canvas_render_context_2d(ctx);
             self.adapter.resize(window_size.0, window_size.1);
             self.old_canvas = Some(self.canvas.clone());
             self.canvas = canvas;
@@ -195,6 +205,17 @@ where
         }
     }
 
+    // Sends the changed touch points to the adapter.
+    fn touch_events(&mut self, touches: Vec<Touch>, phase: TouchPhase) {
+        for touch in touches {
+            self.adapter.touch_event(TouchEvent {
+                id: touch.identifier() as u64,
+                phase,
+                position: Point::new(touch.client_x() as f64, touch.client_y() as f64),
+            });
+        }
+    }
+
     /// Receives window request from the application and handles them.
     pub fn receive_requests(&mut self) {
         if let Some(request_receiver) = &self.request_receiver {
@@ -212,6 +233,15 @@ where
                     WindowRequest::Close => {
                         self.close = true;
                     }
+                    // moves the text area of the input method to the caret and focuses it to receive the composed text
+                    WindowRequest::SetImePosition(position) => {
+                        js! {
+                            var input = @{&self.ime_input};
+                            input.style.left = @{position.x()} + "px";
+                            input.style.top = @{position.y()} + "px";
+                            input.focus({ preventScroll: true });
+                        }
+                    }
                 }
             }
         }
@@ -246,6 +276,45 @@ where
 
 // -- Helpers --
 
+/// Scales the backing store of the canvas to the device pixel ratio or to the overridden scale factor and returns the
+/// used scale factor. The css size of the canvas is kept in logical pixels.
+pub(crate) fn scale_canvas(
+    canvas: &CanvasElement,
+    ctx: &CanvasRenderingContext2d,
+    scale_factor_override: Option<f64>,
+) -> f64 {
+    let scale_factor = scale_factor_override.unwrap_or_else(|| {
+        let device_pixel_ratio = window().device_pixel_ratio();
+
+        let backing_store_ratio: f64 = js! {
+            var ctx = @{ctx};
+             return ctx.webkitBackingStorePixelRatio ||
+                 ctx.mozBackingStorePixelRatio ||
+                 ctx.msBackingStorePixelRatio ||
+                 ctx.oBackingStorePixelRatio ||
+                 ctx.backingStorePixelRatio || 1;
+        }
+        .try_into()
+        .unwrap();
+
+        device_pixel_ratio / backing_store_ratio
+    });
+
+    if (scale_factor - 1.0).abs() > f64::EPSILON {
+        let old_width = canvas.width();
+        let old_height = canvas.height();
+        canvas.set_width((old_width as f64 * scale_factor) as u32);
+        canvas.set_height((old_height as f64 * scale_factor) as u32);
+
+        js! {
+            @{canvas}.style.width = @{&old_width} + "px";
+            @{canvas}.style.height = @{&old_height} + "px";
+        }
+    }
+
+    scale_factor
+}
+
 fn get_mouse_button(button: event::MouseButton) -> MouseButton {
     match button {
         event::MouseButton::Wheel => MouseButton::Middle,
@@ -273,6 +342,7 @@ fn get_key(code: &str, key: String) -> (Key, String) {
         "Enter" => Key::Enter,
         "OSLeft" | "OSRight" => Key::Home,
         "CapsLock" => Key::CapsLock,
+        _ if key == "Dead" || key == "Process" => Key::Unknown,
         _ => {
             text = key.clone();
             Key::from(key.chars().next().unwrap())
@@ -10,13 +10,15 @@ use stdweb::{
     js,
     traits::*,
     unstable::TryInto,
-    web::{document, event, html_element::CanvasElement, window, CanvasRenderingContext2d},
+    web::{
+        document, event, html_element::CanvasElement, window, CanvasRenderingContext2d, HtmlElement,
+    },
 };
 
-use super::{EventState, Shell, Window};
+use super::{window::scale_canvas, EventState, Shell, Window};
 use crate::{
-    render::RenderContext2D, utils::Rectangle, window_adapter::WindowAdapter, WindowRequest,
-    WindowSettings,
+    event::ImeEvent, render::RenderContext2D, scale_factor_override, utils::Rectangle,
+    window_adapter::WindowAdapter, WindowRequest, WindowSettings,
 };
 
 /// The `WindowBuilder` is used to construct a window shell for the web backend.
@@ -32,6 +34,7 @@ where
     borderless: bool,
     fonts: HashMap<String, &'static [u8]>,
     bounds: Rectangle,
+    scale_factor: Option<f64>,
     request_receiver: Option<mpsc::Receiver<WindowRequest>>,
 }
 
@@ -50,6 +53,7 @@ where
             borderless: false,
             fonts: HashMap::new(),
             bounds: Rectangle::new((0.0, 0.0), (100.0, 75.0)),
+            scale_factor: None,
             request_receiver: None,
         }
     }
@@ -65,6 +69,7 @@ where
             borderless: settings.borderless,
             fonts: settings.fonts,
             bounds: Rectangle::new(settings.position, (settings.size.0, settings.size.1)),
+            scale_factor: settings.scale_factor.or(shell.scale_factor),
             request_receiver: None,
         }
     }
@@ -99,6 +104,12 @@ where
         self
     }
 
+    /// Overrides the scale factor of the window.
+    pub fn scale_factor(mut self, scale_factor: f64) -> Self {
+        self.scale_factor = Some(scale_factor);
+        self
+    }
+
     /// Registers a new font with family key.
     pub fn font(mut self, family: impl Into<String>, font_file: &'static [u8]) -> Self {
         self.fonts.insert(family.into(), font_file);
@@ -143,11 +154,13 @@ where
         let touch_start = Rc::new(RefCell::new(vec![]));
         let touch_end = Rc::new(RefCell::new(vec![]));
         let touch_move = Rc::new(RefCell::new(vec![]));
+        let touch_cancel = Rc::new(RefCell::new(vec![]));
         let mouse_down = Rc::new(RefCell::new(vec![]));
         let scroll = Rc::new(RefCell::new(vec![]));
         let key_down = Rc::new(RefCell::new(vec![]));
         let key_up = Rc::new(RefCell::new(vec![]));
         let resize = Rc::new(RefCell::new(vec![]));
+        let ime = Rc::new(RefCell::new(vec![]));
         let mouse_blocked = Rc::new(Cell::new(false));
 
         let mouse_down_c = mouse_down.clone();
@@ -157,6 +170,8 @@ where
             .body()
             .unwrap()
             .add_event_listener(move |e: event::MouseDownEvent| {
+                // keeps the focus on the input method text area
+                e.prevent_default();
                 if !mouse_blocked_c.get() {
                     mouse_down_c.borrow_mut().push(e);
                 }
@@ -199,6 +214,14 @@ where
                 touch_move_c.borrow_mut().push(e);
             });
 
+        let touch_cancel_c = touch_cancel.clone();
+        document()
+            .body()
+            .unwrap()
+            .add_event_listener(move |e: event::TouchCancel| {
+                touch_cancel_c.borrow_mut().push(e);
+            });
+
         let mouse_move_c = mouse_move.clone();
         document()
             .body()
@@ -220,10 +243,68 @@ where
             .body()
             .unwrap()
             .add_event_listener(move |e: event::KeyDownEvent| {
+                // the keys that are processed by the input method are part of the composition events
+                let composing: bool = js! {
+                    return @{&e}.isComposing || @{&e}.keyCode === 229;
+                }
+                .try_into()
+                .unwrap_or(false);
+
+                if composing {
+                    return;
+                }
+
                 e.prevent_default();
                 key_down_c.borrow_mut().push(e);
             });
 
+        // input methods compose text only in editable elements. The hidden text area receives the composition events
+        // and is moved to the caret of the focused text input, where the input method shows its candidate window.
+        let ime_input: HtmlElement = document()
+            .create_element("textarea")
+            .unwrap()
+            .try_into()
+            .unwrap();
+
+        let ime_c = ime.clone();
+        let preedit = move |text: String| {
+            ime_c.borrow_mut().push(ImeEvent::Preedit {
+                cursor: text.chars().count(),
+                text,
+            });
+        };
+
+        let ime_c = ime.clone();
+        let commit = move |text: String| {
+            ime_c.borrow_mut().push(ImeEvent::Commit(text));
+        };
+
+        js! {
+            var input = @{&ime_input};
+            var preedit = @{preedit};
+            var commit = @{commit};
+
+            input.style.position = "absolute";
+            input.style.left = "0px";
+            input.style.top = "0px";
+            input.style.width = "1px";
+            input.style.height = "1px";
+            input.style.padding = "0";
+            input.style.border = "none";
+            input.style.outline = "none";
+            input.style.resize = "none";
+            input.style.opacity = "0";
+            input.style.pointerEvents = "none";
+
+            input.addEventListener("compositionupdate", function(e) {
+                preedit(e.data || "");
+            });
+            input.addEventListener("compositionend", function(e) {
+                commit(e.data || "");
+                input.value = "";
+            });
+        }
+
         let key_up_c = key_up.clone();
         document().add_event_listener(move |e: event::KeyUpEvent| {
             e.prevent_default();
@@ -237,40 +318,15 @@ where
         });
 
         document().body().unwrap().append_child(&canvas);
+        document().body().unwrap().append_child(&ime_input);
         let ctx: CanvasRenderingContext2d = canvas.get_context().unwrap();
 
-        let device_pixel_ratio = window().device_pixel_ratio();
-
-        let backing_store_ratio = js! {
-            var ctx = @{&ctx};
-             return ctx.webkitBackingStorePixelRatio ||
-                 ctx.mozBackingStorePixelRatio ||
-                 ctx.msBackingStorePixelRatio ||
-                 ctx.oBackingStorePixelRatio ||
-                 ctx.backingStorePixelRatio || 1;
-        };
-
-        let ratio: f64 = js! {
-            return @{&device_pixel_ratio} / @{&backing_store_ratio};
-        }
-        .try_into()
-        .unwrap();
-
-        if device_pixel_ratio != backing_store_ratio {
-            let old_width = canvas.width();
-            let old_height = canvas.height();
-            canvas.set_width((old_width as f64 * ratio) as u32);
-            canvas.set_height((old_height as f64 * ratio) as u32);
-
-            js! {
-                @{&canvas}.style.width = @{&old_width} + "px";
-                @{&canvas}.style.height = @{&old_height} + "px";
-            }
-
-            ctx.scale(ratio, ratio);
-        }
+        let fixed_scale_factor = scale_factor_override(self.scale_factor);
+        let scale_factor = scale_canvas(&canvas, &ctx, fixed_scale_factor);
 
-        let render_context = RenderContext2D::from_context(canvas.get_context().unwrap());
+        let mut render_context = RenderContext2D::from_context(ctx);
+        render_context.set_scale_factor(scale_factor);
+        self.adapter.scale_factor_changed(scale_factor);
 
         document().set_title(self.title.as_str());
 
@@ -286,17 +342,23 @@ where
                 touch_start_events: touch_start,
                 touch_end_events: touch_end,
                 touch_move_events: touch_move,
+                touch_cancel_events: touch_cancel,
                 mouse_down_events: mouse_down,
                 scroll_events: scroll,
                 key_down_events: key_down,
                 key_up_events: key_up,
                 resize_events: resize,
+                ime_events: ime,
             },
             canvas,
+            ime_input,
             None,
             true,
             true,
             false,
+            scale_factor,
+            fixed_scale_factor,
+            0.0,
         ));
     }
 }
@@ -1,26 +1,54 @@
 //! This module contains traits to inject custom logic into the window shell.
 
+use std::{path::PathBuf, time::Duration};
+
 use crate::render::RenderContext2D;
 use crate::{event::*, utils::Point};
 
 /// The `WindowAdapter` represents the bridge to the `Shell` backend.
 /// It receives events from the `Window` and runs it's own logic.  
 pub trait WindowAdapter {
-    /// Is called after the window is resized.
+    /// Is called after the window is resized. The size is given in logical units.
     fn resize(&mut self, _width: f64, _height: f64) {}
 
+    /// Is called if the scale factor of the window is changed, e.g. if the window is moved to a monitor with another
+    /// pixel density. It is also called after the window is created. All sizes and positions that are passed to the
+    /// adapter are logical, physical pixels are logical units multiplied by the scale factor.
+    fn scale_factor_changed(&mut self, _scale_factor: f64) {}
+
+    /// Is called after the window was moved on the screen.
+    fn moved(&mut self, _x: f64, _y: f64) {}
+
     /// Is called after the mouse was moved.
     fn mouse(&mut self, _x: f64, _y: f64) {}
 
     /// Is called after the state of a mouse button is changed.
     fn mouse_event(&mut self, _event: MouseEvent) {}
 
+    /// Is called if the state of a touch point is changed.
+    fn touch_event(&mut self, _event: TouchEvent) {}
+
     /// Is called if mouse wheel or trackpad detect scroll event.
     fn scroll(&mut self, _delta_x: f64, _delta_y: f64) {}
 
     /// Is called after the state of a keyboard key is changed.
     fn key_event(&mut self, _event: KeyEvent) {}
 
+    /// Is called if an input method editor (IME), a dead key or a compose sequence produces text.
+    fn ime_event(&mut self, _event: ImeEvent) {}
+
+    /// Is called if files are dragged from outside over the window.
+    fn file_hovered(&mut self, _paths: Vec<PathBuf>, _position: Point) {}
+
+    /// Is called if dragged files leave the window without being dropped.
+    fn file_hover_cancelled(&mut self) {}
+
+    /// Is called after files are dropped from outside on the window.
+    fn file_dropped(&mut self, _paths: Vec<PathBuf>, _position: Point) {}
+
+    /// Is called if the preferred color scheme of the desktop is changed.
+    fn color_scheme_changed(&mut self, _color_scheme: ColorScheme) {}
+
     /// Is called after the quit event of the window is called.
     fn quit_event(&mut self) {}
 
@@ -30,6 +58,12 @@ pub trait WindowAdapter {
     /// Is called if active state of the window is changed.
     fn active(&mut self, active: bool);
 
+    /// Returns the interval the adapter wants to be run in also if there are no events, e.g. to poll for changed
+    /// files. `None` means the adapter is only run after events and requests.
+    fn poll_interval(&self) -> Option<Duration> {
+        None
+    }
+
     /// Runs the inner logic of the shell adapter.
     fn run(&mut self, render_context: &mut RenderContext2D);
 }
@@ -24,6 +24,9 @@ pub const COLORS_RON: &str = include_str!("../assets/common/colors.ron");
 /// The common fonts resource file.
 pub const FONTS_RON: &str = include_str!("../assets/common/fonts.ron");
 
+/// The common density scales resource file.
+pub const DENSITY_RON: &str = include_str!("../assets/common/density.ron");
+
 /// The light theme resource file.
 pub const LIGHT_THEME_RON: &str = include_str!("../assets/light/light.ron");
 
@@ -53,7 +56,8 @@ pub fn dark_theme() -> Theme {
     Theme::from_config(
         ThemeConfig::from(DARK_THEME_RON)
             .extend(ThemeConfig::from(COLORS_RON))
-            .extend(ThemeConfig::from(FONTS_RON)),
+            .extend(ThemeConfig::from(FONTS_RON))
+            .extend(ThemeConfig::from(DENSITY_RON)),
     )
 }
 
@@ -62,7 +66,8 @@ pub fn light_theme() -> Theme {
     Theme::from_config(
         ThemeConfig::from(LIGHT_THEME_RON)
             .extend(ThemeConfig::from(COLORS_RON))
-            .extend(ThemeConfig::from(FONTS_RON)),
+            .extend(ThemeConfig::from(FONTS_RON))
+            .extend(ThemeConfig::from(DENSITY_RON)),
     )
 }
 
@@ -72,6 +77,95 @@ pub fn redox_theme() -> Theme {
         ThemeConfig::from(REDOX_THEME_RON)
             .extend(ThemeConfig::from(COLORS_RON))
             .extend(ThemeConfig::from(REDOX_COLORS_RON))
-            .extend(ThemeConfig::from(FONTS_RON)),
+            .extend(ThemeConfig::from(FONTS_RON))
+            .extend(ThemeConfig::from(DENSITY_RON)),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use orbtk_theming::DiagnosticKind;
+
+    // states the default widgets could have
+    const STATES: [&str; 7] = [
+        "disabled", "empty", "expanded", "focused", "hover", "pressed", "selected",
+    ];
+
+    fn assert_valid(sources: &[(&str, &str)]) {
+        let validator = sources
+            .iter()
+            .fold(ThemeValidator::new(), |validator, (name, ron)| {
+                validator.source(*name, *ron)
+            })
+            .known_states(&STATES);
+
+        // the common resources are shared by all themes, so unused resources are allowed
+        let problems: Vec<_> = validator
+            .validate()
+            .into_iter()
+            .filter(|diagnostic| !matches!(diagnostic.kind, DiagnosticKind::UnusedResource(_)))
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+
+        assert!(problems.is_empty(), "{:#?}", problems);
+    }
+
+    #[test]
+    fn test_dark_theme() {
+        assert_valid(&[
+            ("dark.ron", DARK_THEME_RON),
+            ("colors.ron", COLORS_RON),
+            ("fonts.ron", FONTS_RON),
+            ("density.ron", DENSITY_RON),
+        ]);
+    }
+
+    #[test]
+    fn test_light_theme() {
+        assert_valid(&[
+            ("light.ron", LIGHT_THEME_RON),
+            ("colors.ron", COLORS_RON),
+            ("fonts.ron", FONTS_RON),
+            ("density.ron", DENSITY_RON),
+        ]);
+    }
+
+    #[test]
+    fn test_redox_theme() {
+        assert_valid(&[
+            ("redox.ron", REDOX_THEME_RON),
+            ("colors.ron", COLORS_RON),
+            ("redox_colors.ron", REDOX_COLORS_RON),
+            ("fonts.ron", FONTS_RON),
+            ("density.ron", DENSITY_RON),
+        ]);
+    }
+
+    #[test]
+    fn test_material_icons() {
+        use crate::vector_graphics::*;
+
+        // the table is searched by name
+        assert!(MATERIAL_ICONS
+            .windows(2)
+            .all(|icons| icons[0].0 < icons[1].0));
+        assert!(MATERIAL_ICONS.contains(&("save", MD_SAVE)));
+        assert!(MATERIAL_ICONS.contains(&("3d_rotation", MD_3D_ROTATION)));
+    }
+
+    #[test]
+    fn test_density() {
+        let height = |theme: Theme| {
+            theme.properties(&Selector::new("button")).unwrap()["height"]
+                .clone()
+                .into_rust::<f64>()
+                .unwrap()
+        };
+
+        assert_eq!(height(dark_theme()), 36.0);
+        assert_eq!(height(dark_theme().with_density(Density::Compact)), 27.0);
+        assert_eq!(height(light_theme().with_density(Density::Touch)), 54.0);
+    }
+}
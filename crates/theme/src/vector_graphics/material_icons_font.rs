@@ -1397,3 +1397,1474 @@ pub const MD_TAPAS: &str = "\u{e893}";
 pub const MD_WATER_DAMAGE: &str = "\u{e895}";
 pub const MD_WEST: &str = "\u{e897}";
 pub const MD_WINE_BAR: &str = "\u{e898}";
+
+/// Names and glyphs of the constants, sorted by name. The name of a glyph is the lowercase name of its constant
+/// without the `MD_` prefix, e.g. `save` for `MD_SAVE`.
+pub static MATERIAL_ICONS: &[(&str, &str)] = &[
+    ("360", MD_360),
+    ("3d_rotation", MD_3D_ROTATION),
+    ("4k", MD_4K),
+    ("5g", MD_5G),
+    ("ac_unit", MD_AC_UNIT),
+    ("access_alarm", MD_ACCESS_ALARM),
+    ("access_alarms", MD_ACCESS_ALARMS),
+    ("access_time", MD_ACCESS_TIME),
+    ("accessibility", MD_ACCESSIBILITY),
+    ("accessibility_new", MD_ACCESSIBILITY_NEW),
+    ("accessible", MD_ACCESSIBLE),
+    ("accessible_forward", MD_ACCESSIBLE_FORWARD),
+    ("account_balance", MD_ACCOUNT_BALANCE),
+    ("account_balance_wallet", MD_ACCOUNT_BALANCE_WALLET),
+    ("account_box", MD_ACCOUNT_BOX),
+    ("account_circle", MD_ACCOUNT_CIRCLE),
+    ("account_tree", MD_ACCOUNT_TREE),
+    ("ad_units", MD_AD_UNITS),
+    ("adb", MD_ADB),
+    ("add", MD_ADD),
+    ("add_a_photo", MD_ADD_A_PHOTO),
+    ("add_alarm", MD_ADD_ALARM),
+    ("add_alert", MD_ADD_ALERT),
+    ("add_box", MD_ADD_BOX),
+    ("add_business", MD_ADD_BUSINESS),
+    ("add_circle", MD_ADD_CIRCLE),
+    ("add_circle_outline", MD_ADD_CIRCLE_OUTLINE),
+    ("add_comment", MD_ADD_COMMENT),
+    ("add_ic_call", MD_ADD_IC_CALL),
+    ("add_location", MD_ADD_LOCATION),
+    ("add_location_alt", MD_ADD_LOCATION_ALT),
+    ("add_photo_alternate", MD_ADD_PHOTO_ALTERNATE),
+    ("add_road", MD_ADD_ROAD),
+    ("add_shopping_cart", MD_ADD_SHOPPING_CART),
+    ("add_to_home_screen", MD_ADD_TO_HOME_SCREEN),
+    ("add_to_photos", MD_ADD_TO_PHOTOS),
+    ("add_to_queue", MD_ADD_TO_QUEUE),
+    ("addchart", MD_ADDCHART),
+    ("adjust", MD_ADJUST),
+    ("admin_panel_settings", MD_ADMIN_PANEL_SETTINGS),
+    ("agriculture", MD_AGRICULTURE),
+    ("airline_seat_flat", MD_AIRLINE_SEAT_FLAT),
+    ("airline_seat_flat_angled", MD_AIRLINE_SEAT_FLAT_ANGLED),
+    (
+        "airline_seat_individual_suite",
+        MD_AIRLINE_SEAT_INDIVIDUAL_SUITE,
+    ),
+    ("airline_seat_legroom_extra", MD_AIRLINE_SEAT_LEGROOM_EXTRA),
+    (
+        "airline_seat_legroom_normal",
+        MD_AIRLINE_SEAT_LEGROOM_NORMAL,
+    ),
+    (
+        "airline_seat_legroom_reduced",
+        MD_AIRLINE_SEAT_LEGROOM_REDUCED,
+    ),
+    ("airline_seat_recline_extra", MD_AIRLINE_SEAT_RECLINE_EXTRA),
+    (
+        "airline_seat_recline_normal",
+        MD_AIRLINE_SEAT_RECLINE_NORMAL,
+    ),
+    ("airplanemode_active", MD_AIRPLANEMODE_ACTIVE),
+    ("airplanemode_inactive", MD_AIRPLANEMODE_INACTIVE),
+    ("airplay", MD_AIRPLAY),
+    ("airport_shuttle", MD_AIRPORT_SHUTTLE),
+    ("alarm", MD_ALARM),
+    ("alarm_add", MD_ALARM_ADD),
+    ("alarm_off", MD_ALARM_OFF),
+    ("alarm_on", MD_ALARM_ON),
+    ("album", MD_ALBUM),
+    ("all_inbox", MD_ALL_INBOX),
+    ("all_inclusive", MD_ALL_INCLUSIVE),
+    ("all_out", MD_ALL_OUT),
+    ("alt_route", MD_ALT_ROUTE),
+    ("alternate_email", MD_ALTERNATE_EMAIL),
+    ("amp_stories", MD_AMP_STORIES),
+    ("analytics", MD_ANALYTICS),
+    ("anchor", MD_ANCHOR),
+    ("android", MD_ANDROID),
+    ("announcement", MD_ANNOUNCEMENT),
+    ("apartment", MD_APARTMENT),
+    ("api", MD_API),
+    ("app_blocking", MD_APP_BLOCKING),
+    ("app_settings_alt", MD_APP_SETTINGS_ALT),
+    ("apps", MD_APPS),
+    ("architecture", MD_ARCHITECTURE),
+    ("archive", MD_ARCHIVE),
+    ("arrow_back", MD_ARROW_BACK),
+    ("arrow_back_ios", MD_ARROW_BACK_IOS),
+    ("arrow_circle_down", MD_ARROW_CIRCLE_DOWN),
+    ("arrow_circle_up", MD_ARROW_CIRCLE_UP),
+    ("arrow_downward", MD_ARROW_DOWNWARD),
+    ("arrow_drop_down", MD_ARROW_DROP_DOWN),
+    ("arrow_drop_down_circle", MD_ARROW_DROP_DOWN_CIRCLE),
+    ("arrow_drop_up", MD_ARROW_DROP_UP),
+    ("arrow_forward", MD_ARROW_FORWARD),
+    ("arrow_forward_ios", MD_ARROW_FORWARD_IOS),
+    ("arrow_left", MD_ARROW_LEFT),
+    ("arrow_right", MD_ARROW_RIGHT),
+    ("arrow_right_alt", MD_ARROW_RIGHT_ALT),
+    ("arrow_upward", MD_ARROW_UPWARD),
+    ("art_track", MD_ART_TRACK),
+    ("article", MD_ARTICLE),
+    ("aspect_ratio", MD_ASPECT_RATIO),
+    ("assessment", MD_ASSESSMENT),
+    ("assignment", MD_ASSIGNMENT),
+    ("assignment_ind", MD_ASSIGNMENT_IND),
+    ("assignment_late", MD_ASSIGNMENT_LATE),
+    ("assignment_return", MD_ASSIGNMENT_RETURN),
+    ("assignment_returned", MD_ASSIGNMENT_RETURNED),
+    ("assignment_turned_in", MD_ASSIGNMENT_TURNED_IN),
+    ("assistant", MD_ASSISTANT),
+    ("assistant_photo", MD_ASSISTANT_PHOTO),
+    ("atm", MD_ATM),
+    ("attach_email", MD_ATTACH_EMAIL),
+    ("attach_file", MD_ATTACH_FILE),
+    ("attach_money", MD_ATTACH_MONEY),
+    ("attachment", MD_ATTACHMENT),
+    ("audiotrack", MD_AUDIOTRACK),
+    ("auto_delete", MD_AUTO_DELETE),
+    ("autorenew", MD_AUTORENEW),
+    ("av_timer", MD_AV_TIMER),
+    ("baby_changing_station", MD_BABY_CHANGING_STATION),
+    ("backpack", MD_BACKPACK),
+    ("backspace", MD_BACKSPACE),
+    ("backup", MD_BACKUP),
+    ("backup_table", MD_BACKUP_TABLE),
+    ("ballot", MD_BALLOT),
+    ("bar_chart", MD_BAR_CHART),
+    ("barcode", MD_BARCODE),
+    ("batch_prediction", MD_BATCH_PREDICTION),
+    ("bathtub", MD_BATHTUB),
+    ("battery_20_after", MD_BATTERY_20_AFTER),
+    ("battery_20_before", MD_BATTERY_20_BEFORE),
+    ("battery_30_after", MD_BATTERY_30_AFTER),
+    ("battery_30_before", MD_BATTERY_30_BEFORE),
+    ("battery_50_after", MD_BATTERY_50_AFTER),
+    ("battery_50_before", MD_BATTERY_50_BEFORE),
+    ("battery_60_after", MD_BATTERY_60_AFTER),
+    ("battery_60_before", MD_BATTERY_60_BEFORE),
+    ("battery_80_after", MD_BATTERY_80_AFTER),
+    ("battery_80_before", MD_BATTERY_80_BEFORE),
+    ("battery_90_after", MD_BATTERY_90_AFTER),
+    ("battery_90_before", MD_BATTERY_90_BEFORE),
+    ("battery_alert", MD_BATTERY_ALERT),
+    ("battery_charging_20_after", MD_BATTERY_CHARGING_20_AFTER),
+    ("battery_charging_20_before", MD_BATTERY_CHARGING_20_BEFORE),
+    ("battery_charging_30_after", MD_BATTERY_CHARGING_30_AFTER),
+    ("battery_charging_30_before", MD_BATTERY_CHARGING_30_BEFORE),
+    ("battery_charging_50_after", MD_BATTERY_CHARGING_50_AFTER),
+    ("battery_charging_50_before", MD_BATTERY_CHARGING_50_BEFORE),
+    ("battery_charging_60_after", MD_BATTERY_CHARGING_60_AFTER),
+    ("battery_charging_60_before", MD_BATTERY_CHARGING_60_BEFORE),
+    ("battery_charging_80_after", MD_BATTERY_CHARGING_80_AFTER),
+    ("battery_charging_80_before", MD_BATTERY_CHARGING_80_BEFORE),
+    ("battery_charging_90_after", MD_BATTERY_CHARGING_90_AFTER),
+    ("battery_charging_90_before", MD_BATTERY_CHARGING_90_BEFORE),
+    ("battery_charging_full", MD_BATTERY_CHARGING_FULL),
+    ("battery_full", MD_BATTERY_FULL),
+    ("battery_std", MD_BATTERY_STD),
+    ("battery_unknown", MD_BATTERY_UNKNOWN),
+    ("beach_access", MD_BEACH_ACCESS),
+    ("bedtime", MD_BEDTIME),
+    ("beenhere", MD_BEENHERE),
+    ("bento", MD_BENTO),
+    ("bike_scooter", MD_BIKE_SCOOTER),
+    ("biotech", MD_BIOTECH),
+    ("block", MD_BLOCK),
+    ("bluetooth", MD_BLUETOOTH),
+    ("bluetooth_audio", MD_BLUETOOTH_AUDIO),
+    ("bluetooth_connected", MD_BLUETOOTH_CONNECTED),
+    ("bluetooth_disabled", MD_BLUETOOTH_DISABLED),
+    ("bluetooth_searching", MD_BLUETOOTH_SEARCHING),
+    ("blur_circular", MD_BLUR_CIRCULAR),
+    ("blur_linear", MD_BLUR_LINEAR),
+    ("blur_off", MD_BLUR_OFF),
+    ("blur_on", MD_BLUR_ON),
+    ("book", MD_BOOK),
+    ("bookmark", MD_BOOKMARK),
+    ("bookmark_border", MD_BOOKMARK_BORDER),
+    ("bookmarks", MD_BOOKMARKS),
+    ("border_all", MD_BORDER_ALL),
+    ("border_bottom", MD_BORDER_BOTTOM),
+    ("border_clear", MD_BORDER_CLEAR),
+    ("border_color_after", MD_BORDER_COLOR_AFTER),
+    ("border_color_before", MD_BORDER_COLOR_BEFORE),
+    ("border_horizontal", MD_BORDER_HORIZONTAL),
+    ("border_inner", MD_BORDER_INNER),
+    ("border_left", MD_BORDER_LEFT),
+    ("border_outer", MD_BORDER_OUTER),
+    ("border_right", MD_BORDER_RIGHT),
+    ("border_style", MD_BORDER_STYLE),
+    ("border_top", MD_BORDER_TOP),
+    ("border_vertical", MD_BORDER_VERTICAL),
+    ("branding_watermark", MD_BRANDING_WATERMARK),
+    ("brightness_1", MD_BRIGHTNESS_1),
+    ("brightness_2", MD_BRIGHTNESS_2),
+    ("brightness_3", MD_BRIGHTNESS_3),
+    ("brightness_4", MD_BRIGHTNESS_4),
+    ("brightness_5", MD_BRIGHTNESS_5),
+    ("brightness_6", MD_BRIGHTNESS_6),
+    ("brightness_7", MD_BRIGHTNESS_7),
+    ("brightness_auto", MD_BRIGHTNESS_AUTO),
+    ("brightness_high", MD_BRIGHTNESS_HIGH),
+    ("brightness_low", MD_BRIGHTNESS_LOW),
+    ("brightness_medium", MD_BRIGHTNESS_MEDIUM),
+    ("broken_image", MD_BROKEN_IMAGE),
+    ("browser_not_supported", MD_BROWSER_NOT_SUPPORTED),
+    ("brush", MD_BRUSH),
+    ("bubble_chart", MD_BUBBLE_CHART),
+    ("bug_report", MD_BUG_REPORT),
+    ("build", MD_BUILD),
+    ("build_circle", MD_BUILD_CIRCLE),
+    ("burst_mode", MD_BURST_MODE),
+    ("business", MD_BUSINESS),
+    ("business_center", MD_BUSINESS_CENTER),
+    ("cached", MD_CACHED),
+    ("cake", MD_CAKE),
+    ("calculate", MD_CALCULATE),
+    ("calendar_today", MD_CALENDAR_TODAY),
+    ("calendar_view_day", MD_CALENDAR_VIEW_DAY),
+    ("call", MD_CALL),
+    ("call_end", MD_CALL_END),
+    ("call_made", MD_CALL_MADE),
+    ("call_merge", MD_CALL_MERGE),
+    ("call_missed", MD_CALL_MISSED),
+    ("call_missed_outgoing", MD_CALL_MISSED_OUTGOING),
+    ("call_received", MD_CALL_RECEIVED),
+    ("call_split", MD_CALL_SPLIT),
+    ("call_to_action", MD_CALL_TO_ACTION),
+    ("camera", MD_CAMERA),
+    ("camera_alt", MD_CAMERA_ALT),
+    ("camera_enhance", MD_CAMERA_ENHANCE),
+    ("camera_front", MD_CAMERA_FRONT),
+    ("camera_rear", MD_CAMERA_REAR),
+    ("camera_roll", MD_CAMERA_ROLL),
+    ("campaign", MD_CAMPAIGN),
+    ("cancel", MD_CANCEL),
+    ("cancel_presentation", MD_CANCEL_PRESENTATION),
+    ("cancel_schedule_send", MD_CANCEL_SCHEDULE_SEND),
+    ("card_giftcard", MD_CARD_GIFTCARD),
+    ("card_membership", MD_CARD_MEMBERSHIP),
+    ("card_travel", MD_CARD_TRAVEL),
+    ("carpenter", MD_CARPENTER),
+    ("casino", MD_CASINO),
+    ("cast", MD_CAST),
+    ("cast_connected", MD_CAST_CONNECTED),
+    ("cast_for_education", MD_CAST_FOR_EDUCATION),
+    ("category", MD_CATEGORY),
+    ("cell_wifi_after", MD_CELL_WIFI_AFTER),
+    ("cell_wifi_before", MD_CELL_WIFI_BEFORE),
+    ("center_focus_strong", MD_CENTER_FOCUS_STRONG),
+    ("center_focus_weak", MD_CENTER_FOCUS_WEAK),
+    ("change_history", MD_CHANGE_HISTORY),
+    ("charging_station", MD_CHARGING_STATION),
+    ("chat", MD_CHAT),
+    ("chat_bubble", MD_CHAT_BUBBLE),
+    ("chat_bubble_outline", MD_CHAT_BUBBLE_OUTLINE),
+    ("check", MD_CHECK),
+    ("check_box", MD_CHECK_BOX),
+    ("check_box_outline_blank", MD_CHECK_BOX_OUTLINE_BLANK),
+    ("check_circle", MD_CHECK_CIRCLE),
+    ("check_circle_outline", MD_CHECK_CIRCLE_OUTLINE),
+    ("checkroom", MD_CHECKROOM),
+    ("chevron_left", MD_CHEVRON_LEFT),
+    ("chevron_right", MD_CHEVRON_RIGHT),
+    ("child_care", MD_CHILD_CARE),
+    ("child_friendly", MD_CHILD_FRIENDLY),
+    ("chrome_reader_mode", MD_CHROME_READER_MODE),
+    ("class", MD_CLASS),
+    ("cleaning_services", MD_CLEANING_SERVICES),
+    ("clear", MD_CLEAR),
+    ("clear_all", MD_CLEAR_ALL),
+    ("close", MD_CLOSE),
+    ("close_fullscreen", MD_CLOSE_FULLSCREEN),
+    ("closed_caption", MD_CLOSED_CAPTION),
+    ("closed_caption_disabled", MD_CLOSED_CAPTION_DISABLED),
+    ("cloud", MD_CLOUD),
+    ("cloud_circle", MD_CLOUD_CIRCLE),
+    ("cloud_done", MD_CLOUD_DONE),
+    ("cloud_download", MD_CLOUD_DOWNLOAD),
+    ("cloud_off", MD_CLOUD_OFF),
+    ("cloud_queue", MD_CLOUD_QUEUE),
+    ("cloud_upload", MD_CLOUD_UPLOAD),
+    ("code", MD_CODE),
+    ("collections", MD_COLLECTIONS),
+    ("collections_bookmark", MD_COLLECTIONS_BOOKMARK),
+    ("color_lens", MD_COLOR_LENS),
+    ("colorize", MD_COLORIZE),
+    ("comment", MD_COMMENT),
+    ("comment_bank", MD_COMMENT_BANK),
+    ("commute", MD_COMMUTE),
+    ("compare", MD_COMPARE),
+    ("compare_arrows", MD_COMPARE_ARROWS),
+    ("compass_calibration", MD_COMPASS_CALIBRATION),
+    ("computer", MD_COMPUTER),
+    ("confirmation_number", MD_CONFIRMATION_NUMBER),
+    ("construction", MD_CONSTRUCTION),
+    ("contact_mail", MD_CONTACT_MAIL),
+    ("contact_phone", MD_CONTACT_PHONE),
+    ("contact_support", MD_CONTACT_SUPPORT),
+    ("contactless", MD_CONTACTLESS),
+    ("contacts", MD_CONTACTS),
+    ("content_copy", MD_CONTENT_COPY),
+    ("content_cut", MD_CONTENT_CUT),
+    ("content_paste", MD_CONTENT_PASTE),
+    ("control_camera", MD_CONTROL_CAMERA),
+    ("control_point", MD_CONTROL_POINT),
+    ("control_point_duplicate", MD_CONTROL_POINT_DUPLICATE),
+    ("copyright", MD_COPYRIGHT),
+    ("corporate_fare", MD_CORPORATE_FARE),
+    ("countertops", MD_COUNTERTOPS),
+    ("create", MD_CREATE),
+    ("create_new_folder", MD_CREATE_NEW_FOLDER),
+    ("credit_card", MD_CREDIT_CARD),
+    ("crop", MD_CROP),
+    ("crop_16_9", MD_CROP_16_9),
+    ("crop_3_2", MD_CROP_3_2),
+    ("crop_5_4", MD_CROP_5_4),
+    ("crop_7_5", MD_CROP_7_5),
+    ("crop_din", MD_CROP_DIN),
+    ("crop_free", MD_CROP_FREE),
+    ("crop_landscape", MD_CROP_LANDSCAPE),
+    ("crop_original", MD_CROP_ORIGINAL),
+    ("crop_portrait", MD_CROP_PORTRAIT),
+    ("crop_rotate", MD_CROP_ROTATE),
+    ("crop_square", MD_CROP_SQUARE),
+    ("dashboard", MD_DASHBOARD),
+    ("data_usage", MD_DATA_USAGE),
+    ("date_range", MD_DATE_RANGE),
+    ("deck", MD_DECK),
+    ("dehaze", MD_DEHAZE),
+    ("delete", MD_DELETE),
+    ("delete_forever", MD_DELETE_FOREVER),
+    ("delete_outline", MD_DELETE_OUTLINE),
+    ("delete_sweep", MD_DELETE_SWEEP),
+    ("departure_board", MD_DEPARTURE_BOARD),
+    ("description", MD_DESCRIPTION),
+    ("design_services", MD_DESIGN_SERVICES),
+    ("desktop_access_disabled", MD_DESKTOP_ACCESS_DISABLED),
+    ("desktop_mac", MD_DESKTOP_MAC),
+    ("desktop_windows", MD_DESKTOP_WINDOWS),
+    ("details", MD_DETAILS),
+    ("developer_board", MD_DEVELOPER_BOARD),
+    ("developer_mode", MD_DEVELOPER_MODE),
+    ("device_hub", MD_DEVICE_HUB),
+    ("device_unknown", MD_DEVICE_UNKNOWN),
+    ("devices", MD_DEVICES),
+    ("devices_other", MD_DEVICES_OTHER),
+    ("dialer_sip", MD_DIALER_SIP),
+    ("dialpad", MD_DIALPAD),
+    ("directions", MD_DIRECTIONS),
+    ("directions_bike", MD_DIRECTIONS_BIKE),
+    ("directions_boat", MD_DIRECTIONS_BOAT),
+    ("directions_bus", MD_DIRECTIONS_BUS),
+    ("directions_car", MD_DIRECTIONS_CAR),
+    ("directions_off", MD_DIRECTIONS_OFF),
+    ("directions_railway", MD_DIRECTIONS_RAILWAY),
+    ("directions_run", MD_DIRECTIONS_RUN),
+    ("directions_subway", MD_DIRECTIONS_SUBWAY),
+    ("directions_transit", MD_DIRECTIONS_TRANSIT),
+    ("directions_walk", MD_DIRECTIONS_WALK),
+    ("disc_full", MD_DISC_FULL),
+    ("divide", MD_DIVIDE),
+    ("dns", MD_DNS),
+    ("do_not_disturb", MD_DO_NOT_DISTURB),
+    ("do_not_disturb_alt", MD_DO_NOT_DISTURB_ALT),
+    ("do_not_disturb_off", MD_DO_NOT_DISTURB_OFF),
+    ("do_not_step", MD_DO_NOT_STEP),
+    ("do_not_touch", MD_DO_NOT_TOUCH),
+    ("dock", MD_DOCK),
+    ("domain", MD_DOMAIN),
+    ("domain_disabled", MD_DOMAIN_DISABLED),
+    ("domain_verification", MD_DOMAIN_VERIFICATION),
+    ("done", MD_DONE),
+    ("done_all", MD_DONE_ALL),
+    ("done_outline", MD_DONE_OUTLINE),
+    ("donut_large", MD_DONUT_LARGE),
+    ("donut_small", MD_DONUT_SMALL),
+    ("double_arrow", MD_DOUBLE_ARROW),
+    ("drafts", MD_DRAFTS),
+    ("drag_handle", MD_DRAG_HANDLE),
+    ("drag_indicator", MD_DRAG_INDICATOR),
+    ("drive_eta", MD_DRIVE_ETA),
+    ("dry", MD_DRY),
+    ("duo", MD_DUO),
+    ("dvr", MD_DVR),
+    ("dynamic_feed", MD_DYNAMIC_FEED),
+    ("dynamic_form", MD_DYNAMIC_FORM),
+    ("east", MD_EAST),
+    ("eco", MD_ECO),
+    ("edit", MD_EDIT),
+    ("edit_attributes", MD_EDIT_ATTRIBUTES),
+    ("edit_location", MD_EDIT_LOCATION),
+    ("edit_road", MD_EDIT_ROAD),
+    ("eject", MD_EJECT),
+    ("electric_bike", MD_ELECTRIC_BIKE),
+    ("electric_car", MD_ELECTRIC_CAR),
+    ("electric_moped", MD_ELECTRIC_MOPED),
+    ("electric_scooter", MD_ELECTRIC_SCOOTER),
+    ("electrical_services", MD_ELECTRICAL_SERVICES),
+    ("elevator", MD_ELEVATOR),
+    ("email", MD_EMAIL),
+    ("emoji_emotions", MD_EMOJI_EMOTIONS),
+    ("emoji_events", MD_EMOJI_EVENTS),
+    ("emoji_flags", MD_EMOJI_FLAGS),
+    ("emoji_food_beverage", MD_EMOJI_FOOD_BEVERAGE),
+    ("emoji_nature", MD_EMOJI_NATURE),
+    ("emoji_objects", MD_EMOJI_OBJECTS),
+    ("emoji_people", MD_EMOJI_PEOPLE),
+    ("emoji_symbols", MD_EMOJI_SYMBOLS),
+    ("emoji_transportation", MD_EMOJI_TRANSPORTATION),
+    ("engineering", MD_ENGINEERING),
+    ("enhanced_encryption", MD_ENHANCED_ENCRYPTION),
+    ("equalizer", MD_EQUALIZER),
+    ("equals", MD_EQUALS),
+    ("error", MD_ERROR),
+    ("error_outline", MD_ERROR_OUTLINE),
+    ("escalator", MD_ESCALATOR),
+    ("escalator_warning", MD_ESCALATOR_WARNING),
+    ("euro", MD_EURO),
+    ("euro_symbol", MD_EURO_SYMBOL),
+    ("ev_station", MD_EV_STATION),
+    ("event", MD_EVENT),
+    ("event_available", MD_EVENT_AVAILABLE),
+    ("event_busy", MD_EVENT_BUSY),
+    ("event_note", MD_EVENT_NOTE),
+    ("event_seat", MD_EVENT_SEAT),
+    ("exit_to_app", MD_EXIT_TO_APP),
+    ("expand_less", MD_EXPAND_LESS),
+    ("expand_more", MD_EXPAND_MORE),
+    ("explicit", MD_EXPLICIT),
+    ("explore", MD_EXPLORE),
+    ("explore_off", MD_EXPLORE_OFF),
+    ("exposure", MD_EXPOSURE),
+    ("exposure_neg_1", MD_EXPOSURE_NEG_1),
+    ("exposure_neg_2", MD_EXPOSURE_NEG_2),
+    ("exposure_plus_1", MD_EXPOSURE_PLUS_1),
+    ("exposure_plus_2", MD_EXPOSURE_PLUS_2),
+    ("exposure_zero", MD_EXPOSURE_ZERO),
+    ("extension", MD_EXTENSION),
+    ("face", MD_FACE),
+    ("fact_check", MD_FACT_CHECK),
+    ("family_restroom", MD_FAMILY_RESTROOM),
+    ("fast_forward", MD_FAST_FORWARD),
+    ("fast_rewind", MD_FAST_REWIND),
+    ("fastfood", MD_FASTFOOD),
+    ("favorite", MD_FAVORITE),
+    ("favorite_border", MD_FAVORITE_BORDER),
+    ("featured_play_list", MD_FEATURED_PLAY_LIST),
+    ("featured_video", MD_FEATURED_VIDEO),
+    ("feedback", MD_FEEDBACK),
+    ("fence", MD_FENCE),
+    ("fiber_dvr", MD_FIBER_DVR),
+    ("fiber_manual_record", MD_FIBER_MANUAL_RECORD),
+    ("fiber_new", MD_FIBER_NEW),
+    ("fiber_pin", MD_FIBER_PIN),
+    ("fiber_smart_record", MD_FIBER_SMART_RECORD),
+    ("file_copy", MD_FILE_COPY),
+    ("file_upload", MD_FILE_UPLOAD),
+    ("filter", MD_FILTER),
+    ("filter_1", MD_FILTER_1),
+    ("filter_2", MD_FILTER_2),
+    ("filter_3", MD_FILTER_3),
+    ("filter_4", MD_FILTER_4),
+    ("filter_5", MD_FILTER_5),
+    ("filter_6", MD_FILTER_6),
+    ("filter_7", MD_FILTER_7),
+    ("filter_8", MD_FILTER_8),
+    ("filter_9", MD_FILTER_9),
+    ("filter_9_plus", MD_FILTER_9_PLUS),
+    ("filter_alt", MD_FILTER_ALT),
+    ("filter_b_and_w", MD_FILTER_B_AND_W),
+    ("filter_center_focus", MD_FILTER_CENTER_FOCUS),
+    ("filter_drama", MD_FILTER_DRAMA),
+    ("filter_frames", MD_FILTER_FRAMES),
+    ("filter_hdr", MD_FILTER_HDR),
+    ("filter_list", MD_FILTER_LIST),
+    ("filter_none", MD_FILTER_NONE),
+    ("filter_tilt_shift", MD_FILTER_TILT_SHIFT),
+    ("filter_vintage", MD_FILTER_VINTAGE),
+    ("find_in_page", MD_FIND_IN_PAGE),
+    ("find_replace", MD_FIND_REPLACE),
+    ("fingerprint", MD_FINGERPRINT),
+    ("fire_extinguisher", MD_FIRE_EXTINGUISHER),
+    ("fireplace", MD_FIREPLACE),
+    ("first_page", MD_FIRST_PAGE),
+    ("fitness_center", MD_FITNESS_CENTER),
+    ("flag", MD_FLAG),
+    ("flaky", MD_FLAKY),
+    ("flare", MD_FLARE),
+    ("flash_auto", MD_FLASH_AUTO),
+    ("flash_off", MD_FLASH_OFF),
+    ("flash_on", MD_FLASH_ON),
+    ("flight", MD_FLIGHT),
+    ("flight_land", MD_FLIGHT_LAND),
+    ("flight_takeoff", MD_FLIGHT_TAKEOFF),
+    ("flip", MD_FLIP),
+    ("flip_camera_android", MD_FLIP_CAMERA_ANDROID),
+    ("flip_camera_ios", MD_FLIP_CAMERA_IOS),
+    ("flip_to_back", MD_FLIP_TO_BACK),
+    ("flip_to_front", MD_FLIP_TO_FRONT),
+    ("folder", MD_FOLDER),
+    ("folder_open", MD_FOLDER_OPEN),
+    ("folder_shared", MD_FOLDER_SHARED),
+    ("folder_special", MD_FOLDER_SPECIAL),
+    ("font_download", MD_FONT_DOWNLOAD),
+    ("food_bank", MD_FOOD_BANK),
+    ("format_align_center", MD_FORMAT_ALIGN_CENTER),
+    ("format_align_justify", MD_FORMAT_ALIGN_JUSTIFY),
+    ("format_align_left", MD_FORMAT_ALIGN_LEFT),
+    ("format_align_right", MD_FORMAT_ALIGN_RIGHT),
+    ("format_bold", MD_FORMAT_BOLD),
+    ("format_clear", MD_FORMAT_CLEAR),
+    ("format_color_fill_after", MD_FORMAT_COLOR_FILL_AFTER),
+    ("format_color_fill_before", MD_FORMAT_COLOR_FILL_BEFORE),
+    ("format_color_reset", MD_FORMAT_COLOR_RESET),
+    ("format_color_text_after", MD_FORMAT_COLOR_TEXT_AFTER),
+    ("format_color_text_before", MD_FORMAT_COLOR_TEXT_BEFORE),
+    ("format_indent_decrease", MD_FORMAT_INDENT_DECREASE),
+    ("format_indent_increase", MD_FORMAT_INDENT_INCREASE),
+    ("format_italic", MD_FORMAT_ITALIC),
+    ("format_line_spacing", MD_FORMAT_LINE_SPACING),
+    ("format_list_bulleted", MD_FORMAT_LIST_BULLETED),
+    ("format_list_numbered", MD_FORMAT_LIST_NUMBERED),
+    ("format_list_numbered_rtl", MD_FORMAT_LIST_NUMBERED_RTL),
+    ("format_paint", MD_FORMAT_PAINT),
+    ("format_quote", MD_FORMAT_QUOTE),
+    ("format_shapes", MD_FORMAT_SHAPES),
+    ("format_size", MD_FORMAT_SIZE),
+    ("format_strikethrough", MD_FORMAT_STRIKETHROUGH),
+    (
+        "format_textdirection_l_to_r",
+        MD_FORMAT_TEXTDIRECTION_L_TO_R,
+    ),
+    (
+        "format_textdirection_r_to_l",
+        MD_FORMAT_TEXTDIRECTION_R_TO_L,
+    ),
+    ("format_underlined", MD_FORMAT_UNDERLINED),
+    ("forum", MD_FORUM),
+    ("forward", MD_FORWARD),
+    ("forward_10", MD_FORWARD_10),
+    ("forward_30", MD_FORWARD_30),
+    ("forward_5", MD_FORWARD_5),
+    ("forward_to_inbox", MD_FORWARD_TO_INBOX),
+    ("foundation", MD_FOUNDATION),
+    ("free_breakfast", MD_FREE_BREAKFAST),
+    ("fullscreen", MD_FULLSCREEN),
+    ("fullscreen_exit", MD_FULLSCREEN_EXIT),
+    ("functions", MD_FUNCTIONS),
+    ("g_translate", MD_G_TRANSLATE),
+    ("gamepad", MD_GAMEPAD),
+    ("games", MD_GAMES),
+    ("gavel", MD_GAVEL),
+    ("gesture", MD_GESTURE),
+    ("get_app", MD_GET_APP),
+    ("gif", MD_GIF),
+    ("golf_course", MD_GOLF_COURSE),
+    ("gps_fixed", MD_GPS_FIXED),
+    ("gps_not_fixed", MD_GPS_NOT_FIXED),
+    ("gps_off", MD_GPS_OFF),
+    ("grade", MD_GRADE),
+    ("gradient", MD_GRADIENT),
+    ("grading", MD_GRADING),
+    ("grain", MD_GRAIN),
+    ("graphic_eq", MD_GRAPHIC_EQ),
+    ("grass", MD_GRASS),
+    ("greater_than", MD_GREATER_THAN),
+    ("greater_than_equal", MD_GREATER_THAN_EQUAL),
+    ("grid_off", MD_GRID_OFF),
+    ("grid_on", MD_GRID_ON),
+    ("group", MD_GROUP),
+    ("group_add", MD_GROUP_ADD),
+    ("group_work", MD_GROUP_WORK),
+    ("handyman", MD_HANDYMAN),
+    ("hd", MD_HD),
+    ("hdr_off", MD_HDR_OFF),
+    ("hdr_on", MD_HDR_ON),
+    ("hdr_strong", MD_HDR_STRONG),
+    ("hdr_weak", MD_HDR_WEAK),
+    ("headset", MD_HEADSET),
+    ("headset_mic", MD_HEADSET_MIC),
+    ("healing", MD_HEALING),
+    ("hearing", MD_HEARING),
+    ("hearing_disabled", MD_HEARING_DISABLED),
+    ("height", MD_HEIGHT),
+    ("help", MD_HELP),
+    ("help_center", MD_HELP_CENTER),
+    ("help_outline", MD_HELP_OUTLINE),
+    ("high_quality", MD_HIGH_QUALITY),
+    ("highlight", MD_HIGHLIGHT),
+    ("highlight_alt", MD_HIGHLIGHT_ALT),
+    ("highlight_off", MD_HIGHLIGHT_OFF),
+    ("history", MD_HISTORY),
+    ("history_edu", MD_HISTORY_EDU),
+    ("history_toggle_off", MD_HISTORY_TOGGLE_OFF),
+    ("home", MD_HOME),
+    ("home_repair_service", MD_HOME_REPAIR_SERVICE),
+    ("home_work", MD_HOME_WORK),
+    ("horizontal_rule", MD_HORIZONTAL_RULE),
+    ("horizontal_split", MD_HORIZONTAL_SPLIT),
+    ("hot_tub", MD_HOT_TUB),
+    ("hotel", MD_HOTEL),
+    ("hourglass_bottom", MD_HOURGLASS_BOTTOM),
+    ("hourglass_disabled", MD_HOURGLASS_DISABLED),
+    ("hourglass_empty", MD_HOURGLASS_EMPTY),
+    ("hourglass_full", MD_HOURGLASS_FULL),
+    ("hourglass_top", MD_HOURGLASS_TOP),
+    ("house", MD_HOUSE),
+    ("house_siding", MD_HOUSE_SIDING),
+    ("how_to_reg", MD_HOW_TO_REG),
+    ("how_to_vote", MD_HOW_TO_VOTE),
+    ("http", MD_HTTP),
+    ("https", MD_HTTPS),
+    ("hvac", MD_HVAC),
+    ("image", MD_IMAGE),
+    ("image_aspect_ratio", MD_IMAGE_ASPECT_RATIO),
+    ("image_not_supported", MD_IMAGE_NOT_SUPPORTED),
+    ("image_search", MD_IMAGE_SEARCH),
+    ("import_contacts", MD_IMPORT_CONTACTS),
+    ("import_export", MD_IMPORT_EXPORT),
+    ("important_devices", MD_IMPORTANT_DEVICES),
+    ("inbox", MD_INBOX),
+    ("indeterminate_check_box", MD_INDETERMINATE_CHECK_BOX),
+    ("info", MD_INFO),
+    ("input", MD_INPUT),
+    ("insert_chart", MD_INSERT_CHART),
+    ("insert_chart_outlined", MD_INSERT_CHART_OUTLINED),
+    ("insert_comment", MD_INSERT_COMMENT),
+    ("insert_drive_file", MD_INSERT_DRIVE_FILE),
+    ("insert_emoticon", MD_INSERT_EMOTICON),
+    ("insert_invitation", MD_INSERT_INVITATION),
+    ("insert_link", MD_INSERT_LINK),
+    ("insert_photo", MD_INSERT_PHOTO),
+    ("insights", MD_INSIGHTS),
+    ("integration_instructions", MD_INTEGRATION_INSTRUCTIONS),
+    ("invert_colors", MD_INVERT_COLORS),
+    ("invert_colors_off", MD_INVERT_COLORS_OFF),
+    ("iso", MD_ISO),
+    ("keyboard", MD_KEYBOARD),
+    ("keyboard_arrow_down", MD_KEYBOARD_ARROW_DOWN),
+    ("keyboard_arrow_left", MD_KEYBOARD_ARROW_LEFT),
+    ("keyboard_arrow_right", MD_KEYBOARD_ARROW_RIGHT),
+    ("keyboard_arrow_up", MD_KEYBOARD_ARROW_UP),
+    ("keyboard_backspace", MD_KEYBOARD_BACKSPACE),
+    ("keyboard_capslock", MD_KEYBOARD_CAPSLOCK),
+    ("keyboard_hide", MD_KEYBOARD_HIDE),
+    ("keyboard_return", MD_KEYBOARD_RETURN),
+    ("keyboard_tab", MD_KEYBOARD_TAB),
+    ("keyboard_voice", MD_KEYBOARD_VOICE),
+    ("king_bed", MD_KING_BED),
+    ("kitchen", MD_KITCHEN),
+    ("label", MD_LABEL),
+    ("label_important", MD_LABEL_IMPORTANT),
+    ("label_off", MD_LABEL_OFF),
+    ("landscape", MD_LANDSCAPE),
+    ("language", MD_LANGUAGE),
+    ("laptop", MD_LAPTOP),
+    ("laptop_chromebook", MD_LAPTOP_CHROMEBOOK),
+    ("laptop_mac", MD_LAPTOP_MAC),
+    ("laptop_windows", MD_LAPTOP_WINDOWS),
+    ("last_page", MD_LAST_PAGE),
+    ("launch", MD_LAUNCH),
+    ("layers", MD_LAYERS),
+    ("layers_clear", MD_LAYERS_CLEAR),
+    ("leaderboard", MD_LEADERBOARD),
+    ("leak_add", MD_LEAK_ADD),
+    ("leak_remove", MD_LEAK_REMOVE),
+    ("legend_toggle", MD_LEGEND_TOGGLE),
+    ("lens", MD_LENS),
+    ("less_than", MD_LESS_THAN),
+    ("less_than_equal", MD_LESS_THAN_EQUAL),
+    ("library_add", MD_LIBRARY_ADD),
+    ("library_add_check", MD_LIBRARY_ADD_CHECK),
+    ("library_books", MD_LIBRARY_BOOKS),
+    ("library_music", MD_LIBRARY_MUSIC),
+    ("lightbulb", MD_LIGHTBULB),
+    ("line_style", MD_LINE_STYLE),
+    ("line_weight", MD_LINE_WEIGHT),
+    ("linear_scale", MD_LINEAR_SCALE),
+    ("link", MD_LINK),
+    ("link_off", MD_LINK_OFF),
+    ("linked_camera", MD_LINKED_CAMERA),
+    ("list", MD_LIST),
+    ("list_alt", MD_LIST_ALT),
+    ("live_help", MD_LIVE_HELP),
+    ("live_tv", MD_LIVE_TV),
+    ("local_activity", MD_LOCAL_ACTIVITY),
+    ("local_airport", MD_LOCAL_AIRPORT),
+    ("local_atm", MD_LOCAL_ATM),
+    ("local_bar", MD_LOCAL_BAR),
+    ("local_cafe", MD_LOCAL_CAFE),
+    ("local_car_wash", MD_LOCAL_CAR_WASH),
+    ("local_convenience_store", MD_LOCAL_CONVENIENCE_STORE),
+    ("local_dining", MD_LOCAL_DINING),
+    ("local_drink", MD_LOCAL_DRINK),
+    ("local_florist", MD_LOCAL_FLORIST),
+    ("local_gas_station", MD_LOCAL_GAS_STATION),
+    ("local_grocery_store", MD_LOCAL_GROCERY_STORE),
+    ("local_hospital", MD_LOCAL_HOSPITAL),
+    ("local_hotel", MD_LOCAL_HOTEL),
+    ("local_laundry_service", MD_LOCAL_LAUNDRY_SERVICE),
+    ("local_library", MD_LOCAL_LIBRARY),
+    ("local_mall", MD_LOCAL_MALL),
+    ("local_movies", MD_LOCAL_MOVIES),
+    ("local_offer", MD_LOCAL_OFFER),
+    ("local_parking", MD_LOCAL_PARKING),
+    ("local_pharmacy", MD_LOCAL_PHARMACY),
+    ("local_phone", MD_LOCAL_PHONE),
+    ("local_pizza", MD_LOCAL_PIZZA),
+    ("local_play", MD_LOCAL_PLAY),
+    ("local_post_office", MD_LOCAL_POST_OFFICE),
+    ("local_printshop", MD_LOCAL_PRINTSHOP),
+    ("local_see", MD_LOCAL_SEE),
+    ("local_shipping", MD_LOCAL_SHIPPING),
+    ("local_taxi", MD_LOCAL_TAXI),
+    ("location_city", MD_LOCATION_CITY),
+    ("location_disabled", MD_LOCATION_DISABLED),
+    ("location_off", MD_LOCATION_OFF),
+    ("location_on", MD_LOCATION_ON),
+    ("location_searching", MD_LOCATION_SEARCHING),
+    ("lock", MD_LOCK),
+    ("lock_open", MD_LOCK_OPEN),
+    ("log_in", MD_LOG_IN),
+    ("log_out", MD_LOG_OUT),
+    ("login", MD_LOGIN),
+    ("looks", MD_LOOKS),
+    ("looks_3", MD_LOOKS_3),
+    ("looks_4", MD_LOOKS_4),
+    ("looks_5", MD_LOOKS_5),
+    ("looks_6", MD_LOOKS_6),
+    ("looks_one", MD_LOOKS_ONE),
+    ("looks_two", MD_LOOKS_TWO),
+    ("loop", MD_LOOP),
+    ("loupe", MD_LOUPE),
+    ("low_priority", MD_LOW_PRIORITY),
+    ("loyalty", MD_LOYALTY),
+    ("mail", MD_MAIL),
+    ("mail_outline", MD_MAIL_OUTLINE),
+    ("map", MD_MAP),
+    ("maps_ugc", MD_MAPS_UGC),
+    ("mark_chat_read", MD_MARK_CHAT_READ),
+    ("mark_chat_unread", MD_MARK_CHAT_UNREAD),
+    ("mark_email_read", MD_MARK_EMAIL_READ),
+    ("mark_email_unread", MD_MARK_EMAIL_UNREAD),
+    ("markunread", MD_MARKUNREAD),
+    ("markunread_mailbox", MD_MARKUNREAD_MAILBOX),
+    ("maximize", MD_MAXIMIZE),
+    ("mediation", MD_MEDIATION),
+    ("medical_services", MD_MEDICAL_SERVICES),
+    ("meeting_room", MD_MEETING_ROOM),
+    ("memory", MD_MEMORY),
+    ("menu", MD_MENU),
+    ("menu_book", MD_MENU_BOOK),
+    ("menu_open", MD_MENU_OPEN),
+    ("merge_type", MD_MERGE_TYPE),
+    ("message", MD_MESSAGE),
+    ("mic", MD_MIC),
+    ("mic_none", MD_MIC_NONE),
+    ("mic_off", MD_MIC_OFF),
+    ("microwave", MD_MICROWAVE),
+    ("military_tech", MD_MILITARY_TECH),
+    ("minimize", MD_MINIMIZE),
+    ("minus", MD_MINUS),
+    ("miscellaneous_services", MD_MISCELLANEOUS_SERVICES),
+    ("missed_video_call", MD_MISSED_VIDEO_CALL),
+    ("mms", MD_MMS),
+    ("mobile_friendly", MD_MOBILE_FRIENDLY),
+    ("mobile_off", MD_MOBILE_OFF),
+    ("mobile_screen_share", MD_MOBILE_SCREEN_SHARE),
+    ("mode_comment", MD_MODE_COMMENT),
+    ("model_training", MD_MODEL_TRAINING),
+    ("monetization_on", MD_MONETIZATION_ON),
+    ("money", MD_MONEY),
+    ("money_off", MD_MONEY_OFF),
+    ("monochrome_photos", MD_MONOCHROME_PHOTOS),
+    ("mood", MD_MOOD),
+    ("mood_bad", MD_MOOD_BAD),
+    ("moped", MD_MOPED),
+    ("more", MD_MORE),
+    ("more_horiz", MD_MORE_HORIZ),
+    ("more_time", MD_MORE_TIME),
+    ("more_vert", MD_MORE_VERT),
+    ("motorcycle", MD_MOTORCYCLE),
+    ("mouse", MD_MOUSE),
+    ("move_to_inbox", MD_MOVE_TO_INBOX),
+    ("movie", MD_MOVIE),
+    ("movie_creation", MD_MOVIE_CREATION),
+    ("movie_filter", MD_MOVIE_FILTER),
+    ("multiline_chart", MD_MULTILINE_CHART),
+    ("multiple_stop", MD_MULTIPLE_STOP),
+    ("museum", MD_MUSEUM),
+    ("music_note", MD_MUSIC_NOTE),
+    ("music_off", MD_MUSIC_OFF),
+    ("music_video", MD_MUSIC_VIDEO),
+    ("my_location", MD_MY_LOCATION),
+    ("nat", MD_NAT),
+    ("nature", MD_NATURE),
+    ("nature_people", MD_NATURE_PEOPLE),
+    ("navigate_before", MD_NAVIGATE_BEFORE),
+    ("navigate_next", MD_NAVIGATE_NEXT),
+    ("navigation", MD_NAVIGATION),
+    ("near_me", MD_NEAR_ME),
+    ("near_me_disabled", MD_NEAR_ME_DISABLED),
+    ("network_cell_after", MD_NETWORK_CELL_AFTER),
+    ("network_cell_before", MD_NETWORK_CELL_BEFORE),
+    ("network_check", MD_NETWORK_CHECK),
+    ("network_locked", MD_NETWORK_LOCKED),
+    ("network_wifi_after", MD_NETWORK_WIFI_AFTER),
+    ("network_wifi_before", MD_NETWORK_WIFI_BEFORE),
+    ("new_releases", MD_NEW_RELEASES),
+    ("next_plan", MD_NEXT_PLAN),
+    ("next_week", MD_NEXT_WEEK),
+    ("nfc", MD_NFC),
+    ("night_shelter", MD_NIGHT_SHELTER),
+    ("nights_stay", MD_NIGHTS_STAY),
+    ("no_cell", MD_NO_CELL),
+    ("no_drinks", MD_NO_DRINKS),
+    ("no_encryption", MD_NO_ENCRYPTION),
+    ("no_flash", MD_NO_FLASH),
+    ("no_food", MD_NO_FOOD),
+    ("no_meals", MD_NO_MEALS),
+    ("no_meeting_room", MD_NO_MEETING_ROOM),
+    ("no_photography", MD_NO_PHOTOGRAPHY),
+    ("no_sim", MD_NO_SIM),
+    ("no_stroller", MD_NO_STROLLER),
+    ("no_transfer", MD_NO_TRANSFER),
+    ("north", MD_NORTH),
+    ("north_east", MD_NORTH_EAST),
+    ("north_west", MD_NORTH_WEST),
+    ("not_accessible", MD_NOT_ACCESSIBLE),
+    ("not_equal", MD_NOT_EQUAL),
+    ("not_interested", MD_NOT_INTERESTED),
+    ("not_listed_location", MD_NOT_LISTED_LOCATION),
+    ("not_started", MD_NOT_STARTED),
+    ("note", MD_NOTE),
+    ("note_add", MD_NOTE_ADD),
+    ("notes", MD_NOTES),
+    ("notification_important", MD_NOTIFICATION_IMPORTANT),
+    ("notifications", MD_NOTIFICATIONS),
+    ("notifications_active", MD_NOTIFICATIONS_ACTIVE),
+    ("notifications_none", MD_NOTIFICATIONS_NONE),
+    ("notifications_off", MD_NOTIFICATIONS_OFF),
+    ("notifications_paused", MD_NOTIFICATIONS_PAUSED),
+    ("offline_bolt", MD_OFFLINE_BOLT),
+    ("offline_pin", MD_OFFLINE_PIN),
+    ("ondemand_video", MD_ONDEMAND_VIDEO),
+    ("online_prediction", MD_ONLINE_PREDICTION),
+    ("opacity", MD_OPACITY),
+    ("open_in_browser", MD_OPEN_IN_BROWSER),
+    ("open_in_full", MD_OPEN_IN_FULL),
+    ("open_in_new", MD_OPEN_IN_NEW),
+    ("open_with", MD_OPEN_WITH),
+    ("outdoor_grill", MD_OUTDOOR_GRILL),
+    ("outlet", MD_OUTLET),
+    ("outlined_flag", MD_OUTLINED_FLAG),
+    ("pages", MD_PAGES),
+    ("pageview", MD_PAGEVIEW),
+    ("palette", MD_PALETTE),
+    ("pan_tool", MD_PAN_TOOL),
+    ("panorama", MD_PANORAMA),
+    ("panorama_fish_eye", MD_PANORAMA_FISH_EYE),
+    ("panorama_horizontal", MD_PANORAMA_HORIZONTAL),
+    ("panorama_vertical", MD_PANORAMA_VERTICAL),
+    ("panorama_wide_angle", MD_PANORAMA_WIDE_ANGLE),
+    ("party_mode", MD_PARTY_MODE),
+    ("pause", MD_PAUSE),
+    ("pause_circle_filled", MD_PAUSE_CIRCLE_FILLED),
+    ("pause_circle_outline", MD_PAUSE_CIRCLE_OUTLINE),
+    ("pause_presentation", MD_PAUSE_PRESENTATION),
+    ("payment", MD_PAYMENT),
+    ("payments", MD_PAYMENTS),
+    ("pedal_bike", MD_PEDAL_BIKE),
+    ("pending", MD_PENDING),
+    ("pending_actions", MD_PENDING_ACTIONS),
+    ("people", MD_PEOPLE),
+    ("people_alt", MD_PEOPLE_ALT),
+    ("people_outline", MD_PEOPLE_OUTLINE),
+    ("percentage", MD_PERCENTAGE),
+    ("perm_camera_mic", MD_PERM_CAMERA_MIC),
+    ("perm_contact_calendar", MD_PERM_CONTACT_CALENDAR),
+    ("perm_data_setting", MD_PERM_DATA_SETTING),
+    ("perm_device_information", MD_PERM_DEVICE_INFORMATION),
+    ("perm_identity", MD_PERM_IDENTITY),
+    ("perm_media", MD_PERM_MEDIA),
+    ("perm_phone_msg", MD_PERM_PHONE_MSG),
+    ("perm_scan_wifi", MD_PERM_SCAN_WIFI),
+    ("person", MD_PERSON),
+    ("person_add", MD_PERSON_ADD),
+    ("person_add_alt_1", MD_PERSON_ADD_ALT_1),
+    ("person_add_disabled", MD_PERSON_ADD_DISABLED),
+    ("person_outline", MD_PERSON_OUTLINE),
+    ("person_pin", MD_PERSON_PIN),
+    ("person_pin_circle", MD_PERSON_PIN_CIRCLE),
+    ("person_remove", MD_PERSON_REMOVE),
+    ("person_remove_alt_1", MD_PERSON_REMOVE_ALT_1),
+    ("person_search", MD_PERSON_SEARCH),
+    ("personal_video", MD_PERSONAL_VIDEO),
+    ("pest_control", MD_PEST_CONTROL),
+    ("pest_control_rodent", MD_PEST_CONTROL_RODENT),
+    ("pets", MD_PETS),
+    ("phone", MD_PHONE),
+    ("phone_android", MD_PHONE_ANDROID),
+    ("phone_bluetooth_speaker", MD_PHONE_BLUETOOTH_SPEAKER),
+    ("phone_callback", MD_PHONE_CALLBACK),
+    ("phone_disabled", MD_PHONE_DISABLED),
+    ("phone_enabled", MD_PHONE_ENABLED),
+    ("phone_forwarded", MD_PHONE_FORWARDED),
+    ("phone_in_talk", MD_PHONE_IN_TALK),
+    ("phone_iphone", MD_PHONE_IPHONE),
+    ("phone_locked", MD_PHONE_LOCKED),
+    ("phone_missed", MD_PHONE_MISSED),
+    ("phone_paused", MD_PHONE_PAUSED),
+    ("phonelink", MD_PHONELINK),
+    ("phonelink_erase", MD_PHONELINK_ERASE),
+    ("phonelink_lock", MD_PHONELINK_LOCK),
+    ("phonelink_off", MD_PHONELINK_OFF),
+    ("phonelink_ring", MD_PHONELINK_RING),
+    ("phonelink_setup", MD_PHONELINK_SETUP),
+    ("photo", MD_PHOTO),
+    ("photo_album", MD_PHOTO_ALBUM),
+    ("photo_camera", MD_PHOTO_CAMERA),
+    ("photo_filter", MD_PHOTO_FILTER),
+    ("photo_library", MD_PHOTO_LIBRARY),
+    ("photo_size_select_actual", MD_PHOTO_SIZE_SELECT_ACTUAL),
+    ("photo_size_select_large", MD_PHOTO_SIZE_SELECT_LARGE),
+    ("photo_size_select_small", MD_PHOTO_SIZE_SELECT_SMALL),
+    ("picture_as_pdf", MD_PICTURE_AS_PDF),
+    ("picture_in_picture", MD_PICTURE_IN_PICTURE),
+    ("picture_in_picture_alt", MD_PICTURE_IN_PICTURE_ALT),
+    ("pie_chart", MD_PIE_CHART),
+    ("pin", MD_PIN),
+    ("pin_drop", MD_PIN_DROP),
+    ("pin_off", MD_PIN_OFF),
+    ("place", MD_PLACE),
+    ("plagiarism", MD_PLAGIARISM),
+    ("play_arrow", MD_PLAY_ARROW),
+    ("play_circle_filled", MD_PLAY_CIRCLE_FILLED),
+    ("play_circle_filled_white", MD_PLAY_CIRCLE_FILLED_WHITE),
+    ("play_circle_outline", MD_PLAY_CIRCLE_OUTLINE),
+    ("play_for_work", MD_PLAY_FOR_WORK),
+    ("playlist_add", MD_PLAYLIST_ADD),
+    ("playlist_add_check", MD_PLAYLIST_ADD_CHECK),
+    ("playlist_play", MD_PLAYLIST_PLAY),
+    ("plumbing", MD_PLUMBING),
+    ("plus", MD_PLUS),
+    ("plus_minus", MD_PLUS_MINUS),
+    ("plus_minus_alt", MD_PLUS_MINUS_ALT),
+    ("plus_one", MD_PLUS_ONE),
+    ("point_of_sale", MD_POINT_OF_SALE),
+    ("policy", MD_POLICY),
+    ("poll", MD_POLL),
+    ("polymer", MD_POLYMER),
+    ("pool", MD_POOL),
+    ("portable_wifi_off", MD_PORTABLE_WIFI_OFF),
+    ("portrait", MD_PORTRAIT),
+    ("post_add", MD_POST_ADD),
+    ("power", MD_POWER),
+    ("power_input", MD_POWER_INPUT),
+    ("power_off", MD_POWER_OFF),
+    ("power_settings_new", MD_POWER_SETTINGS_NEW),
+    ("pregnant_woman", MD_PREGNANT_WOMAN),
+    ("present_to_all", MD_PRESENT_TO_ALL),
+    ("preview", MD_PREVIEW),
+    ("print", MD_PRINT),
+    ("print_disabled", MD_PRINT_DISABLED),
+    ("priority_high", MD_PRIORITY_HIGH),
+    ("privacy_tip", MD_PRIVACY_TIP),
+    ("psychology", MD_PSYCHOLOGY),
+    ("public", MD_PUBLIC),
+    ("public_off", MD_PUBLIC_OFF),
+    ("publish", MD_PUBLISH),
+    ("push_pin", MD_PUSH_PIN),
+    ("qr_code", MD_QR_CODE),
+    ("qr_code_scanner", MD_QR_CODE_SCANNER),
+    ("qrcode", MD_QRCODE),
+    ("query_builder", MD_QUERY_BUILDER),
+    ("question_answer", MD_QUESTION_ANSWER),
+    ("queue", MD_QUEUE),
+    ("queue_music", MD_QUEUE_MUSIC),
+    ("queue_play_next", MD_QUEUE_PLAY_NEXT),
+    ("quickreply", MD_QUICKREPLY),
+    ("radio", MD_RADIO),
+    ("radio_button_checked", MD_RADIO_BUTTON_CHECKED),
+    ("radio_button_unchecked", MD_RADIO_BUTTON_UNCHECKED),
+    ("rate_review", MD_RATE_REVIEW),
+    ("read_more", MD_READ_MORE),
+    ("receipt", MD_RECEIPT),
+    ("receipt_long", MD_RECEIPT_LONG),
+    ("recent_actors", MD_RECENT_ACTORS),
+    ("record_voice_over", MD_RECORD_VOICE_OVER),
+    ("redeem", MD_REDEEM),
+    ("redo", MD_REDO),
+    ("refresh", MD_REFRESH),
+    ("remove", MD_REMOVE),
+    ("remove_circle", MD_REMOVE_CIRCLE),
+    ("remove_circle_outline", MD_REMOVE_CIRCLE_OUTLINE),
+    ("remove_from_queue", MD_REMOVE_FROM_QUEUE),
+    ("remove_red_eye", MD_REMOVE_RED_EYE),
+    ("remove_shopping_cart", MD_REMOVE_SHOPPING_CART),
+    ("reorder", MD_REORDER),
+    ("repeat", MD_REPEAT),
+    ("repeat_one", MD_REPEAT_ONE),
+    ("replay", MD_REPLAY),
+    ("replay_10", MD_REPLAY_10),
+    ("replay_30", MD_REPLAY_30),
+    ("replay_5", MD_REPLAY_5),
+    ("reply", MD_REPLY),
+    ("reply_all", MD_REPLY_ALL),
+    ("report", MD_REPORT),
+    ("report_off", MD_REPORT_OFF),
+    ("report_problem", MD_REPORT_PROBLEM),
+    ("request_quote", MD_REQUEST_QUOTE),
+    ("restaurant", MD_RESTAURANT),
+    ("restaurant_menu", MD_RESTAURANT_MENU),
+    ("restore", MD_RESTORE),
+    ("restore_from_trash", MD_RESTORE_FROM_TRASH),
+    ("restore_page", MD_RESTORE_PAGE),
+    ("rice_bowl", MD_RICE_BOWL),
+    ("ring_volume", MD_RING_VOLUME),
+    ("rocket", MD_ROCKET),
+    ("roofing", MD_ROOFING),
+    ("room", MD_ROOM),
+    ("room_preferences", MD_ROOM_PREFERENCES),
+    ("room_service", MD_ROOM_SERVICE),
+    ("rotate_90_degrees_ccw", MD_ROTATE_90_DEGREES_CCW),
+    ("rotate_left", MD_ROTATE_LEFT),
+    ("rotate_right", MD_ROTATE_RIGHT),
+    ("rounded_corner", MD_ROUNDED_CORNER),
+    ("router", MD_ROUTER),
+    ("rowing", MD_ROWING),
+    ("rss_feed", MD_RSS_FEED),
+    ("rule", MD_RULE),
+    ("rule_folder", MD_RULE_FOLDER),
+    ("run_circle", MD_RUN_CIRCLE),
+    ("rv_hookup", MD_RV_HOOKUP),
+    ("satellite", MD_SATELLITE),
+    ("save", MD_SAVE),
+    ("save_alt", MD_SAVE_ALT),
+    ("scanner", MD_SCANNER),
+    ("scatter_plot", MD_SCATTER_PLOT),
+    ("schedule", MD_SCHEDULE),
+    ("school", MD_SCHOOL),
+    ("science", MD_SCIENCE),
+    ("score", MD_SCORE),
+    ("screen_lock_landscape", MD_SCREEN_LOCK_LANDSCAPE),
+    ("screen_lock_portrait", MD_SCREEN_LOCK_PORTRAIT),
+    ("screen_lock_rotation", MD_SCREEN_LOCK_ROTATION),
+    ("screen_rotation", MD_SCREEN_ROTATION),
+    ("screen_share", MD_SCREEN_SHARE),
+    ("sd_card", MD_SD_CARD),
+    ("sd_storage", MD_SD_STORAGE),
+    ("search", MD_SEARCH),
+    ("search_off", MD_SEARCH_OFF),
+    ("security", MD_SECURITY),
+    ("select_all", MD_SELECT_ALL),
+    ("self_improvement", MD_SELF_IMPROVEMENT),
+    ("send", MD_SEND),
+    ("sensor_door", MD_SENSOR_DOOR),
+    ("sensor_window", MD_SENSOR_WINDOW),
+    ("sentiment_dissatisfied", MD_SENTIMENT_DISSATISFIED),
+    ("sentiment_neutral", MD_SENTIMENT_NEUTRAL),
+    ("sentiment_satisfied", MD_SENTIMENT_SATISFIED),
+    ("sentiment_satisfied_alt", MD_SENTIMENT_SATISFIED_ALT),
+    (
+        "sentiment_slightly_dissatisfied",
+        MD_SENTIMENT_SLIGHTLY_DISSATISFIED,
+    ),
+    (
+        "sentiment_very_dissatisfied",
+        MD_SENTIMENT_VERY_DISSATISFIED,
+    ),
+    ("sentiment_very_satisfied", MD_SENTIMENT_VERY_SATISFIED),
+    ("set_meal", MD_SET_MEAL),
+    ("settings", MD_SETTINGS),
+    ("settings_applications", MD_SETTINGS_APPLICATIONS),
+    ("settings_backup_restore", MD_SETTINGS_BACKUP_RESTORE),
+    ("settings_bluetooth", MD_SETTINGS_BLUETOOTH),
+    ("settings_brightness", MD_SETTINGS_BRIGHTNESS),
+    ("settings_cell", MD_SETTINGS_CELL),
+    ("settings_ethernet", MD_SETTINGS_ETHERNET),
+    ("settings_input_antenna", MD_SETTINGS_INPUT_ANTENNA),
+    ("settings_input_component", MD_SETTINGS_INPUT_COMPONENT),
+    ("settings_input_composite", MD_SETTINGS_INPUT_COMPOSITE),
+    ("settings_input_hdmi", MD_SETTINGS_INPUT_HDMI),
+    ("settings_input_svideo", MD_SETTINGS_INPUT_SVIDEO),
+    ("settings_overscan", MD_SETTINGS_OVERSCAN),
+    ("settings_phone", MD_SETTINGS_PHONE),
+    ("settings_power", MD_SETTINGS_POWER),
+    ("settings_remote", MD_SETTINGS_REMOTE),
+    ("settings_system_daydream", MD_SETTINGS_SYSTEM_DAYDREAM),
+    ("settings_voice", MD_SETTINGS_VOICE),
+    ("share", MD_SHARE),
+    ("shop", MD_SHOP),
+    ("shop_two", MD_SHOP_TWO),
+    ("shopping_bag", MD_SHOPPING_BAG),
+    ("shopping_basket", MD_SHOPPING_BASKET),
+    ("shopping_cart", MD_SHOPPING_CART),
+    ("short_text", MD_SHORT_TEXT),
+    ("show_chart", MD_SHOW_CHART),
+    ("shuffle", MD_SHUFFLE),
+    ("shutter_speed", MD_SHUTTER_SPEED),
+    (
+        "signal_cellular_0_bar_after",
+        MD_SIGNAL_CELLULAR_0_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_0_bar_before",
+        MD_SIGNAL_CELLULAR_0_BAR_BEFORE,
+    ),
+    (
+        "signal_cellular_1_bar_after",
+        MD_SIGNAL_CELLULAR_1_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_1_bar_before",
+        MD_SIGNAL_CELLULAR_1_BAR_BEFORE,
+    ),
+    (
+        "signal_cellular_2_bar_after",
+        MD_SIGNAL_CELLULAR_2_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_2_bar_before",
+        MD_SIGNAL_CELLULAR_2_BAR_BEFORE,
+    ),
+    (
+        "signal_cellular_3_bar_after",
+        MD_SIGNAL_CELLULAR_3_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_3_bar_before",
+        MD_SIGNAL_CELLULAR_3_BAR_BEFORE,
+    ),
+    ("signal_cellular_4_bar", MD_SIGNAL_CELLULAR_4_BAR),
+    ("signal_cellular_alt", MD_SIGNAL_CELLULAR_ALT),
+    (
+        "signal_cellular_connected_no_internet_0_bar_after",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_0_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_connected_no_internet_0_bar_before",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_0_BAR_BEFORE,
+    ),
+    (
+        "signal_cellular_connected_no_internet_1_bar_after",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_1_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_connected_no_internet_1_bar_before",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_1_BAR_BEFORE,
+    ),
+    (
+        "signal_cellular_connected_no_internet_2_bar_after",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_2_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_connected_no_internet_2_bar_before",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_2_BAR_BEFORE,
+    ),
+    (
+        "signal_cellular_connected_no_internet_3_bar_after",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_3_BAR_AFTER,
+    ),
+    (
+        "signal_cellular_connected_no_internet_3_bar_before",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_3_BAR_BEFORE,
+    ),
+    (
+        "signal_cellular_connected_no_internet_4_bar",
+        MD_SIGNAL_CELLULAR_CONNECTED_NO_INTERNET_4_BAR,
+    ),
+    ("signal_cellular_no_sim", MD_SIGNAL_CELLULAR_NO_SIM),
+    ("signal_cellular_null", MD_SIGNAL_CELLULAR_NULL),
+    ("signal_cellular_off", MD_SIGNAL_CELLULAR_OFF),
+    ("signal_wifi_0_bar_after", MD_SIGNAL_WIFI_0_BAR_AFTER),
+    ("signal_wifi_0_bar_before", MD_SIGNAL_WIFI_0_BAR_BEFORE),
+    ("signal_wifi_1_bar_after", MD_SIGNAL_WIFI_1_BAR_AFTER),
+    ("signal_wifi_1_bar_before", MD_SIGNAL_WIFI_1_BAR_BEFORE),
+    (
+        "signal_wifi_1_bar_lock_after",
+        MD_SIGNAL_WIFI_1_BAR_LOCK_AFTER,
+    ),
+    (
+        "signal_wifi_1_bar_lock_before",
+        MD_SIGNAL_WIFI_1_BAR_LOCK_BEFORE,
+    ),
+    ("signal_wifi_2_bar_after", MD_SIGNAL_WIFI_2_BAR_AFTER),
+    ("signal_wifi_2_bar_before", MD_SIGNAL_WIFI_2_BAR_BEFORE),
+    (
+        "signal_wifi_2_bar_lock_after",
+        MD_SIGNAL_WIFI_2_BAR_LOCK_AFTER,
+    ),
+    (
+        "signal_wifi_2_bar_lock_before",
+        MD_SIGNAL_WIFI_2_BAR_LOCK_BEFORE,
+    ),
+    ("signal_wifi_3_bar_after", MD_SIGNAL_WIFI_3_BAR_AFTER),
+    ("signal_wifi_3_bar_before", MD_SIGNAL_WIFI_3_BAR_BEFORE),
+    (
+        "signal_wifi_3_bar_lock_after",
+        MD_SIGNAL_WIFI_3_BAR_LOCK_AFTER,
+    ),
+    (
+        "signal_wifi_3_bar_lock_before",
+        MD_SIGNAL_WIFI_3_BAR_LOCK_BEFORE,
+    ),
+    ("signal_wifi_4_bar", MD_SIGNAL_WIFI_4_BAR),
+    ("signal_wifi_4_bar_lock", MD_SIGNAL_WIFI_4_BAR_LOCK),
+    ("signal_wifi_off", MD_SIGNAL_WIFI_OFF),
+    ("sim_card", MD_SIM_CARD),
+    ("sim_card_alert", MD_SIM_CARD_ALERT),
+    ("single_bed", MD_SINGLE_BED),
+    ("skip_next", MD_SKIP_NEXT),
+    ("skip_previous", MD_SKIP_PREVIOUS),
+    ("slideshow", MD_SLIDESHOW),
+    ("slow_motion_video", MD_SLOW_MOTION_VIDEO),
+    ("smart_button", MD_SMART_BUTTON),
+    ("smartphone", MD_SMARTPHONE),
+    ("smoke_free", MD_SMOKE_FREE),
+    ("smoking_rooms", MD_SMOKING_ROOMS),
+    ("sms", MD_SMS),
+    ("sms_failed", MD_SMS_FAILED),
+    ("snippet_folder", MD_SNIPPET_FOLDER),
+    ("snooze", MD_SNOOZE),
+    ("soap", MD_SOAP),
+    ("sort", MD_SORT),
+    ("sort_by_alpha", MD_SORT_BY_ALPHA),
+    ("source", MD_SOURCE),
+    ("south", MD_SOUTH),
+    ("south_east", MD_SOUTH_EAST),
+    ("south_west", MD_SOUTH_WEST),
+    ("spa", MD_SPA),
+    ("space_bar", MD_SPACE_BAR),
+    ("speaker", MD_SPEAKER),
+    ("speaker_group", MD_SPEAKER_GROUP),
+    ("speaker_notes", MD_SPEAKER_NOTES),
+    ("speaker_notes_off", MD_SPEAKER_NOTES_OFF),
+    ("speaker_phone", MD_SPEAKER_PHONE),
+    ("speed", MD_SPEED),
+    ("spellcheck", MD_SPELLCHECK),
+    ("sports", MD_SPORTS),
+    ("sports_bar", MD_SPORTS_BAR),
+    ("sports_baseball", MD_SPORTS_BASEBALL),
+    ("sports_basketball", MD_SPORTS_BASKETBALL),
+    ("sports_cricket", MD_SPORTS_CRICKET),
+    ("sports_esports", MD_SPORTS_ESPORTS),
+    ("sports_football", MD_SPORTS_FOOTBALL),
+    ("sports_golf", MD_SPORTS_GOLF),
+    ("sports_handball", MD_SPORTS_HANDBALL),
+    ("sports_hockey", MD_SPORTS_HOCKEY),
+    ("sports_kabaddi", MD_SPORTS_KABADDI),
+    ("sports_mma", MD_SPORTS_MMA),
+    ("sports_motorsports", MD_SPORTS_MOTORSPORTS),
+    ("sports_rugby", MD_SPORTS_RUGBY),
+    ("sports_soccer", MD_SPORTS_SOCCER),
+    ("sports_tennis", MD_SPORTS_TENNIS),
+    ("sports_volleyball", MD_SPORTS_VOLLEYBALL),
+    ("square_foot", MD_SQUARE_FOOT),
+    ("stairs", MD_STAIRS),
+    ("star", MD_STAR),
+    ("star_border", MD_STAR_BORDER),
+    ("star_half", MD_STAR_HALF),
+    ("star_outline", MD_STAR_OUTLINE),
+    ("star_rate", MD_STAR_RATE),
+    ("stars", MD_STARS),
+    ("stay_current_landscape", MD_STAY_CURRENT_LANDSCAPE),
+    ("stay_current_portrait", MD_STAY_CURRENT_PORTRAIT),
+    ("stay_primary_landscape", MD_STAY_PRIMARY_LANDSCAPE),
+    ("stay_primary_portrait", MD_STAY_PRIMARY_PORTRAIT),
+    ("sticky_note_2", MD_STICKY_NOTE_2),
+    ("stop", MD_STOP),
+    ("stop_circle", MD_STOP_CIRCLE),
+    ("stop_screen_share", MD_STOP_SCREEN_SHARE),
+    ("storage", MD_STORAGE),
+    ("store", MD_STORE),
+    ("store_mall_directory", MD_STORE_MALL_DIRECTORY),
+    ("storefront", MD_STOREFRONT),
+    ("straighten", MD_STRAIGHTEN),
+    ("streetview", MD_STREETVIEW),
+    ("strikethrough_s", MD_STRIKETHROUGH_S),
+    ("stroller", MD_STROLLER),
+    ("style", MD_STYLE),
+    ("subdirectory_arrow_left", MD_SUBDIRECTORY_ARROW_LEFT),
+    ("subdirectory_arrow_right", MD_SUBDIRECTORY_ARROW_RIGHT),
+    ("subject", MD_SUBJECT),
+    ("subscript", MD_SUBSCRIPT),
+    ("subscriptions", MD_SUBSCRIPTIONS),
+    ("subtitles", MD_SUBTITLES),
+    ("subtitles_off", MD_SUBTITLES_OFF),
+    ("subway", MD_SUBWAY),
+    ("superscript", MD_SUPERSCRIPT),
+    ("supervised_user_circle", MD_SUPERVISED_USER_CIRCLE),
+    ("supervisor_account", MD_SUPERVISOR_ACCOUNT),
+    ("support", MD_SUPPORT),
+    ("support_agent", MD_SUPPORT_AGENT),
+    ("surround_sound", MD_SURROUND_SOUND),
+    ("swap_calls", MD_SWAP_CALLS),
+    ("swap_horiz", MD_SWAP_HORIZ),
+    ("swap_horizontal_circle", MD_SWAP_HORIZONTAL_CIRCLE),
+    ("swap_vert", MD_SWAP_VERT),
+    ("swap_vertical_circle", MD_SWAP_VERTICAL_CIRCLE),
+    ("switch_camera", MD_SWITCH_CAMERA),
+    ("switch_left", MD_SWITCH_LEFT),
+    ("switch_right", MD_SWITCH_RIGHT),
+    ("switch_video", MD_SWITCH_VIDEO),
+    ("sync", MD_SYNC),
+    ("sync_alt", MD_SYNC_ALT),
+    ("sync_disabled", MD_SYNC_DISABLED),
+    ("sync_problem", MD_SYNC_PROBLEM),
+    ("system_update", MD_SYSTEM_UPDATE),
+    ("system_update_alt", MD_SYSTEM_UPDATE_ALT),
+    ("tab", MD_TAB),
+    ("tab_unselected", MD_TAB_UNSELECTED),
+    ("table_chart", MD_TABLE_CHART),
+    ("table_rows", MD_TABLE_ROWS),
+    ("table_view", MD_TABLE_VIEW),
+    ("tablet", MD_TABLET),
+    ("tablet_android", MD_TABLET_ANDROID),
+    ("tablet_mac", MD_TABLET_MAC),
+    ("tag_faces", MD_TAG_FACES),
+    ("tap_and_play", MD_TAP_AND_PLAY),
+    ("tapas", MD_TAPAS),
+    ("terrain", MD_TERRAIN),
+    ("text_fields", MD_TEXT_FIELDS),
+    ("text_format", MD_TEXT_FORMAT),
+    ("text_rotate_up", MD_TEXT_ROTATE_UP),
+    ("text_rotate_vertical", MD_TEXT_ROTATE_VERTICAL),
+    ("text_rotation_angledown", MD_TEXT_ROTATION_ANGLEDOWN),
+    ("text_rotation_angleup", MD_TEXT_ROTATION_ANGLEUP),
+    ("text_rotation_down", MD_TEXT_ROTATION_DOWN),
+    ("text_rotation_none", MD_TEXT_ROTATION_NONE),
+    ("text_snippet", MD_TEXT_SNIPPET),
+    ("textsms", MD_TEXTSMS),
+    ("texture", MD_TEXTURE),
+    ("theaters", MD_THEATERS),
+    ("thumb_down", MD_THUMB_DOWN),
+    ("thumb_down_alt", MD_THUMB_DOWN_ALT),
+    ("thumb_up", MD_THUMB_UP),
+    ("thumb_up_alt", MD_THUMB_UP_ALT),
+    ("thumbs_up_down", MD_THUMBS_UP_DOWN),
+    ("time_to_leave", MD_TIME_TO_LEAVE),
+    ("timelapse", MD_TIMELAPSE),
+    ("timeline", MD_TIMELINE),
+    ("timer", MD_TIMER),
+    ("timer_10", MD_TIMER_10),
+    ("timer_3", MD_TIMER_3),
+    ("timer_off", MD_TIMER_OFF),
+    ("title", MD_TITLE),
+    ("toc", MD_TOC),
+    ("today", MD_TODAY),
+    ("toggle_off", MD_TOGGLE_OFF),
+    ("toggle_on", MD_TOGGLE_ON),
+    ("toll", MD_TOLL),
+    ("tonality", MD_TONALITY),
+    ("topic", MD_TOPIC),
+    ("touch_app", MD_TOUCH_APP),
+    ("tour", MD_TOUR),
+    ("toys", MD_TOYS),
+    ("track_changes", MD_TRACK_CHANGES),
+    ("traffic", MD_TRAFFIC),
+    ("train", MD_TRAIN),
+    ("tram", MD_TRAM),
+    ("transfer_within_a_station", MD_TRANSFER_WITHIN_A_STATION),
+    ("transform", MD_TRANSFORM),
+    ("transit_enterexit", MD_TRANSIT_ENTEREXIT),
+    ("translate", MD_TRANSLATE),
+    ("trending_down", MD_TRENDING_DOWN),
+    ("trending_flat", MD_TRENDING_FLAT),
+    ("trending_up", MD_TRENDING_UP),
+    ("trip_origin", MD_TRIP_ORIGIN),
+    ("tty", MD_TTY),
+    ("tune", MD_TUNE),
+    ("turned_in", MD_TURNED_IN),
+    ("turned_in_not", MD_TURNED_IN_NOT),
+    ("tv", MD_TV),
+    ("tv_off", MD_TV_OFF),
+    ("two_wheeler", MD_TWO_WHEELER),
+    ("umbrella", MD_UMBRELLA),
+    ("unarchive", MD_UNARCHIVE),
+    ("undo", MD_UNDO),
+    ("unfold_less", MD_UNFOLD_LESS),
+    ("unfold_more", MD_UNFOLD_MORE),
+    ("unsubscribe", MD_UNSUBSCRIBE),
+    ("update", MD_UPDATE),
+    ("upgrade", MD_UPGRADE),
+    ("usb", MD_USB),
+    ("verified", MD_VERIFIED),
+    ("verified_user", MD_VERIFIED_USER),
+    ("vertical_align_bottom", MD_VERTICAL_ALIGN_BOTTOM),
+    ("vertical_align_center", MD_VERTICAL_ALIGN_CENTER),
+    ("vertical_align_top", MD_VERTICAL_ALIGN_TOP),
+    ("vertical_split", MD_VERTICAL_SPLIT),
+    ("vibration", MD_VIBRATION),
+    ("video_call", MD_VIDEO_CALL),
+    ("video_label", MD_VIDEO_LABEL),
+    ("video_library", MD_VIDEO_LIBRARY),
+    ("video_settings", MD_VIDEO_SETTINGS),
+    ("videocam", MD_VIDEOCAM),
+    ("videocam_off", MD_VIDEOCAM_OFF),
+    ("videogame_asset", MD_VIDEOGAME_ASSET),
+    ("view_agenda", MD_VIEW_AGENDA),
+    ("view_array", MD_VIEW_ARRAY),
+    ("view_carousel", MD_VIEW_CAROUSEL),
+    ("view_column", MD_VIEW_COLUMN),
+    ("view_comfy", MD_VIEW_COMFY),
+    ("view_compact", MD_VIEW_COMPACT),
+    ("view_day", MD_VIEW_DAY),
+    ("view_headline", MD_VIEW_HEADLINE),
+    ("view_list", MD_VIEW_LIST),
+    ("view_module", MD_VIEW_MODULE),
+    ("view_quilt", MD_VIEW_QUILT),
+    ("view_sidebar", MD_VIEW_SIDEBAR),
+    ("view_stream", MD_VIEW_STREAM),
+    ("view_week", MD_VIEW_WEEK),
+    ("vignette", MD_VIGNETTE),
+    ("visibility", MD_VISIBILITY),
+    ("visibility_off", MD_VISIBILITY_OFF),
+    ("voice_chat", MD_VOICE_CHAT),
+    ("voice_over_off", MD_VOICE_OVER_OFF),
+    ("voicemail", MD_VOICEMAIL),
+    ("volume_down", MD_VOLUME_DOWN),
+    ("volume_mute", MD_VOLUME_MUTE),
+    ("volume_off", MD_VOLUME_OFF),
+    ("volume_up", MD_VOLUME_UP),
+    ("vpn_key", MD_VPN_KEY),
+    ("vpn_lock", MD_VPN_LOCK),
+    ("wallpaper", MD_WALLPAPER),
+    ("warning", MD_WARNING),
+    ("wash", MD_WASH),
+    ("watch", MD_WATCH),
+    ("watch_later", MD_WATCH_LATER),
+    ("water_damage", MD_WATER_DAMAGE),
+    ("waves", MD_WAVES),
+    ("wb_auto", MD_WB_AUTO),
+    ("wb_cloudy", MD_WB_CLOUDY),
+    ("wb_incandescent", MD_WB_INCANDESCENT),
+    ("wb_iridescent", MD_WB_IRIDESCENT),
+    ("wb_sunny", MD_WB_SUNNY),
+    ("wc", MD_WC),
+    ("web", MD_WEB),
+    ("web_asset", MD_WEB_ASSET),
+    ("weekend", MD_WEEKEND),
+    ("west", MD_WEST),
+    ("whatshot", MD_WHATSHOT),
+    ("wheelchair_pickup", MD_WHEELCHAIR_PICKUP),
+    ("where_to_vote", MD_WHERE_TO_VOTE),
+    ("widgets", MD_WIDGETS),
+    ("wifi", MD_WIFI),
+    ("wifi_calling", MD_WIFI_CALLING),
+    ("wifi_lock", MD_WIFI_LOCK),
+    ("wifi_off", MD_WIFI_OFF),
+    ("wifi_protected_setup", MD_WIFI_PROTECTED_SETUP),
+    ("wifi_tethering", MD_WIFI_TETHERING),
+    ("wine_bar", MD_WINE_BAR),
+    ("work", MD_WORK),
+    ("work_off", MD_WORK_OFF),
+    ("work_outline", MD_WORK_OUTLINE),
+    ("wrap_text", MD_WRAP_TEXT),
+    ("wrong_location", MD_WRONG_LOCATION),
+    ("wysiwyg", MD_WYSIWYG),
+    ("youtube_searched_for", MD_YOUTUBE_SEARCHED_FOR),
+    ("zoom_in", MD_ZOOM_IN),
+    ("zoom_out", MD_ZOOM_OUT),
+    ("zoom_out_map", MD_ZOOM_OUT_MAP),
+];
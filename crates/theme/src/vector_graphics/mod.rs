@@ -4,3 +4,6 @@ pub use self::material_icons_font::*;
 /// their unique codepoints, representing the `glyph`. The codepoints
 /// are mapped to this given constants.
 pub mod material_icons_font;
+
+/// Font family of the Material Icons font.
+pub const MATERIAL_ICONS_FONT: &str = "MaterialIcons-Regular";
@@ -0,0 +1,727 @@
+use std::collections::HashMap;
+
+use ron::{value::Map, Number, Value};
+
+use crate::config::RESOURCE_KEY;
+
+/// Name of the property that expands a font descriptor to the `font_family` and `font_size` properties.
+pub static FONT_PROPERTY: &str = "font";
+
+/// Functions that could be used in values of a theme, e.g. `"lighten($PRIMARY, 10%)"`.
+///
+/// * `lighten(color, amount)`, `darken(color, amount)`: changes the lightness of the color.
+/// * `alpha(color, amount)`: replaces the alpha value of the color.
+/// * `mix(color, color, amount)`: mixes two colors, the amount is the weight of the first color (default `50%`).
+/// * `thickness(all)`, `thickness(left_right, top_bottom)`, `thickness(left, top, right, bottom)`.
+/// * `linear-gradient(x y, x y, color [position], ...)`: gradient from the start to the end point. Stops without a
+///   position are spread evenly.
+/// * `font(family, [weight], [size])`: font descriptor, e.g. `font(Roboto, medium, 16)` for `Roboto-Medium`.
+///
+/// Amounts and positions are numbers between `0` and `1` or percentages.
+pub static FUNCTIONS: &[&str] = &[
+    "alpha",
+    "darken",
+    "font",
+    "lighten",
+    "linear-gradient",
+    "mix",
+    "thickness",
+];
+
+// Font weights by their numeric weight, used to build the font family name, e.g. `Roboto-Medium`.
+static FONT_WEIGHTS: &[(u32, &str)] = &[
+    (100, "Thin"),
+    (200, "ExtraLight"),
+    (300, "Light"),
+    (400, "Regular"),
+    (500, "Medium"),
+    (600, "SemiBold"),
+    (700, "Bold"),
+    (800, "ExtraBold"),
+    (900, "Black"),
+];
+
+/// Resolves resource references and function calls of theme values. Resources could reference other resources.
+pub struct Resolver<'a> {
+    resources: &'a HashMap<String, Value>,
+    stack: Vec<String>,
+}
+
+impl<'a> Resolver<'a> {
+    /// Creates a new resolver for the given resources.
+    pub fn new(resources: &'a HashMap<String, Value>) -> Self {
+        Resolver {
+            resources,
+            stack: vec![],
+        }
+    }
+
+    /// Resolves the given value. Strings are evaluated as expressions, sequences and maps are resolved recursively.
+    pub fn resolve(&mut self, value: &Value) -> Result<Value, String> {
+        match value {
+            Value::String(s) => self.evaluate(s),
+            Value::Seq(seq) => Ok(Value::Seq(
+                seq.iter()
+                    .map(|value| self.resolve(value))
+                    .collect::<Result<_, _>>()?,
+            )),
+            Value::Map(map) => {
+                let mut resolved = Map::new();
+
+                for (key, value) in map.iter() {
+                    resolved.insert(key.clone(), self.resolve(value)?);
+                }
+
+                Ok(Value::Map(resolved))
+            }
+            Value::Option(Some(value)) => Ok(Value::Option(Some(Box::new(self.resolve(value)?)))),
+            _ => Ok(value.clone()),
+        }
+    }
+
+    /// Returns the resolved resource of the given key.
+    pub fn resource(&mut self, key: &str) -> Result<Value, String> {
+        if self.stack.iter().any(|k| k == key) {
+            let mut cycle = self.stack.clone();
+            cycle.push(key.to_string());
+            return Err(format!(
+                "resources reference each other: {}",
+                cycle.join(" -> ")
+            ));
+        }
+
+        let value = self
+            .resources
+            .get(key)
+            .ok_or_else(|| format!("resource \"{}\" is not defined", key))?;
+
+        self.stack.push(key.to_string());
+        let value = self.resolve(value);
+        self.stack.pop();
+
+        value
+    }
+
+    fn evaluate(&mut self, s: &str) -> Result<Value, String> {
+        let expression = s.trim();
+
+        if let Some(key) = resource_key(expression) {
+            return self.resource(key);
+        }
+
+        if let Some((function, args)) = function_call(expression) {
+            return self
+                .call(function, &args)
+                .map_err(|e| format!("{}: {}", expression, e));
+        }
+
+        Ok(Value::String(s.to_string()))
+    }
+
+    fn call(&mut self, function: &str, args: &[&str]) -> Result<Value, String> {
+        match (function, args.len()) {
+            ("lighten", 2) | ("darken", 2) => {
+                let color = self.color(args[0])?;
+                let mut amount = self.number(args[1])?;
+
+                if function == "darken" {
+                    amount = -amount;
+                }
+
+                Ok(color.lighten(amount).into())
+            }
+            ("alpha", 2) => {
+                let mut color = self.color(args[0])?;
+                color.a = clamp(self.number(args[1])?);
+
+                Ok(color.into())
+            }
+            ("mix", 2) | ("mix", 3) => {
+                let first = self.color(args[0])?;
+                let second = self.color(args[1])?;
+                let weight = match args.get(2) {
+                    Some(weight) => clamp(self.number(weight)?),
+                    None => 0.5,
+                };
+
+                Ok(second.mix(first, weight).into())
+            }
+            ("thickness", 1) | ("thickness", 2) | ("thickness", 4) => {
+                let values = args
+                    .iter()
+                    .map(|arg| self.number(arg))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let (left, top, right, bottom) = match values.len() {
+                    1 => (values[0], values[0], values[0], values[0]),
+                    2 => (values[0], values[1], values[0], values[1]),
+                    _ => (values[0], values[1], values[2], values[3]),
+                };
+
+                Ok(map(vec![
+                    ("left", number(left)),
+                    ("top", number(top)),
+                    ("right", number(right)),
+                    ("bottom", number(bottom)),
+                ]))
+            }
+            ("linear-gradient", len) if len >= 4 => self.linear_gradient(args),
+            ("font", 1) | ("font", 2) | ("font", 3) => {
+                let family = self.string(args[0])?;
+                let mut entries = vec![("family", Value::String(family))];
+
+                // a single argument after the family is the size if it is a number
+                let (weight, size) = match args.len() {
+                    3 => (Some(args[1]), Some(args[2])),
+                    2 if as_number(&self.evaluate(args[1])?).is_some() => (None, Some(args[1])),
+                    2 => (Some(args[1]), None),
+                    _ => (None, None),
+                };
+
+                if let Some(weight) = weight {
+                    entries.push(("weight", self.evaluate(weight)?));
+                }
+
+                if let Some(size) = size {
+                    entries.push(("size", number(self.number(size)?)));
+                }
+
+                Ok(map(entries))
+            }
+            _ => Err(format!("wrong number of arguments ({})", args.len())),
+        }
+    }
+
+    fn linear_gradient(&mut self, args: &[&str]) -> Result<Value, String> {
+        let start = point(args[0])?;
+        let end = point(args[1])?;
+        let count = args.len() - 2;
+        let mut stops = vec![];
+
+        for (i, arg) in args[2..].iter().enumerate() {
+            // the position follows the color, e.g. `$PRIMARY 50%`
+            let (color, position) = match arg.trim().rfind(char::is_whitespace) {
+                Some(index) if parse_number(&arg.trim()[index..]).is_some() => {
+                    let arg = arg.trim();
+                    (&arg[..index], parse_number(&arg[index..]).unwrap())
+                }
+                _ => (*arg, i as f64 / (count - 1) as f64),
+            };
+
+            stops.push(map(vec![
+                ("position", number(clamp(position))),
+                ("color", self.color(color)?.into()),
+            ]));
+        }
+
+        Ok(map(vec![
+            ("start", start),
+            ("end", end),
+            ("stops", Value::Seq(stops)),
+        ]))
+    }
+
+    fn string(&mut self, arg: &str) -> Result<String, String> {
+        match self.evaluate(arg)? {
+            Value::String(s) => Ok(s.trim().to_string()),
+            _ => Err(format!("\"{}\" is not a string", arg.trim())),
+        }
+    }
+
+    fn number(&mut self, arg: &str) -> Result<f64, String> {
+        as_number(&self.evaluate(arg)?).ok_or_else(|| format!("\"{}\" is not a number", arg.trim()))
+    }
+
+    fn color(&mut self, arg: &str) -> Result<Rgba, String> {
+        Rgba::parse(&self.string(arg)?)
+    }
+}
+
+/// Returns the keys of the resources the given expression references.
+pub fn references(expression: &str) -> Vec<&str> {
+    let mut references = vec![];
+    let mut rest = expression;
+
+    while let Some(index) = rest.find(RESOURCE_KEY) {
+        rest = &rest[index + RESOURCE_KEY.len()..];
+        let end = rest.find(|c: char| !is_key_char(c)).unwrap_or(rest.len());
+
+        if end > 0 {
+            references.push(&rest[..end]);
+        }
+
+        rest = &rest[end..];
+    }
+
+    references
+}
+
+/// Expands the value of a `font` property to the `font_family` and `font_size` properties. The value is a font
+/// descriptor with the fields `family`, `weight` and `size` or the name of a font family.
+pub fn font_properties(value: &Value) -> Vec<(String, Value)> {
+    let mut properties = vec![];
+
+    let map = match value {
+        Value::Map(map) => map,
+        Value::String(_) => return vec![("font_family".to_string(), value.clone())],
+        _ => return properties,
+    };
+
+    let field = |name: &str| {
+        map.iter()
+            .find(|(key, _)| **key == Value::String(name.to_string()))
+            .map(|(_, value)| value)
+    };
+
+    if let Some(Value::String(family)) = field("family") {
+        let family = match field("weight").and_then(font_weight) {
+            Some(weight) => format!("{}-{}", family, weight),
+            None => family.clone(),
+        };
+
+        properties.push(("font_family".to_string(), Value::String(family)));
+    }
+
+    if let Some(size) = field("size") {
+        properties.push(("font_size".to_string(), size.clone()));
+    }
+
+    properties
+}
+
+// Returns the weight part of a font family name, e.g. `Medium` for `medium` or `500`.
+fn font_weight(weight: &Value) -> Option<String> {
+    if let Some(weight) = as_number(weight) {
+        return FONT_WEIGHTS
+            .iter()
+            .min_by_key(|(w, _)| (*w as i64 - weight as i64).abs())
+            .map(|(_, name)| name.to_string());
+    }
+
+    let weight = weight.clone().into_rust::<String>().ok()?;
+    let key: String = weight
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect::<String>()
+        .to_lowercase();
+
+    match FONT_WEIGHTS
+        .iter()
+        .find(|(_, name)| name.to_lowercase() == key)
+    {
+        Some((_, name)) => Some(name.to_string()),
+        None => Some(weight),
+    }
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Returns the key of a resource reference, e.g. `PRIMARY` for `$PRIMARY`.
+fn resource_key(expression: &str) -> Option<&str> {
+    if !expression.starts_with(RESOURCE_KEY) {
+        return None;
+    }
+
+    let key = &expression[RESOURCE_KEY.len()..];
+
+    if key
+        .chars()
+        .any(|c| c.is_whitespace() || c == '(' || c == ',')
+    {
+        return None;
+    }
+
+    Some(key)
+}
+
+// Splits a call of a known function into its name and arguments. Other strings are literals.
+fn function_call(expression: &str) -> Option<(&str, Vec<&str>)> {
+    let open = expression.find('(')?;
+    let function = expression[..open].trim();
+
+    if !FUNCTIONS.contains(&function) || !expression.ends_with(')') {
+        return None;
+    }
+
+    let inner = &expression[open + 1..expression.len() - 1];
+    let mut args = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, c) in inner.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return None,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return None;
+    }
+
+    if !inner.trim().is_empty() {
+        args.push(&inner[start..]);
+    }
+
+    Some((function, args))
+}
+
+// Parses a number or a percentage, e.g. `0.5` or `50%`.
+fn parse_number(s: &str) -> Option<f64> {
+    let s = s.trim();
+
+    if let Some(s) = s.strip_suffix('%') {
+        return s.trim().parse::<f64>().ok().map(|v| v / 100.0);
+    }
+
+    s.parse::<f64>().ok()
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => Some(number.into_f64()),
+        Value::String(s) => parse_number(s),
+        _ => None,
+    }
+}
+
+// Parses a point, e.g. `0 32`.
+fn point(s: &str) -> Result<Value, String> {
+    let coordinates: Vec<_> = s.split_whitespace().filter_map(parse_number).collect();
+
+    if coordinates.len() != 2 {
+        return Err(format!("\"{}\" is not a point", s.trim()));
+    }
+
+    Ok(map(vec![
+        ("x", number(coordinates[0])),
+        ("y", number(coordinates[1])),
+    ]))
+}
+
+fn number(value: f64) -> Value {
+    Value::Number(Number::new(value))
+}
+
+fn map(entries: Vec<(&str, Value)>) -> Value {
+    Value::Map(
+        entries
+            .into_iter()
+            .map(|(key, value)| (Value::String(key.to_string()), value))
+            .collect(),
+    )
+}
+
+fn clamp(value: f64) -> f64 {
+    value.clamp(0.0, 1.0)
+}
+
+// A color with components between 0 and 1.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Rgba {
+    r: f64,
+    g: f64,
+    b: f64,
+    a: f64,
+}
+
+impl Rgba {
+    // Parses a color in the format of the theme, `#rrggbb`, `#aarrggbb` or `transparent`.
+    fn parse(s: &str) -> Result<Self, String> {
+        if s == "transparent" {
+            return Ok(Rgba {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            });
+        }
+
+        let hex = s.trim_start_matches('#');
+
+        let data = match hex.len() {
+            6 | 8 => u32::from_str_radix(hex, 16).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| format!("\"{}\" is not a color", s))?;
+
+        let component = |shift: u32| ((data >> shift) & 0xFF) as f64 / 255.0;
+
+        Ok(Rgba {
+            r: component(16),
+            g: component(8),
+            b: component(0),
+            a: if hex.len() == 8 { component(24) } else { 1.0 },
+        })
+    }
+
+    // Changes the lightness in the hsl color space by the given amount.
+    fn lighten(self, amount: f64) -> Self {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let lightness = (max + min) / 2.0;
+
+        let (hue, saturation) = if max == min {
+            (0.0, 0.0)
+        } else {
+            let delta = max - min;
+            let saturation = if lightness > 0.5 {
+                delta / (2.0 - max - min)
+            } else {
+                delta / (max + min)
+            };
+
+            let hue = if max == self.r {
+                (self.g - self.b) / delta + if self.g < self.b { 6.0 } else { 0.0 }
+            } else if max == self.g {
+                (self.b - self.r) / delta + 2.0
+            } else {
+                (self.r - self.g) / delta + 4.0
+            };
+
+            (hue / 6.0, saturation)
+        };
+
+        let lightness = clamp(lightness + amount);
+
+        if saturation == 0.0 {
+            return Rgba {
+                r: lightness,
+                g: lightness,
+                b: lightness,
+                a: self.a,
+            };
+        }
+
+        let q = if lightness < 0.5 {
+            lightness * (1.0 + saturation)
+        } else {
+            lightness + saturation - lightness * saturation
+        };
+        let p = 2.0 * lightness - q;
+
+        let channel = |t: f64| {
+            let t = if t < 0.0 {
+                t + 1.0
+            } else if t > 1.0 {
+                t - 1.0
+            } else {
+                t
+            };
+
+            if t < 1.0 / 6.0 {
+                p + (q - p) * 6.0 * t
+            } else if t < 0.5 {
+                q
+            } else if t < 2.0 / 3.0 {
+                p + (q - p) * (2.0 / 3.0 - t) * 6.0
+            } else {
+                p
+            }
+        };
+
+        Rgba {
+            r: channel(hue + 1.0 / 3.0),
+            g: channel(hue),
+            b: channel(hue - 1.0 / 3.0),
+            a: self.a,
+        }
+    }
+
+    // Interpolates to the other color, a weight of 1 returns the other color.
+    fn mix(self, other: Rgba, weight: f64) -> Self {
+        let mix = |a: f64, b: f64| a + (b - a) * weight;
+
+        Rgba {
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+            a: mix(self.a, other.a),
+        }
+    }
+}
+
+impl From<Rgba> for Value {
+    fn from(color: Rgba) -> Self {
+        let component = |c: f64| (c * 255.0).round() as u8;
+
+        let color = if component(color.a) == 255 {
+            format!(
+                "#{:02x}{:02x}{:02x}",
+                component(color.r),
+                component(color.g),
+                component(color.b)
+            )
+        } else {
+            format!(
+                "#{:02x}{:02x}{:02x}{:02x}",
+                component(color.a),
+                component(color.r),
+                component(color.g),
+                component(color.b)
+            )
+        };
+
+        Value::String(color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resources() -> HashMap<String, Value> {
+        let mut resources = HashMap::new();
+        resources.insert("PRIMARY".to_string(), Value::String("#ff0000".to_string()));
+        resources.insert(
+            "PRIMARY_HOVER".to_string(),
+            Value::String("lighten($PRIMARY, 20%)".to_string()),
+        );
+        resources.insert("SIZE".to_string(), Value::Number(Number::new(16)));
+        resources.insert("A".to_string(), Value::String("$B".to_string()));
+        resources.insert("B".to_string(), Value::String("$A".to_string()));
+        resources
+    }
+
+    fn resolve(expression: &str) -> Result<Value, String> {
+        let resources = resources();
+        Resolver::new(&resources).resolve(&Value::String(expression.to_string()))
+    }
+
+    fn string(s: &str) -> Value {
+        Value::String(s.to_string())
+    }
+
+    #[test]
+    fn test_colors() {
+        assert_eq!(resolve("$PRIMARY"), Ok(string("#ff0000")));
+        assert_eq!(resolve("$PRIMARY_HOVER"), Ok(string("#ff6666")));
+        assert_eq!(resolve("darken($PRIMARY, 0.2)"), Ok(string("#990000")));
+        assert_eq!(resolve("lighten(#000000, 100%)"), Ok(string("#ffffff")));
+        assert_eq!(resolve("alpha($PRIMARY, 50%)"), Ok(string("#80ff0000")));
+        assert_eq!(resolve("mix(#ffffff, #000000)"), Ok(string("#808080")));
+        assert_eq!(
+            resolve("mix(#ffffff, #000000, 100%)"),
+            Ok(string("#ffffff"))
+        );
+        assert_eq!(
+            resolve("alpha(lighten($PRIMARY, 20%), 0)"),
+            Ok(string("#00ff6666"))
+        );
+
+        // literals are kept
+        assert_eq!(resolve("Roboto-Regular"), Ok(string("Roboto-Regular")));
+        assert_eq!(resolve("Click (here)"), Ok(string("Click (here)")));
+
+        assert!(resolve("lighten($MISSING, 10%)").is_err());
+        assert!(resolve("lighten(red, 10%)").is_err());
+        assert!(resolve("darken($PRIMARY)").is_err());
+        assert!(resolve("$A").unwrap_err().contains("A -> B -> A"));
+    }
+
+    #[test]
+    fn test_thickness() {
+        let thickness = |left: f64, top: f64, right: f64, bottom: f64| {
+            map(vec![
+                ("left", number(left)),
+                ("top", number(top)),
+                ("right", number(right)),
+                ("bottom", number(bottom)),
+            ])
+        };
+
+        assert_eq!(resolve("thickness(4)"), Ok(thickness(4.0, 4.0, 4.0, 4.0)));
+        assert_eq!(
+            resolve("thickness(8, $SIZE)"),
+            Ok(thickness(8.0, 16.0, 8.0, 16.0))
+        );
+        assert_eq!(
+            resolve("thickness(1, 2, 3, 4)"),
+            Ok(thickness(1.0, 2.0, 3.0, 4.0))
+        );
+        assert!(resolve("thickness(1, 2, 3)").is_err());
+    }
+
+    #[test]
+    fn test_linear_gradient() {
+        let stop = |position: f64, color: &str| {
+            map(vec![
+                ("position", number(position)),
+                ("color", string(color)),
+            ])
+        };
+
+        assert_eq!(
+            resolve("linear-gradient(0 0, 0 32, $PRIMARY, lighten($PRIMARY, 20%) 25%, #000000)"),
+            Ok(map(vec![
+                ("start", map(vec![("x", number(0.0)), ("y", number(0.0))])),
+                ("end", map(vec![("x", number(0.0)), ("y", number(32.0))])),
+                (
+                    "stops",
+                    Value::Seq(vec![
+                        stop(0.0, "#ff0000"),
+                        stop(0.25, "#ff6666"),
+                        stop(1.0, "#000000")
+                    ])
+                ),
+            ]))
+        );
+        assert!(resolve("linear-gradient(0 0, $PRIMARY, #000000)").is_err());
+    }
+
+    #[test]
+    fn test_font() {
+        let properties = |expression: &str| font_properties(&resolve(expression).unwrap());
+
+        assert_eq!(
+            properties("font(Roboto, medium, $SIZE)"),
+            vec![
+                ("font_family".to_string(), string("Roboto-Medium")),
+                ("font_size".to_string(), number(16.0))
+            ]
+        );
+        assert_eq!(
+            properties("font(Roboto, bold)"),
+            vec![("font_family".to_string(), string("Roboto-Bold"))]
+        );
+        assert_eq!(
+            properties("font(Roboto, 12)"),
+            vec![
+                ("font_family".to_string(), string("Roboto")),
+                ("font_size".to_string(), number(12.0))
+            ]
+        );
+        assert_eq!(
+            properties("font(Roboto, 700, 12)"),
+            vec![
+                ("font_family".to_string(), string("Roboto-Bold")),
+                ("font_size".to_string(), number(12.0))
+            ]
+        );
+        assert_eq!(
+            properties("font(Roboto-Regular)"),
+            vec![("font_family".to_string(), string("Roboto-Regular"))]
+        );
+        assert_eq!(
+            properties("Roboto-Regular"),
+            vec![("font_family".to_string(), string("Roboto-Regular"))]
+        );
+    }
+
+    #[test]
+    fn test_references() {
+        assert_eq!(
+            references("mix($PRIMARY, $DARK_2, 10%)"),
+            vec!["PRIMARY", "DARK_2"]
+        );
+        assert!(references("#000000").is_empty());
+    }
+}
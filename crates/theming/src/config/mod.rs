@@ -1,5 +1,7 @@
+pub use self::expression::*;
 pub use self::style_config::*;
 pub use self::theme_config::*;
 
+mod expression;
 mod style_config;
 mod theme_config;
@@ -3,8 +3,10 @@ use std::collections::HashMap;
 use ron::Value;
 use serde_derive::{Deserialize, Serialize};
 
-/// Defines a style. A style could be base on other styles and contains a list for properties
-/// and a list of state properties.
+use crate::Transition;
+
+/// Defines a style. A style could be base on other styles and contains a list for properties,
+/// a list of state properties and the transitions of properties between states.
 #[derive(Default, Clone, Debug, Serialize, Deserialize)]
 pub struct StyleConfig {
     // set default string to base style
@@ -14,4 +16,6 @@ pub struct StyleConfig {
     pub states: HashMap<String, HashMap<String, Value>>,
     #[serde(default)]
     pub properties: HashMap<String, Value>,
+    #[serde(default)]
+    pub transitions: HashMap<String, Transition>,
 }
@@ -1,9 +1,12 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fs, path::Path};
 
 use ron::{de::from_str, Value};
 use serde_derive::{Deserialize, Serialize};
 
-use crate::{config::StyleConfig, Selector};
+use crate::{
+    config::{Resolver, StyleConfig},
+    Selector,
+};
 
 pub static BASE_STYLE: &str = "base";
 pub static RESOURCE_KEY: &str = "$";
@@ -35,11 +38,42 @@ impl<'a> ThemeConfig {
         self
     }
 
+    /// Parses a theme from the given ron string. The error describes the position of the syntax error.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        from_str(s).map_err(|e| e.to_string())
+    }
+
+    /// Reads and parses the theme file of the given path.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let s = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read theme {}: {}", path.display(), e))?;
+
+        ThemeConfig::parse(&s)
+            .map_err(|e| format!("Could not parse theme {}: {}", path.display(), e))
+    }
+
+    /// Resolves the resource references and expressions of the given value, e.g. `"lighten($PRIMARY, 10%)"`. See
+    /// `FUNCTIONS` for the available functions.
+    pub fn resolve(&self, value: &Value) -> Result<Value, String> {
+        Resolver::new(&self.resources).resolve(value)
+    }
+
+    /// Returns the resolved resource of the given key, e.g. `PRIMARY`.
+    pub fn resource(&self, key: &str) -> Result<Value, String> {
+        Resolver::new(&self.resources).resource(key)
+    }
+
     /// Gets a property by the given name and a selector.
     pub fn property(&'a self, property: &str, selector: &Selector) -> Option<Value> {
-        if let Some(style) = &selector.style {
-            if let Some(style) = self.styles.get(style) {
-                return self.get_property(property, style, selector);
+        // the last style class has the most priority
+        let classes: Vec<_> = selector.classes().collect();
+
+        for class in classes.iter().rev() {
+            if let Some(style) = self.styles.get(*class) {
+                if let Some(value) = self.get_property(property, style, selector) {
+                    return Some(value);
+                }
             }
         }
 
@@ -57,24 +91,38 @@ impl<'a> ThemeConfig {
         style: &'a StyleConfig,
         selector: &Selector,
     ) -> Option<Value> {
-        // state properties has the most priority
-        if let Some(state) = &selector.state {
-            if let Some(properties) = style.states.get(state) {
-                return self.get_property_value(property, properties);
+        // state properties has the most priority, the matching state rule with the highest precedence wins
+        // (recursive through base style).
+        let mut rules = vec![];
+        let mut current = Some(style);
+        let mut depth = 0;
+
+        while let Some(style) = current {
+            for (rule, properties) in &style.states {
+                if let Some(precedence) = selector.state_precedence(rule) {
+                    rules.push((precedence, depth, properties));
+                }
             }
 
-            // load state properties from based style if there are no other states (recursive through base style).
-            if style.base.is_empty() {
-                return None;
-            }
+            current = self.styles.get(&style.base);
+            depth += 1;
+        }
 
-            if let Some(base_style) = self.styles.get(&style.base) {
-                if let Some(properties) = base_style.states.get(state) {
-                    return self.get_property_value(property, properties);
-                }
+        // a rule of a style is preferred over a rule with the same precedence of its base style
+        rules.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        for (_, _, properties) in rules {
+            let value = self.get_property_value(property, properties);
+
+            if value.is_some() {
+                return value;
             }
         }
 
+        self.get_style_property(property, style)
+    }
+
+    fn get_style_property(&'a self, property: &str, style: &'a StyleConfig) -> Option<Value> {
         let value = self.get_property_value(property, &style.properties);
 
         if value.is_some() {
@@ -87,7 +135,7 @@ impl<'a> ThemeConfig {
         }
 
         if let Some(base_style) = self.styles.get(&style.base) {
-            return self.get_property(property, base_style, selector);
+            return self.get_style_property(property, base_style);
         }
 
         None
@@ -98,16 +146,8 @@ impl<'a> ThemeConfig {
         property: &str,
         properties: &'a HashMap<String, Value>,
     ) -> Option<Value> {
-        let property = properties.get(property)?;
-
-        // load from resources if the value is a key.
-        if let Ok(key) = property.clone().into_rust::<String>() {
-            if key.starts_with(RESOURCE_KEY) {
-                return Some(self.resources.get(&key.replace(RESOURCE_KEY, ""))?.clone());
-            }
-        }
-
-        Some(property.clone())
+        // resources and expressions are resolved, e.g. `$PRIMARY`.
+        self.resolve(properties.get(property)?).ok()
     }
 }
 
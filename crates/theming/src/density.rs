@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use ron::{Number, Value};
+
+/// Prefix of the resources that define the scale of a density, e.g. `DENSITY_COMPACT`.
+pub static DENSITY_KEY_PREFIX: &str = "DENSITY_";
+
+/// Properties that are scaled by the spacing factor of a density.
+pub static SPACING_PROPERTIES: &[&str] = &[
+    "padding",
+    "padding_left",
+    "padding_top",
+    "padding_right",
+    "padding_bottom",
+    "margin",
+    "spacing",
+];
+
+/// Properties that are scaled by the font size factor of a density.
+pub static FONT_SIZE_PROPERTIES: &[&str] = &["font_size", "icon_size"];
+
+/// Properties that are scaled by the control height factor of a density. Fixed widths are scaled too, so square
+/// controls stay square.
+pub static CONTROL_HEIGHT_PROPERTIES: &[&str] = &["height", "min_height", "width", "min_width"];
+
+/// Describes how dense the widgets of a theme are laid out, e.g. `Compact` for data-heavy screens and `Touch` for
+/// kiosks.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Density {
+    Compact,
+    #[default]
+    Normal,
+    Touch,
+}
+
+impl Density {
+    /// Returns the key of the resource that defines the scale of the density, e.g. `DENSITY_COMPACT`.
+    pub fn resource_key(self) -> String {
+        let name = match self {
+            Density::Compact => "COMPACT",
+            Density::Normal => "NORMAL",
+            Density::Touch => "TOUCH",
+        };
+
+        format!("{}{}", DENSITY_KEY_PREFIX, name)
+    }
+}
+
+impl From<&str> for Density {
+    fn from(s: &str) -> Density {
+        match s {
+            "compact" | "Compact" => Density::Compact,
+            "touch" | "Touch" => Density::Touch,
+            _ => Density::Normal,
+        }
+    }
+}
+
+/// Factors the properties of a theme are scaled by for a density. A theme defines the scales by resources, e.g.
+/// `"DENSITY_COMPACT": (spacing: 0.5, font_size: 0.85, control_height: 0.75)`. Missing factors are `1`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DensityScale {
+    pub spacing: f64,
+    pub font_size: f64,
+    pub control_height: f64,
+}
+
+impl Default for DensityScale {
+    fn default() -> Self {
+        DensityScale::new(1.0, 1.0, 1.0)
+    }
+}
+
+impl DensityScale {
+    /// Creates a new scale with the given factors.
+    pub fn new(spacing: f64, font_size: f64, control_height: f64) -> Self {
+        DensityScale {
+            spacing,
+            font_size,
+            control_height,
+        }
+    }
+
+    /// Reads the scale from the resource of a theme.
+    pub fn from_value(value: &Value) -> Self {
+        let mut scale = DensityScale::default();
+
+        if let Value::Map(map) = value {
+            for (key, value) in map.iter() {
+                let factor = match value {
+                    Value::Number(number) => number.into_f64(),
+                    _ => continue,
+                };
+
+                match key {
+                    Value::String(key) if key == "spacing" => scale.spacing = factor,
+                    Value::String(key) if key == "font_size" => scale.font_size = factor,
+                    Value::String(key) if key == "control_height" => scale.control_height = factor,
+                    _ => {}
+                }
+            }
+        }
+
+        scale
+    }
+
+    /// Returns the factor of the given property or `None` if the property is not scaled.
+    pub fn factor(&self, property: &str) -> Option<f64> {
+        if SPACING_PROPERTIES.contains(&property) {
+            Some(self.spacing)
+        } else if FONT_SIZE_PROPERTIES.contains(&property) {
+            Some(self.font_size)
+        } else if CONTROL_HEIGHT_PROPERTIES.contains(&property) {
+            Some(self.control_height)
+        } else {
+            None
+        }
+    }
+
+    /// Scales the given properties. Values are rounded to whole pixels.
+    pub fn scale(&self, properties: &mut HashMap<String, Value>) {
+        if *self == DensityScale::default() {
+            return;
+        }
+
+        for (key, value) in properties.iter_mut() {
+            if let Some(factor) = self.factor(key) {
+                *value = scale_value(value, factor);
+            }
+        }
+    }
+}
+
+// Scales numbers and the numbers of maps, e.g. of a thickness.
+fn scale_value(value: &Value, factor: f64) -> Value {
+    match value {
+        Value::Number(number) => Value::Number(Number::new((number.into_f64() * factor).round())),
+        Value::Map(map) => Value::Map(
+            map.iter()
+                .map(|(key, value)| (key.clone(), scale_value(value, factor)))
+                .collect(),
+        ),
+        _ => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::ThemeConfig;
+
+    #[test]
+    fn test_scale() {
+        let theme = ThemeConfig::from(
+            r#"Theme ( resources: { "DENSITY_COMPACT": (spacing: 0.5, font_size: 0.75) } )"#,
+        );
+        let scale = DensityScale::from_value(&theme.resource("DENSITY_COMPACT").unwrap());
+        assert_eq!(scale, DensityScale::new(0.5, 0.75, 1.0));
+
+        let mut properties = HashMap::new();
+        properties.insert(
+            "padding".to_string(),
+            theme
+                .resolve(&Value::String("thickness(8, 4)".to_string()))
+                .unwrap(),
+        );
+        properties.insert("font_size".to_string(), Value::Number(Number::new(16)));
+        properties.insert("height".to_string(), Value::Number(Number::new(32)));
+        properties.insert(
+            "background".to_string(),
+            Value::String("#000000".to_string()),
+        );
+        scale.scale(&mut properties);
+
+        assert_eq!(
+            properties["padding"],
+            theme
+                .resolve(&Value::String("thickness(4, 2)".to_string()))
+                .unwrap()
+        );
+        assert_eq!(properties["font_size"], Value::Number(Number::new(12.0)));
+        assert_eq!(properties["height"], Value::Number(Number::new(32.0)));
+        assert_eq!(
+            properties["background"],
+            Value::String("#000000".to_string())
+        );
+
+        assert_eq!(Density::from("touch"), Density::Touch);
+        assert_eq!(Density::Compact.resource_key(), "DENSITY_COMPACT");
+    }
+}
@@ -1,9 +1,21 @@
+pub use self::density::*;
+pub use self::matcher::*;
+pub use self::scope::*;
 pub use self::selector::*;
 pub use self::style::*;
 pub use self::theme::*;
+pub use self::transition::*;
+pub use self::validator::*;
+pub use self::watcher::*;
 
 pub mod config;
+mod density;
+mod matcher;
 pub mod prelude;
+mod scope;
 mod selector;
 mod style;
 mod theme;
+mod transition;
+mod validator;
+mod watcher;
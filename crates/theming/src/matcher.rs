@@ -0,0 +1,207 @@
+use crate::Selector;
+
+/// The specificity of a style rule. Rules with a higher specificity override the properties of rules with a lower
+/// specificity. It is compared by the count of ids, then by the count of classes and states and then by the count of
+/// widget types.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Specificity {
+    pub ids: usize,
+    pub classes: usize,
+    pub types: usize,
+}
+
+impl Specificity {
+    /// Returns the specificity with the given count of additional states.
+    pub fn with_states(mut self, states: usize) -> Self {
+        self.classes += states;
+        self
+    }
+}
+
+// Matches a single widget, e.g. `TextBlock#title.large`.
+#[derive(Debug, Clone, Default, PartialEq)]
+struct CompoundSelector {
+    name: Option<String>,
+    id: Option<String>,
+    classes: Vec<String>,
+}
+
+impl CompoundSelector {
+    fn parse(s: &str) -> Self {
+        let mut compound = CompoundSelector::default();
+        let mut prefix = None;
+        let mut current = String::new();
+
+        for c in s.chars().chain(std::iter::once('.')) {
+            if c != '.' && c != '#' {
+                current.push(c);
+                continue;
+            }
+
+            if !current.is_empty() {
+                let part = std::mem::take(&mut current);
+
+                match prefix {
+                    Some('#') => compound.id = Some(part),
+                    Some(_) => compound.classes.push(part),
+                    // widget types start with an upper case letter, style classes are written in snake case
+                    None if part.starts_with(char::is_uppercase) => compound.name = Some(part),
+                    None => compound.classes.push(part),
+                }
+            }
+
+            prefix = Some(c);
+        }
+
+        compound
+    }
+
+    fn matches(&self, selector: &Selector) -> bool {
+        if self.name.is_some() && self.name != selector.name {
+            return false;
+        }
+
+        if self.id.is_some() && self.id != selector.id {
+            return false;
+        }
+
+        self.classes.iter().all(|class| selector.has_class(class))
+    }
+}
+
+/// Matches widgets by the key of a style of a theme. A key is a list of compound selectors separated by whitespace, the
+/// last one matches the widget and the previous ones match its ancestors in the given order. A compound selector
+/// combines a widget type (e.g. `TextBlock`), an id (e.g. `#title`) and style classes (e.g. `button` or `.large`).
+///
+/// # Examples
+///
+/// * `button`: widgets with the style class `button`
+/// * `button.primary`: widgets with the style classes `button` and `primary`
+/// * `TextBlock`: widgets with the name `TextBlock`
+/// * `#title`: the widget with the id `title`
+/// * `Toolbar TextBlock`: text blocks inside of a widget with the name `Toolbar`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SelectorMatcher {
+    compounds: Vec<CompoundSelector>,
+    specificity: Specificity,
+}
+
+impl SelectorMatcher {
+    /// Parses the given style key.
+    pub fn parse(key: &str) -> Self {
+        let compounds: Vec<_> = key
+            .split_whitespace()
+            .map(CompoundSelector::parse)
+            .collect();
+        let mut specificity = Specificity::default();
+
+        for compound in &compounds {
+            specificity.ids += compound.id.iter().count();
+            specificity.classes += compound.classes.len();
+            specificity.types += compound.name.iter().count();
+        }
+
+        SelectorMatcher {
+            compounds,
+            specificity,
+        }
+    }
+
+    /// Returns the specificity of the rule.
+    pub fn specificity(&self) -> Specificity {
+        self.specificity
+    }
+
+    /// Checks if the rule depends on the ancestors of the widget.
+    pub fn has_ancestors(&self) -> bool {
+        self.compounds.len() > 1
+    }
+
+    /// Checks if the rule matches the widget with the given selector. The ancestors are ordered from the parent to the
+    /// root.
+    pub fn matches(&self, selector: &Selector, ancestors: &[Selector]) -> bool {
+        let (last, compounds) = match self.compounds.split_last() {
+            Some(compounds) => compounds,
+            None => return false,
+        };
+
+        if !last.matches(selector) {
+            return false;
+        }
+
+        let mut ancestors = ancestors.iter();
+
+        compounds
+            .iter()
+            .rev()
+            .all(|compound| ancestors.any(|ancestor| compound.matches(ancestor)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selector(style: &str, name: &str, id: Option<&str>) -> Selector {
+        let mut selector = Selector::new(style);
+        selector.name = Some(name.to_string());
+        selector.id = id.map(str::to_string);
+        selector
+    }
+
+    #[test]
+    fn test_specificity() {
+        assert_eq!(
+            SelectorMatcher::parse("Toolbar TextBlock#title.large.bold").specificity(),
+            Specificity {
+                ids: 1,
+                classes: 2,
+                types: 2
+            }
+        );
+        assert!(
+            SelectorMatcher::parse("#title").specificity()
+                > SelectorMatcher::parse("button.primary.large").specificity()
+        );
+        assert!(
+            SelectorMatcher::parse("button")
+                .specificity()
+                .with_states(1)
+                > SelectorMatcher::parse("Button.primary").specificity()
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let button = selector("button primary", "Button", Some("ok"));
+
+        assert!(SelectorMatcher::parse("button").matches(&button, &[]));
+        assert!(SelectorMatcher::parse(".primary").matches(&button, &[]));
+        assert!(SelectorMatcher::parse("Button.button.primary#ok").matches(&button, &[]));
+        assert!(!SelectorMatcher::parse("button.large").matches(&button, &[]));
+        assert!(!SelectorMatcher::parse("TextBlock").matches(&button, &[]));
+        assert!(!SelectorMatcher::parse("#cancel").matches(&button, &[]));
+    }
+
+    #[test]
+    fn test_descendants() {
+        let text_block = selector("text_block", "TextBlock", None);
+        let ancestors = vec![
+            selector("stack", "Stack", None),
+            selector("toolbar", "Toolbar", Some("main_toolbar")),
+            selector("window", "Window", None),
+        ];
+
+        assert!(SelectorMatcher::parse("Toolbar TextBlock").matches(&text_block, &ancestors));
+        assert!(SelectorMatcher::parse("Window #main_toolbar text_block")
+            .matches(&text_block, &ancestors));
+        assert!(SelectorMatcher::parse("Window Toolbar Stack TextBlock")
+            .matches(&text_block, &ancestors));
+
+        // ancestors have to be in the given order
+        assert!(
+            !SelectorMatcher::parse("Toolbar Window TextBlock").matches(&text_block, &ancestors)
+        );
+        assert!(!SelectorMatcher::parse("Toolbar TextBlock").matches(&text_block, &[]));
+    }
+}
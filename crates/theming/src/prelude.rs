@@ -1 +1,3 @@
-pub use crate::{Selector, Style, Theme};
+pub use crate::{
+    Density, Selector, Style, Theme, ThemeScope, ThemeValidator, ThemeWatcher, Transition,
+};
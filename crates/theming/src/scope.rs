@@ -0,0 +1,193 @@
+use std::{collections::HashMap, rc::Rc};
+
+use ron::Value;
+
+use crate::{Selector, Theme, Transition};
+
+/// A theme that is attached to a widget to style the widget and its descendants, e.g. a dark sidebar inside of a
+/// light window. Scopes could be nested, widgets that are not styled by a scope fall back to the scope of the parent
+/// and at last to the theme of the window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeScope {
+    theme: Rc<Theme>,
+    overlay: bool,
+}
+
+impl ThemeScope {
+    /// Creates a scope that styles the widgets with the given theme. Only widgets the theme has no matching style for
+    /// are styled by the parent scope.
+    pub fn theme(theme: Theme) -> Self {
+        ThemeScope {
+            theme: Rc::new(theme),
+            overlay: false,
+        }
+    }
+
+    /// Creates a scope whose properties override the properties of the parent scope, e.g. a theme that only defines
+    /// larger fonts and stronger borders for a high contrast preview.
+    pub fn overlay(theme: Theme) -> Self {
+        ThemeScope {
+            theme: Rc::new(theme),
+            overlay: true,
+        }
+    }
+
+    /// Returns the theme of the scope.
+    pub fn get(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Checks if the scope is an overlay.
+    pub fn is_overlay(&self) -> bool {
+        self.overlay
+    }
+}
+
+impl From<Theme> for ThemeScope {
+    fn from(theme: Theme) -> Self {
+        ThemeScope::theme(theme)
+    }
+}
+
+/// Resolves the styles of a widget by the theme scopes of its ancestors, ordered from the outermost to the innermost
+/// scope, and the theme of the window.
+pub struct ScopedTheme<'a> {
+    theme: &'a Theme,
+    scopes: &'a [ThemeScope],
+}
+
+impl<'a> ScopedTheme<'a> {
+    /// Creates a new scoped theme.
+    pub fn new(theme: &'a Theme, scopes: &'a [ThemeScope]) -> Self {
+        ScopedTheme { theme, scopes }
+    }
+
+    /// Checks if one of the themes has styles that match widgets by their ancestors.
+    pub fn has_descendant_rules(&self) -> bool {
+        self.theme.has_descendant_rules()
+            || self
+                .scopes
+                .iter()
+                .any(|scope| scope.get().has_descendant_rules())
+    }
+
+    /// Returns the properties of the given selector. See `Theme::properties_in`. The properties of all scopes are
+    /// scaled by the density of the window theme.
+    pub fn properties_in(
+        &self,
+        selector: &Selector,
+        ancestors: &[Selector],
+    ) -> Option<HashMap<String, Value>> {
+        let mut properties =
+            self.resolve(|theme| theme.unscaled_properties_in(selector, ancestors))?;
+        self.theme.density_scale().scale(&mut properties);
+
+        Some(properties)
+    }
+
+    /// Returns the transitions of the given selector. See `Theme::transitions_in`.
+    pub fn transitions_in(
+        &self,
+        selector: &Selector,
+        ancestors: &[Selector],
+    ) -> Option<HashMap<String, Transition>> {
+        self.resolve(|theme| theme.transitions_in(selector, ancestors))
+    }
+
+    // Takes the values of the innermost theme that matches the selector and applies the values of the overlays inside
+    // of it.
+    fn resolve<T, F>(&self, read: F) -> Option<HashMap<String, T>>
+    where
+        F: Fn(&Theme) -> Option<HashMap<String, T>>,
+    {
+        let mut overlays = vec![];
+        let mut values = None;
+
+        for scope in self.scopes.iter().rev() {
+            let scope_values = read(scope.get());
+
+            if scope.is_overlay() {
+                overlays.extend(scope_values);
+            } else if scope_values.is_some() {
+                values = scope_values;
+                break;
+            }
+        }
+
+        if values.is_none() {
+            values = read(self.theme);
+        }
+
+        if values.is_none() && overlays.is_empty() {
+            return None;
+        }
+
+        let mut values = values.unwrap_or_default();
+
+        // the innermost overlay is applied last
+        for overlay in overlays.into_iter().rev() {
+            values.extend(overlay);
+        }
+
+        Some(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::config::ThemeConfig;
+
+    fn theme(ron: &str) -> Theme {
+        Theme::from_config(ThemeConfig::from(ron))
+    }
+
+    fn background(theme: &ScopedTheme, selector: &Selector) -> String {
+        theme.properties_in(selector, &[]).unwrap()["background"]
+            .clone()
+            .into_rust::<String>()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_scopes() {
+        let window = theme(
+            r#"Theme ( styles: {
+                "button": ( properties: { "background": "white", "foreground": "black" } ),
+                "text_box": ( properties: { "background": "white" } ),
+            } )"#,
+        );
+        let scopes = vec![
+            ThemeScope::theme(theme(
+                r#"Theme ( styles: { "button": ( properties: { "background": "black" } ) } )"#,
+            )),
+            ThemeScope::overlay(theme(
+                r#"Theme ( styles: { "text_box": ( properties: { "border_width": 2 } ) } )"#,
+            )),
+        ];
+
+        let button = Selector::new("button");
+        let text_box = Selector::new("text_box");
+
+        // a theme scope replaces the style of the window theme
+        let scoped = ScopedTheme::new(&window, &scopes[..1]);
+        let properties = scoped.properties_in(&button, &[]).unwrap();
+        assert_eq!(background(&scoped, &button), "black");
+        assert!(!properties.contains_key("foreground"));
+
+        // widgets the scope has no style for fall back to the window theme
+        assert_eq!(background(&scoped, &text_box), "white");
+
+        // an overlay extends the style of the parent scope
+        let scoped = ScopedTheme::new(&window, &scopes);
+        let properties = scoped.properties_in(&text_box, &[]).unwrap();
+        assert_eq!(background(&scoped, &text_box), "white");
+        assert_eq!(properties["border_width"], Value::Number(2.into()));
+        assert_eq!(background(&scoped, &button), "black");
+
+        assert!(scoped
+            .properties_in(&Selector::new("check_box"), &[])
+            .is_none());
+    }
+}
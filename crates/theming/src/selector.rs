@@ -1,13 +1,22 @@
 use std::fmt;
 
+/// Separates the states of a combined state rule of a style, e.g. `checked+hover`.
+pub static STATE_SEPARATOR: char = '+';
+
 /// The selector is used to read a property value from the `Theme`.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Selector {
-    /// Represents the key of a style.
+    /// Represents the key of a style. Several style classes are separated by whitespace, e.g. `"button primary"`.
     pub style: Option<String>,
 
-    /// Used to reference the state property list of the given style.
-    pub state: Option<String>,
+    /// The id of the widget, used to match id rules like `#title`.
+    pub id: Option<String>,
+
+    /// The name of the widget type, used to match type rules like `TextBlock`.
+    pub name: Option<String>,
+
+    /// The active states in the order they are added. Used to reference the state property lists of the given style.
+    states: Vec<String>,
 
     /// Check if the selector is dirty.
     dirty: bool,
@@ -18,23 +27,68 @@ impl Selector {
     pub fn new(style: impl Into<String>) -> Self {
         Selector {
             style: Some(style.into()),
-            state: None,
+            id: None,
+            name: None,
+            states: vec![],
             dirty: true,
         }
     }
 
-    /// Set the current state of the selector.
+    /// Replaces all active states of the selector with the given state.
     pub fn set_state(&mut self, state: impl Into<String>) {
-        self.state = Some(state.into());
+        self.states.clear();
+        self.states.push(state.into());
         self.dirty = true;
     }
 
-    /// Clears the current state and reset to default.
+    /// Adds a state to the active states. The selector is marked as dirty if the state was not active before.
+    pub fn add_state(&mut self, state: impl Into<String>) {
+        let state = state.into();
+
+        if !self.has_state(&state) {
+            self.states.push(state);
+            self.dirty = true;
+        }
+    }
+
+    /// Removes a state from the active states. The selector is marked as dirty if the state was active before.
+    pub fn remove_state(&mut self, state: &str) {
+        if let Some(index) = self.states.iter().position(|s| s == state) {
+            self.states.remove(index);
+            self.dirty = true;
+        }
+    }
+
+    /// Adds the state if `active` is `true`, otherwise the state is removed.
+    pub fn set_state_active(&mut self, state: &str, active: bool) {
+        if active {
+            self.add_state(state);
+        } else {
+            self.remove_state(state);
+        }
+    }
+
+    /// Clears all states and reset to default.
     pub fn clear_state(&mut self) {
-        self.state = None;
+        self.states.clear();
         self.dirty = true;
     }
 
+    /// Returns the active states in the order they are added.
+    pub fn states(&self) -> &[String] {
+        &self.states
+    }
+
+    /// Returns the style classes of the selector.
+    pub fn classes(&self) -> impl Iterator<Item = &str> {
+        self.style.iter().flat_map(|style| style.split_whitespace())
+    }
+
+    /// Check if the selector has the given style class.
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes().any(|c| c == class)
+    }
+
     /// Gets the dirty flag.
     pub fn dirty(&self) -> bool {
         self.dirty
@@ -47,13 +101,22 @@ impl Selector {
 
     /// Check if the selector has the given state.
     pub fn has_state(&self, state: &str) -> bool {
-        if let Some(st) = &self.state {
-            if st.as_str() == state {
-                return true;
-            }
+        self.states.iter().any(|s| s == state)
+    }
+
+    /// Returns the precedence of a state rule like `checked+hover` if all of its states are active, otherwise `None`.
+    /// Rules with more states have a higher precedence. Rules with the same count of states are ordered by their most
+    /// recently added state.
+    pub fn state_precedence(&self, rule: &str) -> Option<(usize, usize)> {
+        let mut count = 0;
+        let mut latest = 0;
+
+        for state in rule.split(STATE_SEPARATOR) {
+            latest = latest.max(self.states.iter().position(|s| s == state.trim())?);
+            count += 1;
         }
 
-        false
+        Some((count, latest))
     }
 }
 
@@ -77,3 +140,43 @@ impl From<String> for Selector {
         Selector::new(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_states() {
+        let mut selector = Selector::new("check_box");
+        selector.set_dirty(false);
+
+        selector.add_state("selected");
+        selector.add_state("hover");
+        assert!(selector.dirty());
+        assert_eq!(selector.states(), &["selected", "hover"]);
+
+        selector.set_dirty(false);
+        selector.add_state("hover");
+        selector.remove_state("pressed");
+        assert!(!selector.dirty());
+
+        selector.remove_state("selected");
+        assert!(selector.dirty());
+        assert!(!selector.has_state("selected"));
+
+        selector.set_state("disabled");
+        assert_eq!(selector.states(), &["disabled"]);
+    }
+
+    #[test]
+    fn test_state_precedence() {
+        let mut selector = Selector::new("check_box");
+        selector.add_state("selected");
+        selector.add_state("hover");
+
+        assert_eq!(selector.state_precedence("selected"), Some((1, 0)));
+        assert_eq!(selector.state_precedence("hover"), Some((1, 1)));
+        assert_eq!(selector.state_precedence("hover+selected"), Some((2, 1)));
+        assert_eq!(selector.state_precedence("selected + pressed"), None);
+    }
+}
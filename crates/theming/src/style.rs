@@ -2,8 +2,11 @@ use std::collections::HashMap;
 
 use ron::Value;
 
+use crate::Transition;
+
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Style {
     pub properties: HashMap<String, Value>,
     pub states: HashMap<String, HashMap<String, Value>>,
+    pub transitions: HashMap<String, Transition>,
 }
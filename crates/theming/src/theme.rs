@@ -3,65 +3,202 @@ use std::collections::HashMap;
 use ron::Value;
 
 use crate::{
-    config::{ThemeConfig, RESOURCE_KEY},
-    Selector, Style,
+    config::{font_properties, ThemeConfig, FONT_PROPERTY},
+    Density, DensityScale, Selector, SelectorMatcher, Style, Transition, STATE_SEPARATOR,
 };
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Theme {
     styles: HashMap<String, Style>,
+    rules: Vec<(SelectorMatcher, String)>,
+    density: Density,
+    density_scales: HashMap<Density, DensityScale>,
 }
 
 impl Theme {
     pub fn from_config(theme: ThemeConfig) -> Self {
         let mut styles = HashMap::new();
+        let mut rules = vec![];
 
         for style_key in theme.styles.keys() {
+            rules.push((SelectorMatcher::parse(style_key), style_key.clone()));
+
             let mut properties = HashMap::new();
             Theme::read_properties(style_key, &theme, &mut properties);
 
             let mut states = HashMap::new();
+            Theme::read_states(style_key, &theme, &mut states);
 
-            let base_key = theme.styles.get(style_key).unwrap().base.clone();
+            let mut transitions = HashMap::new();
+            Theme::read_transitions(style_key, &theme, &mut transitions);
 
-            if let Some(base) = theme.styles.get(&base_key) {
-                for state_key in base.states.keys() {
-                    let mut state = HashMap::new();
-                    Theme::read_states(&base_key, state_key, &theme, &mut state);
-                    states.insert(state_key.clone(), state);
-                }
-            }
+            styles.insert(
+                style_key.clone(),
+                Style {
+                    properties,
+                    states,
+                    transitions,
+                },
+            );
+        }
 
-            for state_key in theme.styles.get(style_key).unwrap().states.keys() {
-                let mut state = HashMap::new();
-                Theme::read_states(style_key, state_key, &theme, &mut state);
-                states.insert(state_key.clone(), state);
+        // the order of rules with the same specificity does not depend on the order of the hash map
+        rules.sort_by(|a, b| (a.0.specificity(), &a.1).cmp(&(b.0.specificity(), &b.1)));
+
+        let mut density_scales = HashMap::new();
+
+        for density in &[Density::Compact, Density::Normal, Density::Touch] {
+            if let Ok(value) = theme.resource(&density.resource_key()) {
+                density_scales.insert(*density, DensityScale::from_value(&value));
             }
+        }
 
-            styles.insert(style_key.clone(), Style { properties, states });
+        Theme {
+            styles,
+            rules,
+            density: Density::default(),
+            density_scales,
         }
+    }
+
+    /// Builder method that sets the density the properties of the theme are scaled by.
+    pub fn with_density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Sets the density the properties of the theme are scaled by, e.g. `Density::Touch` for kiosks.
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
+    }
+
+    /// Gets the density the properties of the theme are scaled by.
+    pub fn density(&self) -> Density {
+        self.density
+    }
 
-        Theme { styles }
+    /// Returns the scale of the current density. The scale is defined by the resource of the density, e.g.
+    /// `DENSITY_COMPACT`. Densities without resource do not scale the properties.
+    pub fn density_scale(&self) -> DensityScale {
+        self.density_scales
+            .get(&self.density)
+            .copied()
+            .unwrap_or_default()
     }
 
     pub fn style(&self, key: &str) -> Option<&Style> {
         self.styles.get(key)
     }
 
-    pub fn properties<'a>(&'a self, selector: &Selector) -> Option<&'a HashMap<String, Value>> {
+    /// Returns the properties of the style of the given selector. See `properties_in`.
+    pub fn properties(&self, selector: &Selector) -> Option<HashMap<String, Value>> {
+        self.properties_in(selector, &[])
+    }
+
+    /// Returns the properties of the styles that match the given selector and its ancestors, ordered from the parent
+    /// to the root. The properties of a style and of its state rules that match the active states of the selector
+    /// override the properties with a lower specificity. Each state of a state rule counts like a style class.
+    /// Sizes and spacings are scaled by the density of the theme.
+    pub fn properties_in(
+        &self,
+        selector: &Selector,
+        ancestors: &[Selector],
+    ) -> Option<HashMap<String, Value>> {
+        let mut properties = self.unscaled_properties_in(selector, ancestors)?;
+        self.density_scale().scale(&mut properties);
+
+        Some(properties)
+    }
+
+    // Returns the properties without the scale of the density.
+    pub(crate) fn unscaled_properties_in(
+        &self,
+        selector: &Selector,
+        ancestors: &[Selector],
+    ) -> Option<HashMap<String, Value>> {
         if !selector.dirty() {
             return None;
         }
 
-        if let Some(style) = &selector.style {
-            if let Some(state) = &selector.state {
-                return self.styles.get(style)?.states.get(state);
+        let mut entries = vec![];
+
+        for (style_key, style) in self.matching_styles(selector, ancestors) {
+            let specificity = style_key.specificity();
+
+            entries.push(((specificity, 0), "", &style.properties));
+
+            for (rule, properties) in &style.states {
+                if let Some((states, latest)) = selector.state_precedence(rule) {
+                    entries.push(((specificity.with_states(states), latest), rule, properties));
+                }
+            }
+        }
+
+        if entries.is_empty() {
+            return None;
+        }
+
+        // stable sort keeps the order of rules with the same precedence
+        entries.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        let mut properties = HashMap::new();
+
+        for (_, _, entry) in entries {
+            for (key, value) in entry {
+                properties.insert(key.clone(), value.clone());
             }
+        }
+
+        Some(properties)
+    }
 
-            return Some(&self.styles.get(style)?.properties);
+    /// Returns the transitions of the styles of the given selector. See `transitions_in`.
+    pub fn transitions(&self, selector: &Selector) -> Option<HashMap<String, Transition>> {
+        self.transitions_in(selector, &[])
+    }
+
+    /// Returns the transitions of the styles that match the given selector and its ancestors. Transitions of styles
+    /// with a higher specificity override the transitions of the same properties with a lower specificity.
+    pub fn transitions_in(
+        &self,
+        selector: &Selector,
+        ancestors: &[Selector],
+    ) -> Option<HashMap<String, Transition>> {
+        let mut transitions = HashMap::new();
+        let mut matched = false;
+
+        for (_, style) in self.matching_styles(selector, ancestors) {
+            matched = true;
+
+            for (key, transition) in &style.transitions {
+                transitions.insert(key.clone(), transition.clone());
+            }
         }
 
-        None
+        if !matched {
+            return None;
+        }
+
+        Some(transitions)
+    }
+
+    /// Checks if the theme has styles that match widgets by their ancestors, e.g. `Toolbar TextBlock`.
+    pub fn has_descendant_rules(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|(matcher, _)| matcher.has_ancestors())
+    }
+
+    // Returns the matching styles ordered by their specificity.
+    fn matching_styles<'a>(
+        &'a self,
+        selector: &'a Selector,
+        ancestors: &'a [Selector],
+    ) -> impl Iterator<Item = (&'a SelectorMatcher, &'a Style)> + 'a {
+        self.rules
+            .iter()
+            .filter(move |(matcher, _)| matcher.matches(selector, ancestors))
+            .filter_map(move |(matcher, key)| Some((matcher, self.styles.get(key)?)))
     }
 
     fn read_properties(key: &str, theme: &ThemeConfig, properties: &mut HashMap<String, Value>) {
@@ -71,32 +208,67 @@ impl Theme {
 
         if let Some(style) = theme.styles.get(key) {
             Theme::read_properties(&style.base, theme, properties);
+            Theme::read_property_map(&style.properties, theme, properties);
+        }
+    }
 
-            for (key, value) in &style.properties {
-                Theme::read_property(key, value, theme, properties);
+    fn read_transitions(
+        key: &str,
+        theme: &ThemeConfig,
+        transitions: &mut HashMap<String, Transition>,
+    ) {
+        if key.is_empty() {
+            return;
+        }
+
+        if let Some(style) = theme.styles.get(key) {
+            Theme::read_transitions(&style.base, theme, transitions);
+
+            for (key, transition) in &style.transitions {
+                transitions.insert(key.clone(), transition.clone());
             }
         }
     }
 
+    // Reads the state rules of the style and its base styles. A rule of the style extends the rule with the same
+    // states of its base style.
     fn read_states(
-        style_key: &str,
-        state_key: &str,
+        key: &str,
         theme: &ThemeConfig,
-        states: &mut HashMap<String, Value>,
+        states: &mut HashMap<String, HashMap<String, Value>>,
     ) {
-        if style_key.is_empty() || state_key.is_empty() {
+        if key.is_empty() {
             return;
         }
 
-        if let Some(style) = theme.styles.get(style_key) {
-            for (key, value) in &style.properties {
-                Theme::read_property(key, value, theme, states);
+        if let Some(style) = theme.styles.get(key) {
+            Theme::read_states(&style.base, theme, states);
+
+            for (rule, properties) in &style.states {
+                let rule: Vec<_> = rule.split(STATE_SEPARATOR).map(str::trim).collect();
+                let state = states
+                    .entry(rule.join(&STATE_SEPARATOR.to_string()))
+                    .or_default();
+
+                Theme::read_property_map(properties, theme, state);
             }
+        }
+    }
 
-            if let Some(state) = style.states.get(state_key) {
-                for (key, value) in state {
-                    Theme::read_property(key, value, theme, states);
-                }
+    // Reads the properties of a style or a state rule. A font descriptor is read first, so the `font_family` and
+    // `font_size` properties of the same style override it.
+    fn read_property_map(
+        properties: &HashMap<String, Value>,
+        theme: &ThemeConfig,
+        map: &mut HashMap<String, Value>,
+    ) {
+        if let Some(value) = properties.get(FONT_PROPERTY) {
+            Theme::read_property(FONT_PROPERTY, value, theme, map);
+        }
+
+        for (key, value) in properties {
+            if key != FONT_PROPERTY {
+                Theme::read_property(key, value, theme, map);
             }
         }
     }
@@ -107,16 +279,268 @@ impl Theme {
         theme: &ThemeConfig,
         map: &mut HashMap<String, Value>,
     ) {
-        if let Ok(value) = value.clone().into_rust::<String>() {
-            if value.starts_with(RESOURCE_KEY) {
-                if let Some(value) = theme.resources.get(&value.replace(RESOURCE_KEY, "")) {
-                    map.insert(key.to_string(), value.clone());
-                }
-            } else {
-                map.insert(key.to_string(), Value::String(value));
+        match theme.resolve(value) {
+            Ok(value) if key == FONT_PROPERTY => map.extend(font_properties(&value)),
+            Ok(value) => {
+                map.insert(key.to_string(), value);
             }
-        } else {
-            map.insert(key.to_string(), value.clone());
+            // invalid properties are skipped, they are reported by the `ThemeValidator`
+            Err(_) => {}
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transitions() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"Theme (
+                styles: {
+                    "button": (
+                        properties: { "background": "black" },
+                        transitions: {
+                            "background": (duration: 150, easing: "ease_out"),
+                            "foreground": (),
+                        },
+                    ),
+                    "button_primary": (
+                        base: "button",
+                        transitions: {
+                            "foreground": (duration: 100, delay: 50),
+                        },
+                    ),
+                },
+            )"#,
+        ));
+
+        let transitions = theme.transitions(&Selector::new("button")).unwrap();
+        assert_eq!(
+            transitions.get("background"),
+            Some(&Transition {
+                duration: 150.0,
+                delay: 0.0,
+                easing: String::from("ease_out"),
+            })
+        );
+        assert_eq!(transitions.get("foreground"), Some(&Transition::default()));
+
+        // transitions are inherited from the base style
+        let transitions = theme.transitions(&Selector::new("button_primary")).unwrap();
+        assert_eq!(transitions.get("background").unwrap().duration, 150.0);
+        assert_eq!(transitions.get("foreground").unwrap().delay, 50.0);
+
+        assert!(theme.transitions(&Selector::default()).is_none());
+    }
+
+    #[test]
+    fn test_state_rules() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"Theme (
+                styles: {
+                    "toggle_button": (
+                        properties: { "background": "black", "foreground": "white" },
+                        states: {
+                            "selected": { "background": "blue" },
+                            "hover": { "background": "gray", "foreground": "black" },
+                            "selected + hover": { "background": "navy" },
+                        },
+                    ),
+                    "toggle_button_primary": (
+                        base: "toggle_button",
+                        states: {
+                            "hover": { "foreground": "red" },
+                        },
+                    ),
+                },
+            )"#,
+        ));
+
+        let background = |selector: &Selector| {
+            theme.properties(selector).unwrap()["background"]
+                .clone()
+                .into_rust::<String>()
+                .unwrap()
+        };
+
+        let mut selector = Selector::new("toggle_button");
+        assert_eq!(background(&selector), "black");
+
+        selector.add_state("hover");
+        assert_eq!(background(&selector), "gray");
+
+        // the combined rule wins over the single states
+        selector.add_state("selected");
+        assert_eq!(background(&selector), "navy");
+        assert_eq!(
+            theme.properties(&selector).unwrap()["foreground"],
+            Value::String("black".to_string())
+        );
+
+        // the most recently added state wins between rules with the same count of states
+        selector.remove_state("hover");
+        selector.add_state("pressed");
+        assert_eq!(background(&selector), "blue");
+
+        // state rules are extended by the rules of the base style
+        let mut selector = Selector::new("toggle_button_primary");
+        selector.add_state("hover");
+        let properties = theme.properties(&selector).unwrap();
+        assert_eq!(properties["background"], Value::String("gray".to_string()));
+        assert_eq!(properties["foreground"], Value::String("red".to_string()));
+
+        selector.set_dirty(false);
+        assert!(theme.properties(&selector).is_none());
+    }
+
+    #[test]
+    fn test_selectors() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r##"Theme (
+                styles: {
+                    "button": (
+                        properties: { "background": "black", "foreground": "white", "height": 32 },
+                        states: { "pressed": { "background": "gray" } },
+                    ),
+                    "large": ( properties: { "height": 48 } ),
+                    "button.primary": ( properties: { "background": "blue" } ),
+                    "#ok": ( properties: { "foreground": "yellow" } ),
+                    "TextBlock": ( properties: { "foreground": "white" } ),
+                    "Toolbar TextBlock": ( properties: { "foreground": "silver" } ),
+                },
+            )"##,
+        ));
+
+        let string = |properties: &HashMap<String, Value>, key: &str| {
+            properties[key].clone().into_rust::<String>().unwrap()
+        };
+
+        let mut button = Selector::new("button primary large");
+        button.id = Some("ok".to_string());
+
+        let properties = theme.properties(&button).unwrap();
+        assert_eq!(string(&properties, "background"), "blue");
+        assert_eq!(string(&properties, "foreground"), "yellow");
+        assert_eq!(properties["height"], Value::Number(48.into()));
+
+        // a state counts like a class
+        button.add_state("pressed");
+        assert_eq!(
+            string(&theme.properties(&button).unwrap(), "background"),
+            "gray"
+        );
+
+        let mut text_block = Selector::default();
+        text_block.name = Some("TextBlock".to_string());
+        text_block.set_dirty(true);
+
+        let mut toolbar = Selector::new("toolbar");
+        toolbar.name = Some("Toolbar".to_string());
+
+        assert!(theme.has_descendant_rules());
+        assert_eq!(
+            string(&theme.properties(&text_block).unwrap(), "foreground"),
+            "white"
+        );
+        assert_eq!(
+            string(
+                &theme.properties_in(&text_block, &[toolbar]).unwrap(),
+                "foreground"
+            ),
+            "silver"
+        );
+    }
+
+    #[test]
+    fn test_resources() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r##"Theme (
+                styles: {
+                    "button": (
+                        properties: {
+                            "background": "$PRIMARY_HOVER",
+                            "font": "$HEADER_FONT",
+                            "padding": "thickness(8, 4)",
+                            "border_brush": "$MISSING",
+                        },
+                        states: {
+                            "pressed": { "background": "darken($PRIMARY, 20%)" },
+                        },
+                    ),
+                    "button_small": (
+                        base: "button",
+                        properties: { "font_size": 12 },
+                    ),
+                },
+                resources: {
+                    "PRIMARY": "#ff0000",
+                    "PRIMARY_HOVER": "lighten($PRIMARY, 20%)",
+                    "HEADER_FONT": (family: "Roboto", weight: "medium", size: 24),
+                },
+            )"##,
+        ));
+
+        let mut selector = Selector::new("button");
+        let properties = theme.properties(&selector).unwrap();
+        assert_eq!(
+            properties["background"],
+            Value::String("#ff6666".to_string())
+        );
+        assert_eq!(
+            properties["font_family"],
+            Value::String("Roboto-Medium".to_string())
+        );
+        assert_eq!(properties["font_size"], Value::Number(24.into()));
+        assert!(!properties.contains_key("font"));
+        assert!(!properties.contains_key("border_brush"));
+
+        selector.add_state("pressed");
+        assert_eq!(
+            theme.properties(&selector).unwrap()["background"],
+            Value::String("#990000".to_string())
+        );
+
+        // the explicit font size wins over the font descriptor of the base style
+        let properties = theme.properties(&Selector::new("button_small")).unwrap();
+        assert_eq!(properties["font_size"], Value::Number(12.into()));
+        assert_eq!(
+            properties["font_family"],
+            Value::String("Roboto-Medium".to_string())
+        );
+    }
+
+    #[test]
+    fn test_density() {
+        let theme = Theme::from_config(ThemeConfig::from(
+            r#"Theme (
+                styles: {
+                    "button": ( properties: { "padding": 8, "font_size": 12, "height": 36, "border_radius": 2 } ),
+                },
+                resources: {
+                    "DENSITY_TOUCH": (spacing: 1.5, font_size: 1.25, control_height: 1.5),
+                },
+            )"#,
+        ));
+
+        let number = |theme: &Theme, key: &str| {
+            theme.properties(&Selector::new("button")).unwrap()[key]
+                .clone()
+                .into_rust::<f64>()
+                .unwrap()
+        };
+
+        assert_eq!(number(&theme, "padding"), 8.0);
+
+        let theme = theme.with_density(Density::Touch);
+        assert_eq!(number(&theme, "padding"), 12.0);
+        assert_eq!(number(&theme, "font_size"), 15.0);
+        assert_eq!(number(&theme, "height"), 54.0);
+        assert_eq!(number(&theme, "border_radius"), 2.0);
+
+        // densities without scale keep the properties
+        let theme = theme.with_density(Density::Compact);
+        assert_eq!(number(&theme, "height"), 36.0);
+    }
+}
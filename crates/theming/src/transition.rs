@@ -0,0 +1,43 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Describes how a property of a style changes its value if the state of a widget changes.
+///
+/// # Example
+///
+/// ```ron
+/// transitions: {
+///     "background": (duration: 150, easing: "ease_out"),
+/// },
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Transition {
+    /// Duration of the transition in milliseconds.
+    #[serde(default = "default_duration")]
+    pub duration: f64,
+
+    /// Time in milliseconds the transition waits before it starts.
+    #[serde(default)]
+    pub delay: f64,
+
+    /// Name of the easing curve, e.g. `ease_in_out`.
+    #[serde(default = "default_easing")]
+    pub easing: String,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Transition {
+            duration: default_duration(),
+            delay: 0.0,
+            easing: default_easing(),
+        }
+    }
+}
+
+fn default_duration() -> f64 {
+    250.0
+}
+
+fn default_easing() -> String {
+    String::from("linear")
+}
@@ -0,0 +1,743 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, iter,
+};
+
+use ron::Value;
+
+use crate::{
+    config::{references, ThemeConfig, RESOURCE_KEY},
+    DENSITY_KEY_PREFIX, STATE_SEPARATOR,
+};
+
+/// The severity of a diagnostic of the theme validator.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Severity {
+    /// The theme does not work as expected, e.g. a property that references a missing resource is skipped.
+    Error,
+
+    /// The theme works but contains something that is probably a mistake.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// Describes a problem of a theme found by the theme validator.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticKind {
+    /// The theme source is not valid ron or does not describe a theme.
+    Syntax(String),
+
+    /// A property references a resource that is not defined by any of the theme sources.
+    UnresolvedResource { style: String, resource: String },
+
+    /// A style is based on a style that does not exist.
+    UnknownBase { style: String, base: String },
+
+    /// The base styles of the given styles are based on each other.
+    InheritanceCycle(Vec<String>),
+
+    /// A resource is not referenced by any style.
+    UnusedResource(String),
+
+    /// A state rule of a style contains a state that is not in the list of known states.
+    UnknownState { style: String, state: String },
+
+    /// The value of a property could not be read, e.g. because of an invalid expression.
+    InvalidProperty {
+        style: String,
+        property: String,
+        message: String,
+    },
+}
+
+impl DiagnosticKind {
+    /// Returns the severity of the diagnostic.
+    pub fn severity(&self) -> Severity {
+        match self {
+            DiagnosticKind::UnusedResource(_) | DiagnosticKind::UnknownState { .. } => {
+                Severity::Warning
+            }
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::Syntax(message) => write!(f, "{}", message),
+            DiagnosticKind::UnresolvedResource { style, resource } => write!(
+                f,
+                "resource \"{}\" of style \"{}\" is not defined",
+                resource, style
+            ),
+            DiagnosticKind::UnknownBase { style, base } => write!(
+                f,
+                "base style \"{}\" of style \"{}\" does not exist",
+                base, style
+            ),
+            DiagnosticKind::InheritanceCycle(styles) => write!(
+                f,
+                "base styles form a cycle: {} -> {}",
+                styles.join(" -> "),
+                styles[0]
+            ),
+            DiagnosticKind::UnusedResource(resource) => {
+                write!(f, "resource \"{}\" is never used", resource)
+            }
+            DiagnosticKind::UnknownState { style, state } => {
+                write!(f, "unknown state \"{}\" in style \"{}\"", state, style)
+            }
+            DiagnosticKind::InvalidProperty {
+                style,
+                property,
+                message,
+            } => write!(
+                f,
+                "property \"{}\" of style \"{}\" could not be read: {}",
+                property, style, message
+            ),
+        }
+    }
+}
+
+/// The position of a diagnostic in a theme source. Line and column start with 1.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A problem found by the theme validator.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// Name of the theme source the problem is found in.
+    pub source: String,
+
+    /// Position of the problem in the theme source.
+    pub position: Option<Position>,
+
+    pub kind: DiagnosticKind,
+}
+
+impl Diagnostic {
+    /// Returns the severity of the diagnostic.
+    pub fn severity(&self) -> Severity {
+        self.kind.severity()
+    }
+
+    /// Checks if the diagnostic is an error.
+    pub fn is_error(&self) -> bool {
+        self.severity() == Severity::Error
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.source)?;
+
+        if let Some(position) = self.position {
+            write!(f, ":{}:{}", position.line, position.column)?;
+        }
+
+        write!(f, ": {}: {}", self.severity(), self.kind)
+    }
+}
+
+/// Validates themes that are combined of one or more ron sources, like the sources of a theme that is extended by
+/// color and font resources. Sources added later override styles and resources of the previous ones.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let diagnostics = ThemeValidator::new()
+///     .source("dark.ron", DARK_THEME_RON)
+///     .source("colors.ron", COLORS_RON)
+///     .validate();
+///
+/// for diagnostic in &diagnostics {
+///     println!("{}", diagnostic);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ThemeValidator {
+    sources: Vec<(String, String)>,
+    known_states: Option<HashSet<String>>,
+}
+
+impl ThemeValidator {
+    /// Creates a new validator without sources.
+    pub fn new() -> Self {
+        ThemeValidator::default()
+    }
+
+    /// Adds a theme source with the name that is used in the diagnostics.
+    pub fn source(mut self, name: impl Into<String>, ron: impl Into<String>) -> Self {
+        self.sources.push((name.into(), ron.into()));
+        self
+    }
+
+    /// Sets the states the widgets could have. If it is set, states of state rules that are not in the list are
+    /// reported as warnings to find misspelled states.
+    pub fn known_states(mut self, states: &[&str]) -> Self {
+        self.known_states = Some(states.iter().map(|state| state.to_string()).collect());
+        self
+    }
+
+    /// Validates the sources and returns the found problems, errors first.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        let mut theme = ThemeConfig::default();
+        let mut scans = vec![];
+
+        for (name, ron) in &self.sources {
+            match ron::de::from_str::<ThemeConfig>(ron) {
+                Ok(config) => {
+                    theme = theme.extend(config);
+                    scans.push((name.as_str(), Scan::new(ron)));
+                }
+                Err(error) => diagnostics.push(Diagnostic {
+                    source: name.clone(),
+                    position: if error.position.line > 0 {
+                        Some(Position {
+                            line: error.position.line,
+                            column: error.position.col,
+                        })
+                    } else {
+                        None
+                    },
+                    kind: DiagnosticKind::Syntax(error.code.to_string()),
+                }),
+            }
+        }
+
+        let mut used = HashSet::new();
+
+        for (name, scan) in &scans {
+            used.extend(scan.nested.iter().map(String::as_str));
+
+            for (style, resource, position) in &scan.references {
+                used.insert(resource.as_str());
+
+                if !theme.resources.contains_key(resource) {
+                    diagnostics.push(Diagnostic {
+                        source: name.to_string(),
+                        position: Some(*position),
+                        kind: DiagnosticKind::UnresolvedResource {
+                            style: style.clone(),
+                            resource: resource.clone(),
+                        },
+                    });
+                }
+            }
+
+            if let Some(known_states) = &self.known_states {
+                for (style, rule, position) in &scan.states {
+                    for state in rule.split(STATE_SEPARATOR).map(str::trim) {
+                        if !known_states.contains(state) {
+                            diagnostics.push(Diagnostic {
+                                source: name.to_string(),
+                                position: Some(*position),
+                                kind: DiagnosticKind::UnknownState {
+                                    style: style.clone(),
+                                    state: state.to_string(),
+                                },
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        // a style or resource is reported at its definition in the last source, which is the one that is used
+        let locate = |f: &dyn Fn(&Scan) -> Option<Position>| {
+            scans
+                .iter()
+                .rev()
+                .find_map(|(name, scan)| Some((name.to_string(), Some(f(scan)?))))
+                .unwrap_or_default()
+        };
+
+        let mut style_keys: Vec<_> = theme.styles.keys().collect();
+        style_keys.sort();
+
+        for key in &style_keys {
+            let base = &theme.styles[*key].base;
+
+            if !base.is_empty() && !theme.styles.contains_key(base) {
+                let (source, position) = locate(&|scan| scan.bases.get(*key).copied());
+
+                diagnostics.push(Diagnostic {
+                    source,
+                    position,
+                    kind: DiagnosticKind::UnknownBase {
+                        style: key.to_string(),
+                        base: base.clone(),
+                    },
+                });
+            }
+        }
+
+        for cycle in inheritance_cycles(&theme, &style_keys) {
+            let (source, position) = locate(&|scan| scan.bases.get(&cycle[0]).copied());
+
+            diagnostics.push(Diagnostic {
+                source,
+                position,
+                kind: DiagnosticKind::InheritanceCycle(cycle),
+            });
+        }
+
+        for key in &style_keys {
+            let style = &theme.styles[*key];
+            let mut rules: Vec<_> = style.states.iter().collect();
+            rules.sort_by_key(|(rule, _)| *rule);
+
+            for properties in iter::once(&style.properties).chain(rules.into_iter().map(|(_, p)| p))
+            {
+                let mut names: Vec<_> = properties.keys().collect();
+                names.sort();
+
+                for name in names {
+                    let value = &properties[name];
+
+                    // references of missing resources are already reported
+                    if value_references(value)
+                        .iter()
+                        .any(|resource| !theme.resources.contains_key(resource))
+                    {
+                        continue;
+                    }
+
+                    if let Err(message) = theme.resolve(value) {
+                        let (source, position) = locate(&|scan| scan.styles.get(*key).copied());
+
+                        diagnostics.push(Diagnostic {
+                            source,
+                            position,
+                            kind: DiagnosticKind::InvalidProperty {
+                                style: key.to_string(),
+                                property: name.clone(),
+                                message,
+                            },
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut resources: Vec<_> = theme
+            .resources
+            .keys()
+            // the scales of the densities are read by the theme
+            .filter(|resource| {
+                !used.contains(resource.as_str()) && !resource.starts_with(DENSITY_KEY_PREFIX)
+            })
+            .collect();
+        resources.sort();
+
+        for resource in resources {
+            let (source, position) = locate(&|scan| scan.resources.get(resource).copied());
+
+            diagnostics.push(Diagnostic {
+                source,
+                position,
+                kind: DiagnosticKind::UnusedResource(resource.clone()),
+            });
+        }
+
+        // stable sort keeps the order of the sources and positions
+        diagnostics.sort_by_key(|diagnostic| !diagnostic.is_error());
+
+        diagnostics
+    }
+}
+
+/// Validates a single theme source. See `ThemeValidator`.
+pub fn validate_theme(ron: &str) -> Vec<Diagnostic> {
+    ThemeValidator::new().source("theme", ron).validate()
+}
+
+// Returns the cycles of base styles, each cycle starts with its smallest style key.
+fn inheritance_cycles(theme: &ThemeConfig, style_keys: &[&String]) -> Vec<Vec<String>> {
+    let mut cycles = vec![];
+    let mut checked = HashSet::new();
+
+    for key in style_keys {
+        let mut chain: Vec<&String> = vec![];
+        let mut current = *key;
+
+        while !checked.contains(current) {
+            if let Some(index) = chain.iter().position(|style| *style == current) {
+                let mut cycle: Vec<String> = chain[index..].iter().map(|s| s.to_string()).collect();
+                let first = (0..cycle.len()).min_by_key(|i| &cycle[*i]).unwrap_or(0);
+                cycle.rotate_left(first);
+                cycles.push(cycle);
+                break;
+            }
+
+            chain.push(current);
+
+            match theme.styles.get(current) {
+                Some(style) if !style.base.is_empty() => current = &style.base,
+                _ => break,
+            }
+        }
+
+        checked.extend(chain);
+    }
+
+    cycles
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Punct(char),
+}
+
+// Positions of the definitions and references of a theme source. The parsed theme config does not know them.
+#[derive(Default)]
+struct Scan {
+    resources: HashMap<String, Position>,
+    styles: HashMap<String, Position>,
+    bases: HashMap<String, Position>,
+    states: Vec<(String, String, Position)>,
+    references: Vec<(String, String, Position)>,
+    // resources that are referenced by other resources
+    nested: Vec<String>,
+}
+
+impl Scan {
+    fn new(ron: &str) -> Self {
+        let tokens = tokenize(ron);
+        let mut scan = Scan::default();
+
+        // the field of the theme struct or the key of the map each open bracket belongs to
+        let mut fields: Vec<Option<&Token>> = vec![];
+        let mut style = String::new();
+
+        for (i, (token, position)) in tokens.iter().enumerate() {
+            let next = tokens.get(i + 1).map(|(token, _)| token);
+            let is_key = next == Some(&Token::Punct(':'));
+            let field = fields.last().copied().flatten();
+
+            match token {
+                Token::Punct('(') | Token::Punct('{') | Token::Punct('[') => {
+                    let key = if i >= 2 && tokens[i - 1].0 == Token::Punct(':') {
+                        Some(&tokens[i - 2].0)
+                    } else {
+                        None
+                    };
+
+                    fields.push(key);
+                }
+                Token::Punct(')') | Token::Punct('}') | Token::Punct(']') => {
+                    fields.pop();
+                }
+                Token::Str(key) if is_key && is_field(field, "styles") => {
+                    style = key.clone();
+                    scan.styles.insert(key.clone(), *position);
+                }
+                Token::Str(key) if is_key && is_field(field, "resources") => {
+                    scan.resources.insert(key.clone(), *position);
+                }
+                Token::Str(rule) if is_key && is_field(field, "states") => {
+                    scan.states.push((style.clone(), rule.clone(), *position));
+                }
+                Token::Str(value) if !is_key && value.contains(RESOURCE_KEY) => {
+                    // references could be part of an expression, e.g. `lighten($PRIMARY, 10%)`
+                    if fields.iter().any(|field| is_field(*field, "styles")) {
+                        for resource in references(value) {
+                            scan.references
+                                .push((style.clone(), resource.to_string(), *position));
+                        }
+                    } else if fields.iter().any(|field| is_field(*field, "resources")) {
+                        scan.nested
+                            .extend(references(value).into_iter().map(str::to_string));
+                    }
+                }
+                Token::Str(_)
+                    if i >= 2
+                        && tokens[i - 1].0 == Token::Punct(':')
+                        && tokens[i - 2].0 == Token::Ident("base".to_string()) =>
+                {
+                    scan.bases.insert(style.clone(), *position);
+                }
+                _ => {}
+            }
+        }
+
+        scan
+    }
+}
+
+// Returns the resources the strings of the given value reference.
+fn value_references(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(s) => references(s).into_iter().map(str::to_string).collect(),
+        Value::Seq(seq) => seq.iter().flat_map(value_references).collect(),
+        Value::Map(map) => map.values().flat_map(value_references).collect(),
+        Value::Option(Some(value)) => value_references(value),
+        _ => vec![],
+    }
+}
+
+fn is_field(field: Option<&Token>, name: &str) -> bool {
+    matches!(field, Some(Token::Ident(ident)) if ident == name)
+}
+
+// Splits a ron source into identifiers, strings and punctuation. Numbers are read as identifiers and comments are
+// skipped.
+fn tokenize(ron: &str) -> Vec<(Token, Position)> {
+    let mut tokens = vec![];
+    let mut chars = ron.chars().peekable();
+    let mut position = Position { line: 1, column: 1 };
+
+    let advance = |c: char, position: &mut Position| {
+        if c == '\n' {
+            position.line += 1;
+            position.column = 1;
+        } else {
+            position.column += 1;
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        let start = position;
+        advance(c, &mut position);
+
+        match c {
+            '"' => {
+                let mut value = String::new();
+
+                while let Some(c) = chars.next() {
+                    advance(c, &mut position);
+
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            if let Some(c) = chars.next() {
+                                advance(c, &mut position);
+                                value.push(c);
+                            }
+                        }
+                        _ => value.push(c),
+                    }
+                }
+
+                tokens.push((Token::Str(value), start));
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                while let Some(&c) = chars.peek() {
+                    if c == '\n' {
+                        break;
+                    }
+
+                    advance(c, &mut position);
+                    chars.next();
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                let mut last = ' ';
+
+                for c in chars.by_ref() {
+                    advance(c, &mut position);
+
+                    if last == '*' && c == '/' {
+                        break;
+                    }
+
+                    last = c;
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut ident = c.to_string();
+
+                while let Some(&c) = chars.peek() {
+                    if !(c.is_alphanumeric() || c == '_' || c == '.') {
+                        break;
+                    }
+
+                    advance(c, &mut position);
+                    ident.push(c);
+                    chars.next();
+                }
+
+                tokens.push((Token::Ident(ident), start));
+            }
+            c if c.is_whitespace() => {}
+            c => tokens.push((Token::Punct(c), start)),
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize() {
+        let tokens: Vec<_> = tokenize("Theme (\n  // comment\n  base: \"a \\\"b\\\"\", 1.5)")
+            .into_iter()
+            .map(|(token, position)| (token, position.line, position.column))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                (Token::Ident("Theme".to_string()), 1, 1),
+                (Token::Punct('('), 1, 7),
+                (Token::Ident("base".to_string()), 3, 3),
+                (Token::Punct(':'), 3, 7),
+                (Token::Str("a \"b\"".to_string()), 3, 9),
+                (Token::Punct(','), 3, 18),
+                (Token::Ident("1.5".to_string()), 3, 20),
+                (Token::Punct(')'), 3, 23),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let diagnostics = validate_theme(
+            r##"Theme (
+    styles: {
+        "button": (
+            base: "base",
+            properties: { "background": "$PRIMARY" },
+            states: { "presed": { "background": "$MISSING" } },
+        ),
+        "a": ( base: "b" ),
+        "b": ( base: "a" ),
+    },
+    resources: {
+        "PRIMARY": "#000000",
+        "UNUSED": "#ffffff",
+    },
+)"##,
+        );
+
+        let kinds: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.kind.clone(), diagnostic.position))
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                (
+                    DiagnosticKind::UnresolvedResource {
+                        style: "button".to_string(),
+                        resource: "MISSING".to_string()
+                    },
+                    Some(Position {
+                        line: 6,
+                        column: 49
+                    })
+                ),
+                (
+                    DiagnosticKind::UnknownBase {
+                        style: "button".to_string(),
+                        base: "base".to_string()
+                    },
+                    Some(Position {
+                        line: 4,
+                        column: 19
+                    })
+                ),
+                (
+                    DiagnosticKind::InheritanceCycle(vec!["a".to_string(), "b".to_string()]),
+                    Some(Position {
+                        line: 8,
+                        column: 22
+                    })
+                ),
+                (
+                    DiagnosticKind::UnusedResource("UNUSED".to_string()),
+                    Some(Position {
+                        line: 13,
+                        column: 9
+                    })
+                ),
+            ]
+        );
+
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "theme:6:49: error: resource \"MISSING\" of style \"button\" is not defined"
+        );
+    }
+
+    #[test]
+    fn test_sources() {
+        let validator = ThemeValidator::new()
+            .source(
+                "theme.ron",
+                r#"Theme ( styles: { "button": ( states: { "presed": { "background": "lighten($ACCENT, 10%)" } } ) } )"#,
+            )
+            .source(
+                "colors.ron",
+                r##"Theme ( resources: { "PRIMARY": "#000000", "ACCENT": "$PRIMARY" } )"##,
+            )
+            .known_states(&["pressed"]);
+
+        let diagnostics = validator.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].kind,
+            DiagnosticKind::UnknownState {
+                style: "button".to_string(),
+                state: "presed".to_string()
+            }
+        );
+        assert_eq!(diagnostics[0].source, "theme.ron");
+
+        let diagnostics = validate_theme("Theme ( styles: { ");
+        assert!(matches!(diagnostics[0].kind, DiagnosticKind::Syntax(_)));
+        assert!(diagnostics[0].position.is_some());
+    }
+
+    #[test]
+    fn test_invalid_property() {
+        let diagnostics = validate_theme(
+            r##"Theme (
+    styles: {
+        "button": (
+            properties: {
+                "background": "lighten($PRIMARY, high)",
+                "foreground": "darken($MISSING, 10%)",
+            },
+        ),
+    },
+    resources: { "PRIMARY": "#000000" },
+)"##,
+        );
+
+        assert_eq!(diagnostics.len(), 2);
+        assert!(matches!(
+            diagnostics[0].kind,
+            DiagnosticKind::UnresolvedResource { .. }
+        ));
+        assert!(matches!(
+            &diagnostics[1].kind,
+            DiagnosticKind::InvalidProperty { style, property, .. }
+                if style == "button" && property == "background"
+        ));
+        assert_eq!(
+            diagnostics[1].position,
+            Some(Position { line: 3, column: 9 })
+        );
+    }
+}
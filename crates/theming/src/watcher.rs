@@ -0,0 +1,141 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+use crate::{config::ThemeConfig, Theme};
+
+/// Watches a theme file during the development of a theme. The theme is rebuilt each time the file is changed.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ctx.watch_theme(
+///     ThemeWatcher::new("assets/dark.ron")
+///         .extend(ThemeConfig::from(COLORS_RON))
+///         .extend(ThemeConfig::from(FONTS_RON)),
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThemeWatcher {
+    path: PathBuf,
+    extensions: Vec<ThemeConfig>,
+    // modification time and length of the file at the last poll
+    modified: Option<(SystemTime, u64)>,
+    polled: bool,
+    last_error: Option<String>,
+}
+
+impl ThemeWatcher {
+    /// Creates a new watcher for the theme file of the given path.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        ThemeWatcher {
+            path: path.into(),
+            extensions: vec![],
+            modified: None,
+            polled: false,
+            last_error: None,
+        }
+    }
+
+    /// Extends the watched theme with the given theme, e.g. with the resources it refers to.
+    pub fn extend(mut self, other: ThemeConfig) -> Self {
+        self.extensions.push(other);
+        self
+    }
+
+    /// Returns the path of the watched theme file.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Returns the error of the last poll if the theme could not be rebuilt, it is reset by the next rebuilt theme.
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+
+    /// Reads and builds the theme.
+    pub fn load(&self) -> Result<Theme, String> {
+        let mut config = ThemeConfig::from_file(&self.path)?;
+
+        for extension in &self.extensions {
+            config = config.extend(extension.clone());
+        }
+
+        Ok(Theme::from_config(config))
+    }
+
+    /// Returns the rebuilt theme if the file is changed since the last poll, the first poll always loads the theme.
+    /// Returns `None` if the file is not changed.
+    pub fn poll(&mut self) -> Option<Result<Theme, String>> {
+        // the modification time of some file systems is too coarse to detect quick changes, so the length is
+        // compared too
+        let modified = fs::metadata(&self.path)
+            .and_then(|metadata| Ok((metadata.modified()?, metadata.len())))
+            .ok();
+
+        if self.polled && modified == self.modified {
+            return None;
+        }
+
+        self.polled = true;
+        self.modified = modified;
+
+        let result = self.load();
+        self.last_error = result.as_ref().err().cloned();
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Selector;
+
+    #[test]
+    fn test_poll() {
+        let path = std::env::temp_dir().join(format!(
+            "orbtk_theme_watcher_test_poll_{}.ron",
+            std::process::id()
+        ));
+        let write_theme = |background: &str| {
+            fs::write(
+                &path,
+                format!(
+                    r#"Theme ( styles: {{ "button": ( properties: {{ "background": "{}" }} ) }} )"#,
+                    background
+                ),
+            )
+            .unwrap();
+        };
+        let background = |theme: Theme| {
+            theme.properties(&Selector::new("button")).unwrap()["background"].clone()
+        };
+
+        write_theme("$BACKGROUND");
+
+        let mut watcher = ThemeWatcher::new(&path).extend(ThemeConfig::from(
+            r#"Theme ( resources: { "BACKGROUND": "black" } )"#,
+        ));
+
+        let theme = watcher.poll().unwrap().unwrap();
+        assert_eq!(background(theme), ron::Value::String("black".to_string()));
+
+        // not changed
+        assert!(watcher.poll().is_none());
+
+        // errors are returned instead of a theme
+        fs::write(&path, "Theme ( styles: { ").unwrap();
+        assert!(matches!(watcher.poll(), Some(Err(_))));
+        assert!(watcher.last_error().is_some());
+        assert!(watcher.poll().is_none());
+
+        // the fixed file is picked up again
+        write_theme("white");
+        let theme = watcher.poll().unwrap().unwrap();
+        assert_eq!(background(theme), ron::Value::String("white".to_string()));
+        assert_eq!(watcher.last_error(), None);
+
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(watcher.poll(), Some(Err(_))));
+    }
+}
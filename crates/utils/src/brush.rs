@@ -65,8 +65,57 @@ impl From<String> for Brush {
 
 impl From<Value> for Brush {
     fn from(v: Value) -> Self {
-        let value = v.get::<String>();
-        Brush::from(value)
+        match v.0 {
+            ron::Value::Map(map) => linear_gradient(&map),
+            value => Brush::from(Value(value).get::<String>()),
+        }
+    }
+}
+
+// Reads a linear gradient of a theme, e.g.
+// `(start: (x: 0, y: 0), end: (x: 0, y: 32), stops: [(position: 0.0, color: "#000000")])`.
+fn linear_gradient(map: &ron::Map) -> Brush {
+    fn field<'a>(map: &'a ron::Map, name: &str) -> Option<&'a ron::Value> {
+        map.iter()
+            .find(|(key, _)| **key == ron::Value::String(name.to_string()))
+            .map(|(_, value)| value)
+    }
+
+    fn number(map: &ron::Map, name: &str) -> f64 {
+        match field(map, name) {
+            Some(ron::Value::Number(number)) => number.into_f64(),
+            _ => 0.0,
+        }
+    }
+
+    fn point(map: &ron::Map, name: &str) -> Point {
+        match field(map, name) {
+            Some(ron::Value::Map(point)) => Point::new(number(point, "x"), number(point, "y")),
+            _ => Point::default(),
+        }
+    }
+
+    let stops = match field(map, "stops") {
+        Some(ron::Value::Seq(stops)) => stops
+            .iter()
+            .filter_map(|stop| match stop {
+                ron::Value::Map(stop) => Some(LinearGradientStop {
+                    position: number(stop, "position"),
+                    color: match field(stop, "color") {
+                        Some(ron::Value::String(color)) => Color::from(color.as_str()),
+                        _ => Color::default(),
+                    },
+                }),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    Brush::LinearGradient {
+        start: point(map, "start"),
+        end: point(map, "end"),
+        stops,
     }
 }
 
@@ -78,6 +127,38 @@ impl From<Value> for Brush {
 
 #[cfg(test)]
 mod tests {
-    //  use crate::prelude::*;
-    // todo: tbd after brush struct is finished
+    use crate::prelude::*;
+
+    #[test]
+    fn test_from_value() {
+        let value = |ron: &str| Value(ron::de::from_str::<ron::Value>(ron).unwrap());
+
+        assert_eq!(
+            Brush::from(value(r##""#ff0000""##)),
+            Brush::SolidColor(Color::rgb(255, 0, 0))
+        );
+
+        assert_eq!(
+            Brush::from(value(
+                r##"(start: (x: 0, y: 0), end: (x: 0, y: 32.5), stops: [
+                    (position: 0.0, color: "#ff0000"),
+                    (position: 1.0, color: "#80000000"),
+                ])"##
+            )),
+            Brush::LinearGradient {
+                start: Point::new(0.0, 0.0),
+                end: Point::new(0.0, 32.5),
+                stops: vec![
+                    LinearGradientStop {
+                        position: 0.0,
+                        color: Color::rgb(255, 0, 0)
+                    },
+                    LinearGradientStop {
+                        position: 1.0,
+                        color: Color::rgba(0, 0, 0, 128)
+                    },
+                ],
+            }
+        );
+    }
 }
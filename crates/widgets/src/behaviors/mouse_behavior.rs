@@ -5,6 +5,8 @@ enum Action {
     Press(Mouse),
     Release(Mouse),
     Scroll(Point),
+    Enter,
+    Leave,
 }
 
 /// The `MouseBehaviorState` handles the `MouseBehavior` widget.
@@ -19,6 +21,17 @@ impl MouseBehaviorState {
     fn action(&mut self, action: Action) {
         self.action = Some(action);
     }
+
+    // Adds or removes the hover state of the target, e.g. to style a checked check box under the mouse with a
+    // `selected+hover` rule.
+    fn set_hover_state(&self, ctx: &mut Context, hover: bool) {
+        if let Some(selector) = ctx
+            .get_widget(self.target)
+            .try_get_mut::<Selector>("selector")
+        {
+            selector.set_state_active("hover", hover);
+        }
+    }
 }
 
 impl State for MouseBehaviorState {
@@ -50,6 +63,7 @@ impl State for MouseBehaviorState {
                         ctx.push_event_by_entity(
                             ClickEvent {
                                 position: p.position,
+                                click_count: p.click_count,
                             },
                             parent,
                         )
@@ -59,6 +73,14 @@ impl State for MouseBehaviorState {
                     mouse_behavior(ctx.widget()).set_position(p);
                     self.has_delta = true;
                 }
+                Action::Enter => {
+                    mouse_behavior(ctx.widget()).set_hover(true);
+                    self.set_hover_state(ctx, true);
+                }
+                Action::Leave => {
+                    mouse_behavior(ctx.widget()).set_hover(false);
+                    self.set_hover_state(ctx, false);
+                }
             };
 
             ctx.get_widget(self.target).update(false);
@@ -86,6 +108,9 @@ widget!(
         /// Sets or shares the pressed property.
         pressed: bool,
 
+        /// Sets or shares the hover property. It is `true` while the mouse is over the widget.
+        hover: bool,
+
         /// Sets or shares the (wheel, scroll) delta property.
         delta: Point
     }
@@ -96,6 +121,7 @@ impl Template for MouseBehavior {
         self.name("MouseBehavior")
             .delta(0.0)
             .pressed(false)
+            .hover(false)
             .on_mouse_down(move |states, m| {
                 states
                     .get_mut::<MouseBehaviorState>(id)
@@ -114,5 +140,17 @@ impl Template for MouseBehavior {
                     .action(Action::Scroll(p));
                 false
             })
+            .on_mouse_enter(move |states, _| {
+                states
+                    .get_mut::<MouseBehaviorState>(id)
+                    .action(Action::Enter);
+                false
+            })
+            .on_mouse_leave(move |states, _| {
+                states
+                    .get_mut::<MouseBehaviorState>(id)
+                    .action(Action::Leave);
+                false
+            })
     }
 }
@@ -1,7 +1,9 @@
+use std::collections::VecDeque;
+
 use crate::{
     api::prelude::*,
     proc_macros::*,
-    shell::prelude::{Key, KeyEvent},
+    shell::prelude::{ImeEvent, Key, KeyEvent, WindowRequest},
     theme::fonts,
 };
 
@@ -11,21 +13,26 @@ use super::MouseBehavior;
 enum TextAction {
     Key(KeyEvent),
     Mouse(Mouse),
+    Ime(ImeEvent),
 }
 
 /// The `TextBehaviorState` handles the text processing of the `TextBehavior` widget.
 #[derive(Default, AsAny)]
 pub struct TextBehaviorState {
-    action: Option<TextAction>,
+    actions: VecDeque<TextAction>,
     len: usize,
     cursor: Entity,
+    preedit_cursor: Option<Entity>,
     target: Entity,
     focused: bool,
+    // start index and length of the composed text of the input method
+    preedit: Option<(usize, usize)>,
+    ime_position: Option<Point>,
 }
 
 impl TextBehaviorState {
     fn action(&mut self, action: TextAction) {
-        self.action = Some(action);
+        self.actions.push_back(action);
     }
 
     fn request_focus(&self, ctx: &mut Context, p: Mouse) {
@@ -54,6 +61,32 @@ impl TextBehaviorState {
         }
     }
 
+    // Selects the word (or the run of white spaces / symbols) under the mouse.
+    fn select_word(&self, ctx: &mut Context, p: Mouse) {
+        let index = self.get_new_caret_position(ctx, p);
+        let (start_index, length) =
+            word_range(ctx.widget().get::<String16>("text").as_bytes(), index);
+        self.select(ctx, start_index, length);
+    }
+
+    // Selects the complete line under the mouse.
+    fn select_line(&self, ctx: &mut Context, p: Mouse) {
+        let index = self.get_new_caret_position(ctx, p);
+        let (start_index, length) =
+            line_range(ctx.widget().get::<String16>("text").as_bytes(), index);
+        self.select(ctx, start_index, length);
+    }
+
+    fn select(&self, ctx: &mut Context, start_index: usize, length: usize) {
+        ctx.widget()
+            .get_mut::<TextSelection>("text_selection")
+            .start_index = start_index;
+        ctx.widget()
+            .get_mut::<TextSelection>("text_selection")
+            .length = length;
+        ctx.get_widget(self.cursor).set("expanded", length > 0);
+    }
+
     // Get new position for the caret based on current mouse position
     fn get_new_caret_position(&self, ctx: &mut Context, p: Mouse) -> usize {
         if let Some((index, _x)) = self
@@ -111,6 +144,22 @@ impl TextBehaviorState {
         }
     }
 
+    // Synchronizes the `focused` and `empty` states of the target with the focus and the text of the widget.
+    fn update_selector(&self, ctx: &mut Context) {
+        let focused = *ctx.widget().get::<bool>("focused");
+        let empty = ctx.widget().get::<String16>("text").is_empty();
+
+        let mut target = ctx.get_widget(self.target);
+
+        {
+            let selector = target.get_mut::<Selector>("selector");
+            selector.set_state_active("focused", focused);
+            selector.set_state_active("empty", empty);
+        }
+
+        target.update(false);
+    }
+
     fn handle_key_event(&mut self, key_event: KeyEvent, ctx: &mut Context) {
         if !ctx.widget().get::<bool>("focused") {
             return;
@@ -299,33 +348,111 @@ impl TextBehaviorState {
             return;
         }
 
+        self.insert_text(key_event.text.as_str(), ctx);
+    }
+
+    // Inserts the text at the caret or replaces the selected text.
+    fn insert_text(&mut self, text: &str, ctx: &mut Context) {
         if *ctx.get_widget(self.cursor).get::<bool>("expanded") {
-            ctx.get_widget(self.target)
-                .set("text", String16::from(key_event.text));
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index = 1;
-                selection.length = 0
-            }
-            ctx.get_widget(self.cursor).set("expanded", false);
-        } else {
-            let current_selection = *ctx
-                .get_widget(self.cursor)
-                .get::<TextSelection>("text_selection");
+            self.clear_selection(ctx);
+        }
 
-            let mut text = ctx.widget().clone::<String16>("text");
-            text.insert_str(current_selection.start_index, key_event.text.as_str());
-            ctx.get_widget(self.target).set("text", text);
+        let start_index = ctx
+            .get_widget(self.cursor)
+            .get::<TextSelection>("text_selection")
+            .start_index;
 
-            if let Some(selection) = ctx
-                .get_widget(self.cursor)
-                .try_get_mut::<TextSelection>("text_selection")
-            {
-                selection.start_index =
-                    current_selection.start_index + key_event.text.encode_utf16().count();
-            }
+        let mut value = ctx.widget().clone::<String16>("text");
+        value.insert_str(start_index, text);
+        ctx.get_widget(self.target).set("text", value);
+
+        if let Some(selection) = ctx
+            .get_widget(self.cursor)
+            .try_get_mut::<TextSelection>("text_selection")
+        {
+            selection.start_index = start_index + text.encode_utf16().count();
+        }
+    }
+
+    fn handle_ime_event(&mut self, ime_event: ImeEvent, ctx: &mut Context) {
+        if !ctx.widget().get::<bool>("focused") {
+            return;
+        }
+
+        self.compose(ime_event, ctx);
+    }
+
+    // Applies the preedit or the committed text of the input method at the caret and underlines the preedit text.
+    fn compose(&mut self, ime_event: ImeEvent, ctx: &mut Context) {
+        // the text that is composed first replaces the selected text
+        if self.preedit.is_none() && *ctx.get_widget(self.cursor).get::<bool>("expanded") {
+            self.clear_selection(ctx);
+        }
+
+        let caret = ctx
+            .widget()
+            .get::<TextSelection>("text_selection")
+            .start_index;
+        let mut text = ctx.widget().clone::<String16>("text");
+        let caret = apply_ime_event(&mut text, caret, &mut self.preedit, ime_event);
+
+        ctx.get_widget(self.target).set("text", text);
+        self.select(ctx, caret, 0);
+        self.update_preedit_cursor(ctx);
+    }
+
+    // Shows the underline of the composed text.
+    fn update_preedit_cursor(&self, ctx: &mut Context) {
+        if let Some(preedit_cursor) = self.preedit_cursor {
+            let (start_index, length) = self.preedit.unwrap_or((0, 0));
+
+            ctx.get_widget(preedit_cursor).set(
+                "text_selection",
+                TextSelection {
+                    start_index,
+                    length,
+                },
+            );
+            ctx.get_widget(preedit_cursor).set(
+                "visibility",
+                if length > 0 {
+                    Visibility::Visible
+                } else {
+                    Visibility::Collapsed
+                },
+            );
+            ctx.get_widget(preedit_cursor).update(false);
+        }
+    }
+
+    // Moves the candidate window of the input method to the caret.
+    fn update_ime_position(&mut self, ctx: &mut Context) {
+        let index = ctx
+            .widget()
+            .get::<TextSelection>("text_selection")
+            .start_index;
+        let text: String16 = ctx.widget().clone("text");
+        let font: String = ctx.widget().clone_or_default::<String>("font");
+        let font_size: f64 = ctx.widget().clone_or_default::<f64>("font_size");
+
+        let width = text.get_string(0, index).map_or(0.0, |text_part| {
+            ctx.render_context_2_d()
+                .measure(text_part.as_str(), font_size, &font)
+                .width
+        });
+
+        let position = *ctx.widget().get::<Point>("position");
+        let padding_left = ctx.get_widget(self.target).get::<Thickness>("padding").left;
+        let height = ctx
+            .get_widget(self.target)
+            .get::<Rectangle>("bounds")
+            .height();
+
+        let ime_position = Point::new(position.x() + padding_left + width, position.y() + height);
+
+        if self.ime_position != Some(ime_position) {
+            self.ime_position = Some(ime_position);
+            ctx.send_window_request(WindowRequest::SetImePosition(ime_position));
         }
     }
 }
@@ -342,35 +469,20 @@ impl State for TextBehaviorState {
                 .try_clone::<u32>("target")
                 .expect("TextBehaviorState.init: target could not be found."),
         );
+        self.preedit_cursor = ctx
+            .widget()
+            .try_clone::<u32>("preedit_cursor")
+            .map(Entity::from);
         self.len = ctx.widget().get::<String16>("text").len();
         self.focused = *ctx.widget().get::<bool>("focused");
 
-        if self.len == 0 {
-            ctx.get_widget(self.target)
-                .get_mut::<Selector>("selector")
-                .set_state("empty");
-            ctx.get_widget(self.target).update(false);
-        }
+        self.update_selector(ctx);
     }
 
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
         self.check_outside_update(ctx);
 
         let focused = *ctx.widget().get::<bool>("focused");
-        let empty = ctx.widget().get::<String16>("text").is_empty();
-
-        if !focused
-            && empty
-            && !ctx
-                .get_widget(self.target)
-                .get::<Selector>("selector")
-                .has_state("empty")
-        {
-            ctx.get_widget(self.target)
-                .get_mut::<Selector>("selector")
-                .set_state("empty");
-            ctx.get_widget(self.target).update(false);
-        }
 
         if !focused && *ctx.widget().get::<bool>("request_focus") {
             ctx.widget().set("request_focus", false);
@@ -378,37 +490,53 @@ impl State for TextBehaviorState {
             self.select_all(ctx);
         }
 
+        let mut caret_changed = false;
+
         if self.focused != *ctx.widget().get::<bool>("focused") {
             self.focused = *ctx.widget().get::<bool>("focused");
+            caret_changed = true;
+
+            // the composed text is discarded if the widget loses the focus
+            if !self.focused && self.preedit.is_some() {
+                self.compose(
+                    ImeEvent::Preedit {
+                        text: String::new(),
+                        cursor: 0,
+                    },
+                    ctx,
+                );
+            }
+
+            if !self.focused {
+                self.ime_position = None;
+            }
         }
 
-        if let Some(action) = self.action.clone() {
+        while let Some(action) = self.actions.pop_front() {
             match action {
                 TextAction::Key(event) => {
                     self.handle_key_event(event, ctx);
                 }
-                TextAction::Mouse(p) => {
-                    self.request_focus(ctx, p);
+                TextAction::Mouse(p) => match p.click_count {
+                    2 => self.select_word(ctx, p),
+                    c if c > 2 => self.select_line(ctx, p),
+                    _ => self.request_focus(ctx, p),
+                },
+                TextAction::Ime(event) => {
+                    self.handle_ime_event(event, ctx);
                 }
             }
 
-            self.action = None;
+            caret_changed = true;
             ctx.get_widget(self.target).update(false);
         }
 
-        self.len = ctx.widget().get::<String16>("text").len();
-
-        if self.len == 0 && self.focused {
-            ctx.get_widget(self.target)
-                .get_mut::<Selector>("selector")
-                .set_state("empty_focused");
-            ctx.get_widget(self.target).update(false);
-        } else if self.len > 0 && self.focused {
-            ctx.get_widget(self.target)
-                .get_mut::<Selector>("selector")
-                .set_state("focused");
-            ctx.get_widget(self.target).update(false);
+        if caret_changed && self.focused {
+            self.update_ime_position(ctx);
         }
+
+        self.len = ctx.widget().get::<String16>("text").len();
+        self.update_selector(ctx);
     }
 }
 
@@ -421,8 +549,11 @@ widget!(
     /// * select all text with Ctrl+A key combination
     /// * delete selected text with Backspace or Delete
     /// * move cursor by the left or right arrow keys or clicking with mouse
+    /// * select a word by double click and a line by triple click
     /// * delete characters by pressing the Backspace or the Delete key
     /// * run on_activate() callback on pressing the Enter key
+    /// * input composed text of input methods, dead keys and compose sequences. The composed text is underlined
+    ///   by the optional `preedit_cursor` and the candidate window of the input method is placed at the caret.
     ///
     /// TextBehavior needs the following prerequisites to able to work:
     /// * a `cursor`: the [`Entity`] of a [`Cursor`] widget
@@ -488,10 +619,13 @@ widget!(
     ///
     /// [`Entity`]: https://docs.rs/dces/0.2.0/dces/entity/struct.Entity.html
     /// [`Cursor`]: ../struct.Cursor.html
-    TextBehavior<TextBehaviorState>: ActivateHandler, KeyDownHandler {
+    TextBehavior<TextBehaviorState>: ActivateHandler, KeyDownHandler, TextInputHandler {
         /// Sets or shares the entity of the Cursor widget property.
         cursor: u32,
 
+        /// Sets or shares the entity of the Cursor widget that underlines the composed text of input methods.
+        preedit_cursor: u32,
+
         /// Sets or shares the focused property.
         focused: bool,
 
@@ -545,5 +679,192 @@ impl Template for TextBehavior {
                     .action(TextAction::Key(event));
                 false
             })
+            .on_text_input(move |states, event| -> bool {
+                states
+                    .get_mut::<TextBehaviorState>(id)
+                    .action(TextAction::Ime(event));
+                false
+            })
+    }
+}
+
+// --- Helpers --
+
+#[derive(PartialEq)]
+enum CharClass {
+    Word,
+    WhiteSpace,
+    Other,
+}
+
+fn char_class(c: u16) -> CharClass {
+    match std::char::from_u32(u32::from(c)) {
+        Some(c) if c.is_alphanumeric() || c == '_' => CharClass::Word,
+        Some(c) if c.is_whitespace() => CharClass::WhiteSpace,
+        Some(_) => CharClass::Other,
+        // parts of surrogate pairs are handled as word characters
+        None => CharClass::Word,
+    }
+}
+
+// Returns start index and length of the run of characters with the same class around the given index.
+fn word_range(text: &[u16], index: usize) -> (usize, usize) {
+    if text.is_empty() {
+        return (0, 0);
+    }
+
+    // at the end of the text the word before the caret is selected
+    let index = index.min(text.len() - 1);
+    let class = char_class(text[index]);
+
+    let mut start = index;
+    while start > 0 && char_class(text[start - 1]) == class {
+        start -= 1;
+    }
+
+    let mut end = index + 1;
+    while end < text.len() && char_class(text[end]) == class {
+        end += 1;
+    }
+
+    (start, end - start)
+}
+
+// Returns start index and length of the line around the given index (without line break).
+fn line_range(text: &[u16], index: usize) -> (usize, usize) {
+    let line_break = u16::from(b'\n');
+    let index = index.min(text.len());
+
+    let start = text[..index]
+        .iter()
+        .rposition(|c| *c == line_break)
+        .map_or(0, |i| i + 1);
+    let end = text[index..]
+        .iter()
+        .position(|c| *c == line_break)
+        .map_or(text.len(), |i| index + i);
+
+    (start, end - start)
+}
+
+// Inserts the preedit or the committed text of an input method at the caret, it replaces the current preedit text.
+// Returns the new caret index.
+fn apply_ime_event(
+    text: &mut String16,
+    caret: usize,
+    preedit: &mut Option<(usize, usize)>,
+    ime_event: ImeEvent,
+) -> usize {
+    let mut caret = caret;
+
+    if let Some((start_index, length)) = preedit.take() {
+        for _ in 0..length {
+            text.remove(start_index);
+        }
+
+        caret = start_index;
+    }
+
+    match ime_event {
+        ImeEvent::Preedit {
+            text: preedit_text,
+            cursor,
+        } => {
+            if preedit_text.is_empty() {
+                return caret;
+            }
+
+            text.insert_str(caret, preedit_text.as_str());
+            *preedit = Some((caret, preedit_text.encode_utf16().count()));
+            caret + utf16_index(preedit_text.as_str(), cursor)
+        }
+        ImeEvent::Commit(commit) => {
+            text.insert_str(caret, commit.as_str());
+            caret + commit.encode_utf16().count()
+        }
+    }
+}
+
+// Converts the char index of the caret inside of the composed text to an utf16 index.
+fn utf16_index(text: &str, char_index: usize) -> usize {
+    text.chars().take(char_index).map(|c| c.len_utf16()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_range() {
+        let text = String16::from("hello big_world, bye");
+
+        assert_eq!(word_range(text.as_bytes(), 0), (0, 5));
+        assert_eq!(word_range(text.as_bytes(), 3), (0, 5));
+        assert_eq!(word_range(text.as_bytes(), 5), (5, 1));
+        assert_eq!(word_range(text.as_bytes(), 8), (6, 9));
+        assert_eq!(word_range(text.as_bytes(), 15), (15, 1));
+        assert_eq!(word_range(text.as_bytes(), 20), (17, 3));
+        assert_eq!(word_range(String16::new().as_bytes(), 0), (0, 0));
+    }
+
+    #[test]
+    fn test_line_range() {
+        let text = String16::from("first\nsecond line\nthird");
+
+        assert_eq!(line_range(text.as_bytes(), 2), (0, 5));
+        assert_eq!(line_range(text.as_bytes(), 5), (0, 5));
+        assert_eq!(line_range(text.as_bytes(), 8), (6, 11));
+        assert_eq!(line_range(text.as_bytes(), 22), (18, 5));
+        assert_eq!(line_range(String16::from("single").as_bytes(), 3), (0, 6));
+        assert_eq!(line_range(String16::new().as_bytes(), 0), (0, 0));
+    }
+
+    #[test]
+    fn test_utf16_index() {
+        assert_eq!(utf16_index("にほん", 0), 0);
+        assert_eq!(utf16_index("にほん", 2), 2);
+        assert_eq!(utf16_index("a😀b", 2), 3);
+        assert_eq!(utf16_index("abc", 10), 3);
+    }
+
+    #[test]
+    fn test_apply_ime_event() {
+        let mut text = String16::from("ab");
+        let mut preedit = None;
+
+        let preedit_event = |text: &str, cursor| ImeEvent::Preedit {
+            text: text.to_string(),
+            cursor,
+        };
+
+        // the preedit text is inserted at the caret
+        let caret = apply_ime_event(&mut text, 1, &mut preedit, preedit_event("にほ", 1));
+        assert_eq!(text.as_string(), "aにほb");
+        assert_eq!(caret, 2);
+        assert_eq!(preedit, Some((1, 2)));
+
+        // a new preedit text replaces the old one
+        let caret = apply_ime_event(&mut text, caret, &mut preedit, preedit_event("日本😀", 3));
+        assert_eq!(text.as_string(), "a日本😀b");
+        assert_eq!(caret, 5);
+        assert_eq!(preedit, Some((1, 4)));
+
+        // the committed text replaces the preedit text
+        let caret = apply_ime_event(
+            &mut text,
+            caret,
+            &mut preedit,
+            ImeEvent::Commit("日本".to_string()),
+        );
+        assert_eq!(text.as_string(), "a日本b");
+        assert_eq!(caret, 3);
+        assert_eq!(preedit, None);
+
+        // an empty preedit text removes the preedit text
+        apply_ime_event(&mut text, caret, &mut preedit, preedit_event("ご", 1));
+        let caret = apply_ime_event(&mut text, 4, &mut preedit, preedit_event("", 0));
+        assert_eq!(text.as_string(), "a日本b");
+        assert_eq!(caret, 3);
+        assert_eq!(preedit, None);
     }
 }
@@ -2,7 +2,7 @@ use crate::{api::prelude::*, proc_macros::*};
 
 widget!(
     /// The `Container` layout widget surrounds its child with a padding. Draws a box around the child.
-    Container {
+    Container: DragDropHandler, MessageHandler, TouchHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -0,0 +1,132 @@
+use std::{any::Any, cell::RefCell};
+
+use crate::{api::into_property_source, api::prelude::*, prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_DIALOG: &str = "dialog";
+
+// --- KEYS --
+
+/// Describes the buttons of a dialog.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DialogButtons {
+    Ok,
+    OkCancel,
+    YesNo,
+    YesNoCancel,
+}
+
+impl DialogButtons {
+    /// Returns the results of the buttons in display order.
+    pub fn results(self) -> Vec<DialogResult> {
+        match self {
+            DialogButtons::Ok => vec![DialogResult::Ok],
+            DialogButtons::OkCancel => vec![DialogResult::Ok, DialogResult::Cancel],
+            DialogButtons::YesNo => vec![DialogResult::Yes, DialogResult::No],
+            DialogButtons::YesNoCancel => {
+                vec![DialogResult::Yes, DialogResult::No, DialogResult::Cancel]
+            }
+        }
+    }
+
+    /// Returns the result of the default button that is pressed with `Enter`.
+    pub fn default_result(self) -> DialogResult {
+        self.results()[0]
+    }
+
+    /// Returns the result that is used if the dialog is cancelled with `Escape`.
+    pub fn cancel_result(self) -> DialogResult {
+        *self.results().last().unwrap()
+    }
+}
+
+impl Default for DialogButtons {
+    fn default() -> Self {
+        DialogButtons::Ok
+    }
+}
+
+impl From<&str> for DialogButtons {
+    fn from(s: &str) -> DialogButtons {
+        match s {
+            "OkCancel" | "ok_cancel" => DialogButtons::OkCancel,
+            "YesNo" | "yes_no" => DialogButtons::YesNo,
+            "YesNoCancel" | "yes_no_cancel" => DialogButtons::YesNoCancel,
+            _ => DialogButtons::Ok,
+        }
+    }
+}
+
+into_property_source!(DialogButtons: &str);
+
+/// Represents the button a dialog is closed with.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DialogResult {
+    Ok,
+    Cancel,
+    Yes,
+    No,
+}
+
+impl DialogResult {
+    /// Returns the text of the button.
+    pub fn text(self) -> &'static str {
+        match self {
+            DialogResult::Ok => "OK",
+            DialogResult::Cancel => "Cancel",
+            DialogResult::Yes => "Yes",
+            DialogResult::No => "No",
+        }
+    }
+}
+
+/// Shows the given dialog as modal window with the given size. The title of the window is shared with the `title`
+/// property of the dialog. The dialog closes the window with `Context::close_modal`, its result is passed to
+/// `on_result`.
+pub fn show_dialog<W, R, H>(ctx: &mut Context, dialog: W, size: (f64, f64), on_result: H)
+where
+    W: Widget + 'static,
+    R: Any + Clone,
+    H: Fn(&mut StatesContext, Option<R>) + 'static,
+{
+    // the window is created only once, the builder is moved out on creation
+    let dialog = RefCell::new(Some(dialog));
+
+    ctx.show_modal_window(
+        move |ctx| {
+            let dialog = dialog
+                .borrow_mut()
+                .take()
+                .expect("show_dialog: the dialog could only be created once.")
+                .build(ctx);
+
+            Window::new()
+                .title(("title", dialog))
+                .size(size.0, size.1)
+                .child(dialog)
+                .build(ctx)
+        },
+        on_result,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dialog_buttons() {
+        assert_eq!(DialogButtons::from("ok_cancel"), DialogButtons::OkCancel);
+        assert_eq!(DialogButtons::from("YesNo"), DialogButtons::YesNo);
+        assert_eq!(DialogButtons::from("unknown"), DialogButtons::Ok);
+
+        assert_eq!(DialogButtons::Ok.default_result(), DialogResult::Ok);
+        assert_eq!(DialogButtons::Ok.cancel_result(), DialogResult::Ok);
+        assert_eq!(DialogButtons::YesNo.cancel_result(), DialogResult::No);
+        assert_eq!(
+            DialogButtons::YesNoCancel.results(),
+            vec![DialogResult::Yes, DialogResult::No, DialogResult::Cancel]
+        );
+    }
+}
@@ -0,0 +1,586 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    api::into_property_source, api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*,
+    theme::prelude::*,
+};
+
+// --- KEYS --
+
+pub static STYLE_FILE_CHOOSER_ITEM: &str = "file_chooser_item";
+static ID_DIRECTORY: &str = "file_chooser_directory";
+static ID_LIST: &str = "file_chooser_list";
+static ID_FILTER: &str = "file_chooser_filter";
+static ID_ACCEPT: &str = "file_chooser_accept";
+
+// --- KEYS --
+
+/// Describes if the `FileChooser` is used to open or to save a file.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FileChooserMode {
+    Open,
+    Save,
+}
+
+impl Default for FileChooserMode {
+    fn default() -> Self {
+        FileChooserMode::Open
+    }
+}
+
+impl From<&str> for FileChooserMode {
+    fn from(s: &str) -> FileChooserMode {
+        match s {
+            "Save" | "save" => FileChooserMode::Save,
+            _ => FileChooserMode::Open,
+        }
+    }
+}
+
+into_property_source!(FileChooserMode: &str);
+
+/// Represents an entry of a directory that is listed by the `FileChooser`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileEntry {
+    /// The file name of the entry.
+    pub name: String,
+
+    /// Indicates if the entry is a directory.
+    pub is_dir: bool,
+}
+
+/// Checks if the given file name matches the pattern. `*` matches any sequence and `?` any single character, the
+/// comparison ignores the ASCII case.
+pub fn matches_pattern(name: &str, pattern: &str) -> bool {
+    let name: Vec<char> = name.to_ascii_lowercase().chars().collect();
+    let pattern: Vec<char> = pattern.to_ascii_lowercase().chars().collect();
+
+    let (mut n, mut p) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            n += 1;
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // lets the last star match one more character
+            p = star + 1;
+            n = matched + 1;
+            backtrack = Some((star, n));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Returns the patterns of a filter. A filter could have a description, e.g. `Images (*.png;*.jpg)`, the patterns
+/// inside of the last parentheses are used then.
+pub fn filter_patterns(filter: &str) -> Vec<&str> {
+    let patterns = match (filter.rfind('('), filter.rfind(')')) {
+        (Some(start), Some(end)) if start < end => &filter[start + 1..end],
+        _ => filter,
+    };
+
+    patterns
+        .split(';')
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .collect()
+}
+
+/// Reads the entries of the given directory. Directories are listed before files, files are only listed if they
+/// match one of the given patterns. Hidden entries that starts with a `.` are skipped.
+pub fn read_directory(path: &Path, patterns: &[&str]) -> io::Result<Vec<FileEntry>> {
+    let mut entries = vec![];
+
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().to_string();
+
+        if name.starts_with('.') {
+            continue;
+        }
+
+        let is_dir = entry.path().is_dir();
+
+        if !is_dir
+            && !patterns.is_empty()
+            && !patterns
+                .iter()
+                .any(|pattern| matches_pattern(&name, pattern))
+        {
+            continue;
+        }
+
+        entries.push(FileEntry { name, is_dir });
+    }
+
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    Ok(entries)
+}
+
+#[derive(Copy, Clone)]
+enum FileChooserAction {
+    Up,
+    Select(usize),
+    Filter,
+    Accept,
+    Cancel,
+}
+
+/// The `FileChooserState` lists the current directory and closes the modal with the chosen path.
+#[derive(Default, AsAny)]
+pub struct FileChooserState {
+    action: Option<FileChooserAction>,
+    directory: PathBuf,
+    entries: Vec<FileEntry>,
+    list: Entity,
+    filter: Entity,
+}
+
+impl FileChooserState {
+    fn action(&mut self, action: FileChooserAction) {
+        self.action = Some(action);
+    }
+
+    // Lists the entries of the current directory that matches the selected filter.
+    fn refresh(&mut self, ctx: &mut Context) {
+        let filters = ctx.widget().clone::<Vec<String>>("filters");
+        let index = ctx.get_widget(self.filter).clone::<i32>("selected_index");
+
+        let patterns = if index >= 0 && (index as usize) < filters.len() {
+            filter_patterns(&filters[index as usize])
+        } else {
+            vec![]
+        };
+
+        self.entries = read_directory(&self.directory, &patterns).unwrap_or_default();
+
+        let directory = self.directory.to_string_lossy().to_string();
+        ctx.child(ID_DIRECTORY)
+            .set("text", String16::from(directory.as_str()));
+        ctx.widget().set("directory", directory);
+        ctx.widget().set(
+            "entries",
+            self.entries
+                .iter()
+                .map(|entry| {
+                    if entry.is_dir {
+                        format!("{}/", entry.name)
+                    } else {
+                        entry.name.clone()
+                    }
+                })
+                .collect::<Vec<String>>(),
+        );
+
+        let mut list = ctx.get_widget(self.list);
+        list.get_mut::<SelectedIndices>("selected_indices")
+            .0
+            .clear();
+        list.get_mut::<SelectedEntities>("selected_entities")
+            .0
+            .clear();
+        list.set("count", self.entries.len());
+        list.set("request_update", true);
+    }
+
+    fn open_directory(&mut self, directory: PathBuf, ctx: &mut Context) {
+        self.directory = directory;
+        self.refresh(ctx);
+    }
+
+    fn accept(&mut self, ctx: &mut Context) {
+        let file_name = ctx.widget().get::<String16>("file_name").as_string();
+
+        if file_name.is_empty() {
+            return;
+        }
+
+        let path = self.directory.join(file_name);
+
+        if path.is_dir() {
+            ctx.widget().set("file_name", String16::default());
+            self.open_directory(path, ctx);
+            return;
+        }
+
+        if *ctx.widget().get::<FileChooserMode>("mode") == FileChooserMode::Open && !path.exists() {
+            return;
+        }
+
+        ctx.close_modal(path);
+    }
+}
+
+impl State for FileChooserState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        self.list = ctx
+            .entity_of_child(ID_LIST)
+            .expect("FileChooserState.init: list child could not be found.");
+        self.filter = ctx
+            .entity_of_child(ID_FILTER)
+            .expect("FileChooserState.init: filter child could not be found.");
+
+        let filters = ctx.widget().get::<Vec<String>>("filters").len();
+
+        if filters == 0 {
+            ctx.get_widget(self.filter)
+                .set("visibility", Visibility::Collapsed);
+        } else {
+            ctx.get_widget(self.filter).set("count", filters);
+            ctx.get_widget(self.filter).set("selected_index", 0);
+        }
+
+        if *ctx.widget().get::<FileChooserMode>("mode") == FileChooserMode::Save {
+            ctx.child(ID_ACCEPT).set("text", String16::from("Save"));
+        }
+
+        let directory = ctx.widget().clone::<String>("directory");
+
+        self.directory = if directory.is_empty() {
+            std::env::current_dir().unwrap_or_default()
+        } else {
+            PathBuf::from(directory)
+        };
+
+        self.refresh(ctx);
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        match self.action.take() {
+            Some(FileChooserAction::Up) => {
+                if let Some(parent) = self.directory.parent() {
+                    self.open_directory(parent.to_path_buf(), ctx);
+                }
+            }
+            Some(FileChooserAction::Select(index)) => {
+                if let Some(entry) = self.entries.get(index).cloned() {
+                    if entry.is_dir {
+                        self.open_directory(self.directory.join(entry.name), ctx);
+                    } else {
+                        ctx.widget()
+                            .set("file_name", String16::from(entry.name.as_str()));
+                    }
+                }
+            }
+            Some(FileChooserAction::Filter) => self.refresh(ctx),
+            Some(FileChooserAction::Accept) => self.accept(ctx),
+            Some(FileChooserAction::Cancel) => ctx.cancel_modal(),
+            None => {}
+        }
+    }
+}
+
+widget!(
+    /// The `FileChooser` browses the file system to choose a file to open or to save. It is shown as modal with
+    /// `FileChooser::show`.
+    ///
+    /// **style:** `dialog`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// FileChooser::new()
+    ///     .title("Open image")
+    ///     .filters(vec![
+    ///         String::from("Images (*.png;*.jpg)"),
+    ///         String::from("All files (*)"),
+    ///     ])
+    ///     .show(ctx, |states, path| println!("{:?}", path));
+    /// ```
+    FileChooser<FileChooserState>: KeyDownHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the title of the dialog window.
+        title: String,
+
+        /// Sets or shares the mode, `open` only accepts existing files.
+        mode: FileChooserMode,
+
+        /// Sets or shares the listed directory. The current directory is used if it is empty.
+        directory: String,
+
+        /// Sets or shares the name of the chosen file.
+        file_name: String16,
+
+        /// Sets or shares the selectable filters, e.g. `Rust files (*.rs)`.
+        filters: Vec<String>,
+
+        /// Internal property that contains the names of the listed entries.
+        entries: Vec<String>
+    }
+);
+
+impl FileChooser {
+    /// Shows the file chooser as modal window. `on_result` gets the chosen path or `None` if it is cancelled.
+    pub fn show<H: Fn(&mut StatesContext, Option<PathBuf>) + 'static>(
+        self,
+        ctx: &mut Context,
+        on_result: H,
+    ) {
+        show_dialog(ctx, self, (560.0, 420.0), on_result);
+    }
+}
+
+impl Template for FileChooser {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let accept = move |states: &mut StatesContext| {
+            states
+                .get_mut::<FileChooserState>(id)
+                .action(FileChooserAction::Accept);
+        };
+
+        self.name("FileChooser")
+            .style(STYLE_DIALOG)
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .padding(12.0)
+            .title("")
+            .mode("open")
+            .directory("")
+            .file_name("")
+            .filters(vec![])
+            .entries(vec![])
+            .on_key_down(move |states, event| -> bool {
+                if event.key == Key::Escape {
+                    states
+                        .get_mut::<FileChooserState>(id)
+                        .action(FileChooserAction::Cancel);
+                    return true;
+                }
+
+                false
+            })
+            .child(
+                Container::new()
+                    .background(id)
+                    .padding(id)
+                    .child(
+                        Grid::new()
+                            .rows(
+                                Rows::create()
+                                    .push("auto")
+                                    .push("*")
+                                    .push("auto")
+                                    .push("auto"),
+                            )
+                            .child(
+                                Stack::new()
+                                    .orientation("horizontal")
+                                    .attach(Grid::row(0))
+                                    .child(
+                                        Button::new()
+                                            .style("button_small")
+                                            .icon(material_icons_font::MD_ARROW_UPWARD)
+                                            .min_width(32.0)
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get_mut::<FileChooserState>(id)
+                                                    .action(FileChooserAction::Up);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        TextBlock::new()
+                                            .id(ID_DIRECTORY)
+                                            .style("body")
+                                            .v_align("center")
+                                            .margin((8.0, 0.0, 0.0, 0.0))
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .child(
+                                ListView::new()
+                                    .id(ID_LIST)
+                                    .margin((0.0, 8.0, 0.0, 8.0))
+                                    .attach(Grid::row(1))
+                                    .items_builder(move |bc, index| {
+                                        let entry = bc.get_widget(id).get::<Vec<String>>("entries")
+                                            [index]
+                                            .clone();
+                                        let icon = if entry.ends_with('/') {
+                                            material_icons_font::MD_FOLDER
+                                        } else {
+                                            material_icons_font::MD_INSERT_DRIVE_FILE
+                                        };
+
+                                        Stack::new()
+                                            .orientation("horizontal")
+                                            .child(
+                                                FontIconBlock::new()
+                                                    .style(STYLE_FILE_CHOOSER_ITEM)
+                                                    .icon(icon)
+                                                    .v_align("center")
+                                                    .margin((0.0, 0.0, 4.0, 0.0))
+                                                    .build(bc),
+                                            )
+                                            .child(
+                                                TextBlock::new()
+                                                    .style(STYLE_FILE_CHOOSER_ITEM)
+                                                    .text(entry)
+                                                    .v_align("center")
+                                                    .build(bc),
+                                            )
+                                            .build(bc)
+                                    })
+                                    .on_selection_changed(move |states, _, indices| {
+                                        if let Some(index) = indices.first() {
+                                            states
+                                                .get_mut::<FileChooserState>(id)
+                                                .action(FileChooserAction::Select(*index));
+                                        }
+                                    })
+                                    .build(ctx),
+                            )
+                            .child(
+                                Grid::new()
+                                    .columns(Columns::create().push("*").push("auto"))
+                                    .attach(Grid::row(2))
+                                    .child(
+                                        TextBox::new()
+                                            .text(("file_name", id))
+                                            .water_mark("File name")
+                                            .lost_focus_on_activation(false)
+                                            .on_activate(move |states, _| accept(states))
+                                            .attach(Grid::column(0))
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        ComboBox::new()
+                                            .id(ID_FILTER)
+                                            .min_width(160.0)
+                                            .margin((8.0, 0.0, 0.0, 0.0))
+                                            .items_builder(move |bc, index| {
+                                                let filter = bc
+                                                    .get_widget(id)
+                                                    .get::<Vec<String>>("filters")[index]
+                                                    .clone();
+                                                TextBlock::new()
+                                                    .style("small_text")
+                                                    .v_align("center")
+                                                    .text(filter)
+                                                    .build(bc)
+                                            })
+                                            .on_changed_filter(vec!["selected_index"])
+                                            .on_changed(move |states, _, _| {
+                                                states
+                                                    .get_mut::<FileChooserState>(id)
+                                                    .action(FileChooserAction::Filter);
+                                            })
+                                            .attach(Grid::column(1))
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .child(
+                                Stack::new()
+                                    .orientation("horizontal")
+                                    .h_align("end")
+                                    .margin((0.0, 8.0, 0.0, 0.0))
+                                    .attach(Grid::row(3))
+                                    .child(
+                                        Button::new()
+                                            .id(ID_ACCEPT)
+                                            .style("button_primary")
+                                            .text("Open")
+                                            .min_width(80.0)
+                                            .margin((8.0, 0.0, 0.0, 0.0))
+                                            .on_click(move |states, _| {
+                                                accept(states);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        Button::new()
+                                            .text(DialogResult::Cancel.text())
+                                            .min_width(80.0)
+                                            .margin((8.0, 0.0, 0.0, 0.0))
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get_mut::<FileChooserState>(id)
+                                                    .action(FileChooserAction::Cancel);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_pattern() {
+        assert!(matches_pattern("main.rs", "*.rs"));
+        assert!(matches_pattern("MAIN.RS", "*.rs"));
+        assert!(matches_pattern("main.rs", "*"));
+        assert!(matches_pattern("main.rs", "ma?n.*"));
+        assert!(matches_pattern("a.tar.gz", "*.gz"));
+        assert!(!matches_pattern("main.rs", "*.toml"));
+        assert!(!matches_pattern("main.rs", "main"));
+        assert!(!matches_pattern("rs", "*.rs"));
+    }
+
+    #[test]
+    fn test_filter_patterns() {
+        assert_eq!(
+            filter_patterns("Images (*.png; *.jpg)"),
+            vec!["*.png", "*.jpg"]
+        );
+        assert_eq!(filter_patterns("*.rs"), vec!["*.rs"]);
+        assert!(filter_patterns("").is_empty());
+    }
+
+    #[test]
+    fn test_read_directory() {
+        let directory = std::env::temp_dir().join(format!(
+            "orbtk_file_chooser_test_read_directory_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(directory.join("sub")).unwrap();
+        fs::write(directory.join("b.rs"), "").unwrap();
+        fs::write(directory.join("A.toml"), "").unwrap();
+        fs::write(directory.join(".hidden"), "").unwrap();
+
+        let names = |patterns: &[&str]| -> Vec<String> {
+            read_directory(&directory, patterns)
+                .unwrap()
+                .into_iter()
+                .map(|entry| entry.name)
+                .collect()
+        };
+
+        assert_eq!(names(&[]), vec!["sub", "A.toml", "b.rs"]);
+        assert_eq!(names(&["*.rs"]), vec!["sub", "b.rs"]);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -1,10 +1,49 @@
 use crate::{api::prelude::*, proc_macros::*, theme::prelude::*};
 
+// Resolves icon names like `app.save` by the icon registry of the application.
+#[derive(Default, AsAny)]
+struct FontIconBlockState {
+    // icon and size of the last resolved icon
+    resolved: Option<(String, f64)>,
+}
+
+impl FontIconBlockState {
+    fn resolve_icon(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        let icon = ctx.widget().clone::<String>("icon");
+        let icon_size = *ctx.widget().get::<f64>("icon_size");
+
+        if self.resolved == Some((icon.clone(), icon_size)) {
+            return;
+        }
+
+        let resolved_icon = registry
+            .try_get::<IconRegistry>("icons")
+            .and_then(|icons| icons.icon(&icon, icon_size));
+
+        self.resolved = Some((icon, icon_size));
+
+        // images with the same source are equal, so the value is replaced without a comparison
+        *ctx.widget().get_mut::<Option<Icon>>("resolved_icon") = resolved_icon;
+    }
+}
+
+impl State for FontIconBlockState {
+    fn init(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        self.resolve_icon(registry, ctx);
+    }
+
+    fn update(&mut self, registry: &mut Registry, ctx: &mut Context) {
+        self.resolve_icon(registry, ctx);
+    }
+}
+
 widget!(
-    /// The `FontIconBlock` widget is used to draw text. It is not interactive.
+    /// The `FontIconBlock` widget is used to draw an icon. It is not interactive.
+    ///
+    /// The icon is a glyph of the icon font or the name of an icon of the `IconRegistry`, e.g. `material.save`.
     ///
     /// **style:** `font-icon-block`
-    FontIconBlock {
+    FontIconBlock<FontIconBlockState> {
         /// Sets or shares the icon property.
         icon: String,
 
@@ -15,7 +54,10 @@ widget!(
         icon_size: f64,
 
         /// Sets or shares the icon font property.
-        icon_font: String
+        icon_font: String,
+
+        /// Sets or shares the icon that is resolved from the icon registry if the icon property is an icon name.
+        resolved_icon: Option<Icon>
     }
 );
 
@@ -27,6 +69,7 @@ impl Template for FontIconBlock {
             .icon_brush(colors::LINK_WATER_COLOR)
             .icon_size(fonts::ICON_FONT_SIZE_12)
             .icon_font("MaterialIcons-Regular")
+            .resolved_icon(None)
     }
 
     fn render_object(&self) -> Box<dyn RenderObject> {
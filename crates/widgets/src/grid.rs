@@ -4,7 +4,7 @@ widget!(
     /// The `Grid` defines a flexible grid area that consists of columns and rows.
     ///
     /// **style:** `grid`
-    Grid {
+    Grid: DragDropHandler, MessageHandler, TouchHandler {
         /// Sets or shares the background property.
         background: Brush,
 
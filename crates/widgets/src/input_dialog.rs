@@ -0,0 +1,175 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+static ID_INPUT: &str = "input_dialog_input";
+
+// --- KEYS --
+
+/// The `InputDialogState` closes the modal with the entered text or without a result if it is cancelled.
+#[derive(Default, AsAny)]
+pub struct InputDialogState {
+    action: Option<DialogResult>,
+}
+
+impl InputDialogState {
+    fn action(&mut self, action: DialogResult) {
+        self.action = Some(action);
+    }
+}
+
+impl State for InputDialogState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(input) = ctx.entity_of_child(ID_INPUT) {
+            ctx.push_event_by_window(FocusEvent::RequestFocus(input));
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        match self.action.take() {
+            Some(DialogResult::Ok) => {
+                let value = ctx.widget().get::<String16>("value").as_string();
+                ctx.close_modal(value);
+            }
+            Some(_) => ctx.cancel_modal(),
+            None => {}
+        }
+    }
+}
+
+widget!(
+    /// The `InputDialog` asks the user to enter a text. It is shown as modal with `InputDialog::show`.
+    ///
+    /// **style:** `dialog`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// InputDialog::new()
+    ///     .title("Rename")
+    ///     .text("Enter the new name:")
+    ///     .value("untitled")
+    ///     .show(ctx, |states, name| println!("{:?}", name));
+    /// ```
+    InputDialog<InputDialogState>: KeyDownHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the title of the dialog window.
+        title: String,
+
+        /// Sets or shares the prompt text.
+        text: String16,
+
+        /// Sets or shares the entered text.
+        value: String16,
+
+        /// Sets or shares the water_mark text of the input.
+        water_mark: String16
+    }
+);
+
+impl InputDialog {
+    /// Shows the input dialog as modal window. `on_result` gets the entered text or `None` if the dialog is cancelled.
+    pub fn show<H: Fn(&mut StatesContext, Option<String>) + 'static>(
+        self,
+        ctx: &mut Context,
+        on_result: H,
+    ) {
+        show_dialog(ctx, self, (360.0, 170.0), on_result);
+    }
+}
+
+impl Template for InputDialog {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("InputDialog")
+            .style(STYLE_DIALOG)
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .padding(12.0)
+            .title("")
+            .text("")
+            .value("")
+            .water_mark("")
+            .on_key_down(move |states, event| -> bool {
+                if event.key == Key::Escape {
+                    states
+                        .get_mut::<InputDialogState>(id)
+                        .action(DialogResult::Cancel);
+                    return true;
+                }
+
+                false
+            })
+            .child(
+                Container::new()
+                    .background(id)
+                    .padding(id)
+                    .child(
+                        Grid::new()
+                            .rows(Rows::create().push("auto").push("auto").push("*"))
+                            .child(
+                                TextBlock::new()
+                                    .style("body")
+                                    .text(id)
+                                    .attach(Grid::row(0))
+                                    .build(ctx),
+                            )
+                            .child(
+                                TextBox::new()
+                                    .id(ID_INPUT)
+                                    .text(("value", id))
+                                    .water_mark(id)
+                                    .margin((0.0, 8.0, 0.0, 0.0))
+                                    .lost_focus_on_activation(false)
+                                    .on_activate(move |states, _| {
+                                        states
+                                            .get_mut::<InputDialogState>(id)
+                                            .action(DialogResult::Ok);
+                                    })
+                                    .attach(Grid::row(1))
+                                    .build(ctx),
+                            )
+                            .child(
+                                Stack::new()
+                                    .orientation("horizontal")
+                                    .h_align("end")
+                                    .v_align("end")
+                                    .attach(Grid::row(2))
+                                    .child(
+                                        Button::new()
+                                            .style("button_primary")
+                                            .text(DialogResult::Ok.text())
+                                            .min_width(80.0)
+                                            .margin((8.0, 0.0, 0.0, 0.0))
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get_mut::<InputDialogState>(id)
+                                                    .action(DialogResult::Ok);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .child(
+                                        Button::new()
+                                            .text(DialogResult::Cancel.text())
+                                            .min_width(80.0)
+                                            .margin((8.0, 0.0, 0.0, 0.0))
+                                            .on_click(move |states, _| {
+                                                states
+                                                    .get_mut::<InputDialogState>(id)
+                                                    .action(DialogResult::Cancel);
+                                                true
+                                            })
+                                            .build(ctx),
+                                    )
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -16,11 +16,15 @@ pub use self::check_box::*;
 pub use self::combo_box::*;
 pub use self::container::*;
 pub use self::cursor::*;
+pub use self::dialog::*;
+pub use self::file_chooser::*;
 pub use self::font_icon_block::*;
 pub use self::grid::*;
 pub use self::image_widget::*;
+pub use self::input_dialog::*;
 pub use self::items_widget::*;
 pub use self::list_view::*;
+pub use self::message_box::*;
 pub use self::numeric_box::*;
 pub use self::popup::*;
 pub use self::progress_bar::*;
@@ -43,11 +47,15 @@ mod check_box;
 mod combo_box;
 mod container;
 mod cursor;
+mod dialog;
+mod file_chooser;
 mod font_icon_block;
 mod grid;
 mod image_widget;
+mod input_dialog;
 mod items_widget;
 mod list_view;
+mod message_box;
 mod numeric_box;
 mod popup;
 mod progress_bar;
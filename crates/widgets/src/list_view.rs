@@ -91,11 +91,9 @@ impl State for ListViewState {
             let selected = !widget.get::<bool>("selected");
             widget.set("selected", selected);
 
-            if selected {
-                widget.get_mut::<Selector>("selector").set_state("selected");
-            } else {
-                widget.get_mut::<Selector>("selector").clear_state();
-            }
+            widget
+                .get_mut::<Selector>("selector")
+                .set_state_active("selected", selected);
 
             widget.update(false);
         }
@@ -198,7 +196,7 @@ widget!(
     /// The `ListViewItem` describes an item inside of a `ListView`.
     ///
     /// **style:** `list-view``
-    ListViewItem<ListViewItemState>: MouseHandler {
+    ListViewItem<ListViewItemState>: MouseHandler, DragDropHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -276,7 +274,7 @@ widget!(
     /// The `ListView` is an items drawer widget with selectable items.
     ///
     /// **style:** `items-widget`
-    ListView<ListViewState> : SelectionChangedHandler {
+    ListView<ListViewState> : SelectionChangedHandler, DragDropHandler {
         /// Sets or shares the background property.
         background: Brush,
 
@@ -0,0 +1,251 @@
+use crate::{api::prelude::*, prelude::*, proc_macros::*, shell::prelude::*, theme::prelude::*};
+
+// --- KEYS --
+
+pub static STYLE_MESSAGE_BOX_ICON: &str = "message_box_icon";
+static ID_BUTTON_YES: &str = "message_box_yes";
+static ID_BUTTON_NO: &str = "message_box_no";
+static ID_BUTTON_OK: &str = "message_box_ok";
+static ID_BUTTON_CANCEL: &str = "message_box_cancel";
+
+// --- KEYS --
+
+#[derive(Copy, Clone)]
+enum MessageBoxAction {
+    Accept,
+    Cancel,
+    Close(DialogResult),
+}
+
+/// The `MessageBoxState` shows the requested buttons and closes the modal with the result of the pressed button.
+#[derive(Default, AsAny)]
+pub struct MessageBoxState {
+    action: Option<MessageBoxAction>,
+}
+
+impl MessageBoxState {
+    fn action(&mut self, action: MessageBoxAction) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MessageBoxState {
+    fn init(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let results = ctx.widget().get::<DialogButtons>("buttons").results();
+
+        for (id, result) in &[
+            (ID_BUTTON_YES, DialogResult::Yes),
+            (ID_BUTTON_NO, DialogResult::No),
+            (ID_BUTTON_OK, DialogResult::Ok),
+            (ID_BUTTON_CANCEL, DialogResult::Cancel),
+        ] {
+            if !results.contains(result) {
+                ctx.child(*id).set("visibility", Visibility::Collapsed);
+            }
+        }
+    }
+
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let buttons = *ctx.widget().get::<DialogButtons>("buttons");
+
+        let result = match self.action.take() {
+            Some(MessageBoxAction::Accept) => buttons.default_result(),
+            Some(MessageBoxAction::Cancel) => buttons.cancel_result(),
+            Some(MessageBoxAction::Close(result)) => result,
+            None => return,
+        };
+
+        ctx.close_modal(result);
+    }
+}
+
+widget!(
+    /// The `MessageBox` displays a message with an icon and a set of buttons. It is shown as modal with
+    /// `MessageBox::show`, the pressed button is returned as `DialogResult`.
+    ///
+    /// **style:** `dialog`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// MessageBox::new()
+    ///     .title("Delete")
+    ///     .text("The file will be deleted.")
+    ///     .icon(material_icons_font::MD_WARNING)
+    ///     .buttons("ok_cancel")
+    ///     .show(ctx, |states, result| println!("{:?}", result));
+    /// ```
+    MessageBox<MessageBoxState>: KeyDownHandler {
+        /// Sets or shares the background property.
+        background: Brush,
+
+        /// Sets or shares the padding property.
+        padding: Thickness,
+
+        /// Sets or shares the title of the dialog window.
+        title: String,
+
+        /// Sets or shares the message text.
+        text: String16,
+
+        /// Sets or shares the icon glyph of the message.
+        icon: String,
+
+        /// Sets or shares the buttons of the message box.
+        buttons: DialogButtons
+    }
+);
+
+impl MessageBox {
+    /// Shows the message box as modal window. The result of the pressed button is passed to `on_result`, closing the
+    /// window without a button results in `DialogResult::Cancel`.
+    pub fn show<H: Fn(&mut StatesContext, DialogResult) + 'static>(
+        self,
+        ctx: &mut Context,
+        on_result: H,
+    ) {
+        show_dialog(
+            ctx,
+            self,
+            (360.0, 160.0),
+            move |states, result: Option<DialogResult>| {
+                on_result(states, result.unwrap_or(DialogResult::Cancel))
+            },
+        );
+    }
+
+    /// Shows a confirmation with `Yes` and `No` buttons as modal window. `on_result` gets `true` if it is confirmed.
+    pub fn confirm<H: Fn(&mut StatesContext, bool) + 'static>(
+        ctx: &mut Context,
+        title: impl Into<String>,
+        text: impl Into<String16>,
+        on_result: H,
+    ) {
+        MessageBox::new()
+            .title(title.into())
+            .text(text.into())
+            .icon(material_icons_font::MD_HELP_OUTLINE)
+            .buttons("yes_no")
+            .show(ctx, move |states, result| {
+                on_result(states, result == DialogResult::Yes)
+            });
+    }
+}
+
+// Creates a button of the message box that closes it with the given result.
+fn dialog_button(
+    ctx: &mut BuildContext,
+    id: Entity,
+    button_id: &str,
+    style: &str,
+    result: DialogResult,
+) -> Entity {
+    Button::new()
+        .id(button_id)
+        .style(style)
+        .text(result.text())
+        .min_width(80.0)
+        .margin((8.0, 0.0, 0.0, 0.0))
+        .on_click(move |states, _| {
+            states
+                .get_mut::<MessageBoxState>(id)
+                .action(MessageBoxAction::Close(result));
+            true
+        })
+        .build(ctx)
+}
+
+impl Template for MessageBox {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.name("MessageBox")
+            .style(STYLE_DIALOG)
+            .background(colors::BRIGHT_GRAY_COLOR)
+            .padding(12.0)
+            .title("")
+            .text("")
+            .icon(material_icons_font::MD_INFO)
+            .buttons("ok")
+            .on_key_down(move |states, event| -> bool {
+                match event.key {
+                    Key::Enter => states
+                        .get_mut::<MessageBoxState>(id)
+                        .action(MessageBoxAction::Accept),
+                    Key::Escape => states
+                        .get_mut::<MessageBoxState>(id)
+                        .action(MessageBoxAction::Cancel),
+                    _ => return false,
+                }
+
+                true
+            })
+            .child(
+                Container::new()
+                    .background(id)
+                    .padding(id)
+                    .child(
+                        Grid::new()
+                            .columns(Columns::create().push("auto").push("*"))
+                            .rows(Rows::create().push("*").push("auto"))
+                            .child(
+                                FontIconBlock::new()
+                                    .style(STYLE_MESSAGE_BOX_ICON)
+                                    .icon(id)
+                                    .icon_size(32.0)
+                                    .v_align("start")
+                                    .margin((0.0, 0.0, 12.0, 0.0))
+                                    .attach(Grid::column(0))
+                                    .attach(Grid::row(0))
+                                    .build(ctx),
+                            )
+                            .child(
+                                TextBlock::new()
+                                    .style("body")
+                                    .text(id)
+                                    .v_align("start")
+                                    .attach(Grid::column(1))
+                                    .attach(Grid::row(0))
+                                    .build(ctx),
+                            )
+                            .child(
+                                Stack::new()
+                                    .orientation("horizontal")
+                                    .h_align("end")
+                                    .attach(Grid::column(0))
+                                    .attach(Grid::column_span(2))
+                                    .attach(Grid::row(1))
+                                    .child(dialog_button(
+                                        ctx,
+                                        id,
+                                        ID_BUTTON_YES,
+                                        "button_primary",
+                                        DialogResult::Yes,
+                                    ))
+                                    .child(dialog_button(
+                                        ctx,
+                                        id,
+                                        ID_BUTTON_NO,
+                                        "button",
+                                        DialogResult::No,
+                                    ))
+                                    .child(dialog_button(
+                                        ctx,
+                                        id,
+                                        ID_BUTTON_OK,
+                                        "button_primary",
+                                        DialogResult::Ok,
+                                    ))
+                                    .child(dialog_button(
+                                        ctx,
+                                        id,
+                                        ID_BUTTON_CANCEL,
+                                        "button",
+                                        DialogResult::Cancel,
+                                    ))
+                                    .build(ctx),
+                            )
+                            .build(ctx),
+                    )
+                    .build(ctx),
+            )
+    }
+}
@@ -10,7 +10,7 @@ widget!(
     /// The `ScrollBar` widget represents a position inside of a scroll container.
     ///
     /// **style:** `scroll_bar`
-    ScrollBar {
+    ScrollBar: MouseHandler {
         /// Sets or shares the background property.
         background: Brush,
 
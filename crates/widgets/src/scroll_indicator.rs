@@ -10,6 +10,62 @@ static ID_SCROLL_BAR_VERTICAL: &str = "scroll_bar_vertical";
 pub struct ScrollIndicatorState {
     horizontal_scroll_bar: Entity,
     vertical_scroll_bar: Entity,
+    capture: Option<bool>,
+    drag_orientation: Option<Orientation>,
+    drag_position: Point,
+    move_position: Option<Point>,
+}
+
+impl ScrollIndicatorState {
+    // starts dragging of a scroll bar
+    fn start_drag(&mut self, orientation: Orientation, position: Point) {
+        self.capture = Some(true);
+        self.drag_orientation = Some(orientation);
+        self.drag_position = position;
+    }
+
+    fn drag(&mut self, position: Point) {
+        self.move_position = Some(position);
+    }
+
+    fn is_dragging(&self) -> bool {
+        self.drag_orientation.is_some()
+    }
+
+    fn stop_drag(&mut self) {
+        self.capture = Some(false);
+        self.drag_orientation = None;
+    }
+
+    // moves the scroll padding by the delta of the dragged scroll bar
+    fn scroll(&mut self, ctx: &mut Context, orientation: Orientation, position: Point) {
+        let delta = position - self.drag_position;
+        self.drag_position = position;
+
+        let size = ctx.widget().get::<Rectangle>("bounds").size();
+        let content_size = ctx.widget().get::<Rectangle>("content_bounds").size();
+        let view_port_size = ctx.widget().get::<Rectangle>("view_port_bounds").size();
+        let mut scroll_padding = *ctx.widget().get::<Thickness>("scroll_padding");
+
+        match orientation {
+            Orientation::Vertical => scroll_padding.set_top(scroll_offset(
+                size.height(),
+                content_size.height(),
+                view_port_size.height(),
+                scroll_padding.top(),
+                delta.y(),
+            )),
+            Orientation::Horizontal => scroll_padding.set_left(scroll_offset(
+                size.width(),
+                content_size.width(),
+                view_port_size.width(),
+                scroll_padding.left(),
+                delta.x(),
+            )),
+        }
+
+        ctx.widget().set("scroll_padding", scroll_padding);
+    }
 }
 
 impl State for ScrollIndicatorState {
@@ -18,10 +74,27 @@ impl State for ScrollIndicatorState {
             .entity_of_child(ID_SCROLL_BAR_HORIZONTAL)
             .expect("ScrollIndicatorState.init: scroll_bar_horizontal child could not be found.");
         self.vertical_scroll_bar = ctx
-            .entity_of_child(ID_SCROLL_BAR_HORIZONTAL)
+            .entity_of_child(ID_SCROLL_BAR_VERTICAL)
             .expect("ScrollIndicatorState.init: scroll_bar_vertical child could not be found.");
     }
 
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // scroll bars could be dragged outside of the bounds of the indicator
+        match self.capture.take() {
+            Some(true) => ctx.capture_mouse(),
+            Some(false) => ctx.release_mouse_capture(),
+            None => {}
+        }
+
+        if let Some(position) = self.move_position.take() {
+            if let Some(orientation) = self.drag_orientation {
+                if ctx.has_mouse_capture() {
+                    self.scroll(ctx, orientation, position);
+                }
+            }
+        }
+    }
+
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
         let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
 
@@ -89,7 +162,7 @@ impl State for ScrollIndicatorState {
 
 widget!(
     /// The `ScrollIndicator` widget contains two scroll bars.
-    ScrollIndicator<ScrollIndicatorState> {
+    ScrollIndicator<ScrollIndicatorState>: MouseHandler {
         /// Shares the mode of the `ScrollViewer`.
         mode: ScrollViewerMode,
 
@@ -117,27 +190,52 @@ impl Template for ScrollIndicator {
                 Grid::new()
                     .child(
                         ScrollBar::new()
-                            .id(ID_SCROLL_BAR_HORIZONTAL)
+                            .id(ID_SCROLL_BAR_VERTICAL)
                             .visibility("collapsed")
                             .min_height(8.0)
                             .margin((0.0, 0.0, 0.0, 6.0))
                             .h_align("end")
                             .opacity(id)
+                            .on_mouse_down(move |states, m| {
+                                states
+                                    .get_mut::<ScrollIndicatorState>(id)
+                                    .start_drag(Orientation::Vertical, m.position);
+                                true
+                            })
                             .build(ctx),
                     )
                     .child(
                         ScrollBar::new()
-                            .id(ID_SCROLL_BAR_VERTICAL)
+                            .id(ID_SCROLL_BAR_HORIZONTAL)
                             .visibility("collapsed")
                             .min_width(8.0)
                             .margin((0.0, 0.0, 6.0, 0.0))
                             .height(4.0)
                             .v_align("end")
                             .opacity(id)
+                            .on_mouse_down(move |states, m| {
+                                states
+                                    .get_mut::<ScrollIndicatorState>(id)
+                                    .start_drag(Orientation::Horizontal, m.position);
+                                true
+                            })
                             .build(ctx),
                     )
                     .build(ctx),
             )
+            // the state is only changed while a scroll bar is dragged, `get_mut` marks it as dirty
+            .on_mouse_move(move |states, p| {
+                if states.get::<ScrollIndicatorState>(id).is_dragging() {
+                    states.get_mut::<ScrollIndicatorState>(id).drag(p);
+                }
+                false
+            })
+            .on_mouse_up(move |states, _| {
+                if states.get::<ScrollIndicatorState>(id).is_dragging() {
+                    states.get_mut::<ScrollIndicatorState>(id).stop_drag();
+                }
+                false
+            })
     }
 
     fn layout(&self) -> Box<dyn Layout> {
@@ -155,6 +253,19 @@ fn offset(size: f64, content_size: f64, offset: f64) -> f64 {
     size * offset / content_size
 }
 
+// Calculates the new scroll offset of the content if the scroll bar is moved by delta.
+fn scroll_offset(
+    size: f64,
+    content_size: f64,
+    view_port_size: f64,
+    offset: f64,
+    delta: f64,
+) -> f64 {
+    (offset - delta * content_size / size)
+        .min(0.0)
+        .max(view_port_size - content_size)
+}
+
 // --- Helpers --
 
 #[cfg(test)]
@@ -182,4 +293,23 @@ mod tests {
 
         assert!((offset(size, content_size, offset_in) - 2.).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn test_scroll_offset() {
+        let size = 50.;
+        let content_size = 200.;
+        let view_port_size = 50.;
+
+        assert!(
+            (scroll_offset(size, content_size, view_port_size, 0., 5.) + 20.).abs() < f64::EPSILON
+        );
+        assert!(
+            (scroll_offset(size, content_size, view_port_size, -20., -10.) - 0.).abs()
+                < f64::EPSILON
+        );
+        assert!(
+            (scroll_offset(size, content_size, view_port_size, -20., 100.) + 150.).abs()
+                < f64::EPSILON
+        );
+    }
 }
@@ -4,18 +4,33 @@ use crate::{api::prelude::*, proc_macros::*};
 #[derive(Default, AsAny)]
 pub struct ScrollViewerState {
     delta: Option<Point>,
+    pan_delta: Option<Point>,
 }
 
 impl ScrollViewerState {
     fn scroll(&mut self, delta: Point) {
         self.delta = Some(delta);
     }
+
+    fn pan(&mut self, delta: Point) {
+        // pan events of one frame are summed up
+        self.pan_delta = Some(self.pan_delta.map_or(delta, |current| current + delta));
+    }
 }
 
 impl State for ScrollViewerState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
-        if let Some(delta) = self.delta {
-            self.delta = None;
+        let speed = *ctx.widget().get::<f64>("speed");
+
+        // the wheel delta is scaled by the speed, panned content follows the finger
+        let delta = match (self.delta.take(), self.pan_delta.take()) {
+            (Some(delta), pan_delta) => Some(
+                Point::new(delta.x() * speed, delta.y() * speed) + pan_delta.unwrap_or_default(),
+            ),
+            (None, pan_delta) => pan_delta,
+        };
+
+        if let Some(delta) = delta {
             let mode = *ctx.widget().get::<ScrollViewerMode>("mode");
 
             if mode.vertical != ScrollMode::Auto && mode.horizontal != ScrollMode::Auto {
@@ -23,7 +38,6 @@ impl State for ScrollViewerState {
             }
 
             let size = ctx.widget().get::<Rectangle>("bounds").size();
-            let speed = *ctx.widget().get::<f64>("speed");
             let mut padding = *ctx.widget().get::<Thickness>("padding");
 
             if let Some(child) = &mut ctx.try_child_from_index(0) {
@@ -34,7 +48,7 @@ impl State for ScrollViewerState {
                         size.height(),
                         child_size.height(),
                         padding.top(),
-                        delta.y() * speed,
+                        delta.y(),
                     ));
                 }
 
@@ -43,7 +57,7 @@ impl State for ScrollViewerState {
                         size.width(),
                         child_size.width(),
                         padding.left(),
-                        delta.x() * speed,
+                        delta.x(),
                     ));
                 }
             } else {
@@ -58,7 +72,9 @@ impl State for ScrollViewerState {
 widget!(
     /// The `ScrollViewer` is used to scroll its child vertical and or horizontal.
     /// Only the first child of the scroll viewer can be scrolled.
-    ScrollViewer<ScrollViewerState>: MouseHandler {
+    ///
+    /// On touch screens the child follows the finger and keeps moving with decreasing speed after a swipe.
+    ScrollViewer<ScrollViewerState>: MouseHandler, TouchHandler {
         /// Sets or shares the scroll mode property.
         mode: ScrollViewerMode,
 
@@ -81,6 +97,10 @@ impl Template for ScrollViewer {
                 states.get_mut::<ScrollViewerState>(id).scroll(p);
                 false
             })
+            .on_pan(move |states, _, delta| {
+                states.get_mut::<ScrollViewerState>(id).pan(delta);
+                true
+            })
     }
 
     fn layout(&self) -> Box<dyn Layout> {
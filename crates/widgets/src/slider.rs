@@ -15,6 +15,7 @@ enum SliderAction {
 #[derive(Default, AsAny)]
 pub struct SliderState {
     action: Option<SliderAction>,
+    capture: Option<bool>,
     val: f64,
     min: f64,
     max: f64,
@@ -28,6 +29,11 @@ impl SliderState {
         self.action = Some(action);
     }
 
+    // requests (`true`) or releases (`false`) the mouse capture
+    fn capture(&mut self, capture: bool) {
+        self.capture = Some(capture);
+    }
+
     // adjust min, max and val
     fn adjust(&mut self, ctx: &mut Context) -> bool {
         let mut has_changes = false;
@@ -105,11 +111,20 @@ impl State for SliderState {
             .expect("SliderState.init: Track child could not be found.");
     }
 
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // the thumb could be dragged outside of the bounds of the slider
+        match self.capture.take() {
+            Some(true) => ctx.capture_mouse(),
+            Some(false) => ctx.release_mouse_capture(),
+            None => {}
+        }
+    }
+
     fn update_post_layout(&mut self, _: &mut Registry, ctx: &mut Context) {
         if let Some(action) = self.action {
             match action {
                 SliderAction::Move { mouse_x } => {
-                    if *ctx.get_widget(self.thumb).get::<bool>("pressed") {
+                    if ctx.has_mouse_capture() {
                         let thumb_width = ctx
                             .get_widget(self.thumb)
                             .get::<Rectangle>("bounds")
@@ -209,6 +224,10 @@ impl Template for Slider {
                             .max_width(24.0)
                             .max_height(24.0)
                             .border_radius(12.0)
+                            .on_mouse_down(move |states, _| {
+                                states.get_mut::<SliderState>(id).capture(true);
+                                false
+                            })
                             .build(ctx),
                     )
                     .build(ctx),
@@ -219,6 +238,10 @@ impl Template for Slider {
                     .action(SliderAction::Move { mouse_x: p.x() });
                 false
             })
+            .on_mouse_up(move |states, _| {
+                states.get_mut::<SliderState>(id).capture(false);
+                false
+            })
     }
 }
 
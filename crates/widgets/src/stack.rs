@@ -4,12 +4,14 @@ widget!(
     /// The `Stack` defines a layout that is used to stack its children vertical or horizontal.
     ///
     /// **style:** `stack`
-    Stack {
+    Stack: DragDropHandler, MessageHandler, TouchHandler {
         /// Sets or shares the orientation property.
         orientation: Orientation,
 
         /// Margin between widgets in the stack.
         spacing: f64
+
+        theme_properties: { orientation }
     }
 );
 
@@ -45,14 +45,14 @@ impl State for SwitchState {
 
             if self.selected {
                 switch_toggle.set("h_align", Alignment::from("end"));
-                switch_toggle
-                    .get_mut::<Selector>("selector")
-                    .set_state("selected");
             } else {
                 switch_toggle.set("h_align", Alignment::from("start"));
-                switch_toggle.get_mut::<Selector>("selector").clear_state();
             }
 
+            switch_toggle
+                .get_mut::<Selector>("selector")
+                .set_state_active("selected", self.selected);
+
             switch_toggle.update(true);
         }
 
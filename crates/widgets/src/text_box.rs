@@ -77,8 +77,23 @@ impl Template for TextBox {
             .text_selection(id)
             .build(ctx);
 
+        // underlines the composed text of input methods, it is always expanded to keep the text block offset
+        // of the cursor untouched
+        let preedit_cursor = Cursor::new()
+            .style("preedit")
+            .h_align("start")
+            .v_align("end")
+            .height(1.0)
+            .visibility("collapsed")
+            .text_block(text_block.0)
+            .focused(id)
+            .expanded(true)
+            .text_selection(TextSelection::default())
+            .build(ctx);
+
         let text_behavior = TextBehavior::new()
             .cursor(cursor.0)
+            .preedit_cursor(preedit_cursor.0)
             .focused(id)
             .font(id)
             .font_size(id)
@@ -120,6 +135,7 @@ impl Template for TextBox {
                             // It is important that cursor is the first child
                             // should be refactored in the future.
                             .child(cursor)
+                            .child(preedit_cursor)
                             .child(text_block)
                             .build(ctx),
                     )
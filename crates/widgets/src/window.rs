@@ -35,6 +35,12 @@ impl WindowState {
             .set_size(width, height);
     }
 
+    // Text is measured in physical pixels, the layout of the whole window is updated with the new scale factor.
+    fn scale_factor_changed(&self, ctx: &mut Context) {
+        ctx.window().update_dirty(true);
+        ctx.send_window_request(WindowRequest::Redraw);
+    }
+
     fn active_changed(&self, active: bool, ctx: &mut Context) {
         window(ctx.widget()).set_active(active);
 
@@ -65,7 +71,7 @@ impl WindowState {
             old_focused_element.set("focused", false);
             old_focused_element
                 .get_mut::<Selector>("selector")
-                .clear_state();
+                .remove_state("focused");
             old_focused_element.update(false);
         }
 
@@ -77,7 +83,7 @@ impl WindowState {
             focused_element.set("focused", true);
             focused_element
                 .get_mut::<Selector>("selector")
-                .set_state("focused");
+                .add_state("focused");
             focused_element.update(false);
         }
     }
@@ -91,7 +97,7 @@ impl WindowState {
             old_focused_element.set("focused", false);
             old_focused_element
                 .get_mut::<Selector>("selector")
-                .clear_state();
+                .remove_state("focused");
             old_focused_element.update(false);
         }
 
@@ -132,6 +138,9 @@ impl State for WindowState {
                     WindowEvent::ActiveChanged(active) => {
                         self.active_changed(active, ctx);
                     }
+                    WindowEvent::ScaleFactorChanged(_) => {
+                        self.scale_factor_changed(ctx);
+                    }
                     _ => {}
                 },
                 Action::FocusEvent(focus_event) => match focus_event {
@@ -152,7 +161,7 @@ widget!(
     /// It also contains global properties like keyboard modifier and focused widget.
     ///
     /// **style:** `window`
-    Window<WindowState> {
+    Window<WindowState>: MessageHandler {
         /// Sets or shares the background property.
         background: Brush,
 
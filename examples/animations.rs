@@ -0,0 +1,173 @@
+use orbtk::prelude::*;
+
+const COLORS: [&str; 3] = ["#5b0f22", "#0f5b3a", "#0f2f5b"];
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Action {
+    Fade,
+    Move,
+    Color,
+    Storyboard,
+}
+
+#[derive(AsAny, Default)]
+struct MainState {
+    action: Option<Action>,
+    faded: bool,
+    moved: bool,
+    color: usize,
+    completed: bool,
+}
+
+impl MainState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MainState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let id = ctx.entity;
+        let target = ctx.entity_of_child("box").unwrap();
+
+        match self.action.take() {
+            Some(Action::Fade) => {
+                self.faded = !self.faded;
+                let opacity = if self.faded { 0.2 } else { 1.0 };
+
+                ctx.animate(
+                    target,
+                    PropertyAnimation::<f32>::new("opacity", opacity)
+                        .duration(400.0)
+                        .easing(Easing::EaseInOut),
+                );
+            }
+            Some(Action::Move) => {
+                self.moved = !self.moved;
+                let margin = if self.moved { 120.0 } else { 0.0 };
+
+                ctx.animate(
+                    target,
+                    PropertyAnimation::<Thickness>::new("margin", (margin, 0.0, 0.0, 0.0))
+                        .duration(600.0)
+                        .easing(Easing::BackOut),
+                );
+            }
+            Some(Action::Color) => {
+                self.color = (self.color + 1) % COLORS.len();
+
+                ctx.animate(
+                    target,
+                    PropertyAnimation::<Brush>::new("background", COLORS[self.color])
+                        .duration(500.0),
+                );
+            }
+            Some(Action::Storyboard) => {
+                ctx.start_storyboard(
+                    Storyboard::new()
+                        .animate(
+                            target,
+                            PropertyAnimation::<f64>::new("border_radius", 40.0)
+                                .duration(300.0)
+                                .easing(Easing::QuadOut),
+                        )
+                        .animate(
+                            target,
+                            PropertyAnimation::<f32>::new("opacity", 0.5).duration(300.0),
+                        )
+                        .then(
+                            target,
+                            PropertyAnimation::<f64>::new("border_radius", 0.0)
+                                .duration(600.0)
+                                .easing(Easing::BounceOut),
+                        )
+                        .animate(
+                            target,
+                            PropertyAnimation::<f32>::new("opacity", 1.0).duration(600.0),
+                        )
+                        .on_completed(move |states| {
+                            states.get_mut::<MainState>(id).completed = true;
+                        }),
+                );
+
+                self.faded = false;
+                text_block(ctx.child("status")).set_text("Storyboard running");
+            }
+            None => {}
+        }
+
+        if self.completed {
+            self.completed = false;
+            text_block(ctx.child("status")).set_text("Storyboard completed");
+        }
+    }
+}
+
+widget!(MainView<MainState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let button = |text: &str, action: Action, ctx: &mut BuildContext| {
+            Button::new()
+                .on_click(move |states, _| {
+                    states.get_mut::<MainState>(id).action(action);
+                    true
+                })
+                .margin(4.0)
+                .text(text)
+                .build(ctx)
+        };
+
+        let fade = button("Fade", Action::Fade, ctx);
+        let move_button = button("Move", Action::Move, ctx);
+        let color = button("Color", Action::Color, ctx);
+        let storyboard = button("Storyboard", Action::Storyboard, ctx);
+
+        self.child(
+            Stack::new()
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .child(fade)
+                        .child(move_button)
+                        .child(color)
+                        .child(storyboard)
+                        .build(ctx),
+                )
+                .child(
+                    Container::new()
+                        .id("box")
+                        .width(100.0)
+                        .height(100.0)
+                        .h_align("start")
+                        .margin(0.0)
+                        .background(COLORS[0])
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .id("status")
+                        .text("")
+                        .margin(4.0)
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - animations example")
+                .position((100.0, 100.0))
+                .size(420.0, 260.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
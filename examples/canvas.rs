@@ -177,16 +177,29 @@ impl RenderPipeline for Graphic2DPipeline {
 #[derive(Default, AsAny)]
 pub struct MainViewState {
     cube_spin: f32,
+    animate: bool,
 }
 
 impl MainViewState {
     fn spin(&mut self) {
         self.cube_spin += 32.0;
     }
+
+    fn toggle_animation(&mut self) {
+        self.animate = !self.animate;
+    }
 }
 
 impl State for MainViewState {
     fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        // spins the cube continuously with the same speed independent of the frame rate
+        if self.animate && !ctx.is_animation_frame_requested() {
+            let id = ctx.entity;
+            ctx.request_animation_frame(move |states, frame| {
+                states.get_mut::<MainViewState>(id).cube_spin += frame.delta as f32;
+            });
+        }
+
         if let Some(cube) = ctx
             .widget()
             .get_mut::<DefaultRenderPipeline>("render_pipeline")
@@ -239,6 +252,19 @@ impl Template for MainView {
                             })
                             .build(ctx),
                     )
+                    .child(
+                        Button::new()
+                            .text("animate")
+                            .v_align("end")
+                            .h_align("end")
+                            .attach(Grid::row(1))
+                            .margin(4.0)
+                            .on_click(move |states, _| {
+                                states.get_mut::<MainViewState>(id).toggle_animation();
+                                true
+                            })
+                            .build(ctx),
+                    )
                     .child(
                         TextBlock::new()
                             .attach(Grid::row(2))
@@ -0,0 +1,133 @@
+use orbtk::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Action {
+    ShowMessage,
+    Confirm,
+    Input,
+    OpenFile,
+    SaveFile,
+}
+
+#[derive(AsAny, Default)]
+struct MainState {
+    action: Option<Action>,
+    result: Option<String>,
+}
+
+impl MainState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+// Stores the result of a dialog to show it on the next update of the main view.
+fn set_result(states: &mut StatesContext, id: Entity, result: String) {
+    states.get_mut::<MainState>(id).result = Some(result);
+}
+
+impl State for MainState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let id = ctx.entity;
+
+        match self.action.take() {
+            Some(Action::ShowMessage) => MessageBox::new()
+                .title("Message")
+                .text("The file was saved.")
+                .icon(material_icons_font::MD_INFO)
+                .show(ctx, move |states, result| {
+                    set_result(states, id, format!("{:?}", result))
+                }),
+            Some(Action::Confirm) => MessageBox::confirm(
+                ctx,
+                "Confirm",
+                "Do you want to quit?",
+                move |states, yes| set_result(states, id, format!("Confirmed: {}", yes)),
+            ),
+            Some(Action::Input) => InputDialog::new()
+                .title("Rename")
+                .text("Enter the new name:")
+                .value("untitled")
+                .show(ctx, move |states, name| {
+                    set_result(states, id, format!("Name: {:?}", name))
+                }),
+            Some(Action::OpenFile) => FileChooser::new()
+                .title("Open file")
+                .filters(vec![
+                    String::from("Rust files (*.rs)"),
+                    String::from("All files (*)"),
+                ])
+                .show(ctx, move |states, path| {
+                    set_result(states, id, format!("Open: {:?}", path))
+                }),
+            Some(Action::SaveFile) => FileChooser::new()
+                .title("Save file")
+                .mode("save")
+                .file_name("untitled.txt")
+                .show(ctx, move |states, path| {
+                    set_result(states, id, format!("Save: {:?}", path))
+                }),
+            None => {}
+        }
+
+        if let Some(result) = self.result.take() {
+            text_block(ctx.child("result")).set_text(result);
+        }
+    }
+}
+
+widget!(MainView<MainState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        let button = |text: &str, action: Action, ctx: &mut BuildContext| {
+            Button::new()
+                .on_click(move |states, _| {
+                    states.get_mut::<MainState>(id).action(action);
+                    true
+                })
+                .margin(4.0)
+                .text(text)
+                .build(ctx)
+        };
+
+        let message_box = button("Message box", Action::ShowMessage, ctx);
+        let confirm = button("Confirm", Action::Confirm, ctx);
+        let input = button("Input dialog", Action::Input, ctx);
+        let open_file = button("Open file", Action::OpenFile, ctx);
+        let save_file = button("Save file", Action::SaveFile, ctx);
+
+        self.child(
+            Stack::new()
+                .child(message_box)
+                .child(confirm)
+                .child(input)
+                .child(open_file)
+                .child(save_file)
+                .child(
+                    TextBlock::new()
+                        .id("result")
+                        .text("No result")
+                        .margin(4.0)
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - dialogs example")
+                .position((100.0, 100.0))
+                .size(420.0, 360.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
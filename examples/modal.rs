@@ -0,0 +1,171 @@
+use orbtk::prelude::*;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Action {
+    ShowModalWindow,
+    ShowModalOverlay,
+}
+
+#[derive(AsAny, Default)]
+struct MainState {
+    action: Option<Action>,
+    answer: Option<bool>,
+}
+
+impl MainState {
+    fn action(&mut self, action: Action) {
+        self.action = Some(action);
+    }
+}
+
+impl State for MainState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        let id = ctx.entity;
+
+        match self.action.take() {
+            Some(Action::ShowModalWindow) => ctx.show_modal_window(
+                |ctx| {
+                    Window::new()
+                        .title("Modal window")
+                        .size(220.0, 100.0)
+                        .child(DialogView::new().build(ctx))
+                        .build(ctx)
+                },
+                move |states, answer: Option<bool>| {
+                    states.get_mut::<MainState>(id).answer = answer;
+                },
+            ),
+            Some(Action::ShowModalOverlay) => ctx.show_modal(
+                |ctx| {
+                    Container::new()
+                        .width(220.0)
+                        .height(100.0)
+                        .background("#3b434a")
+                        .child(DialogView::new().build(ctx))
+                        .build(ctx)
+                },
+                move |states, answer: Option<bool>| {
+                    states.get_mut::<MainState>(id).answer = answer;
+                },
+            ),
+            None => {}
+        }
+
+        if let Some(answer) = self.answer.take() {
+            text_block(ctx.child("answer")).set_text(if answer { "Yes" } else { "No" });
+        }
+    }
+}
+
+widget!(MainView<MainState>);
+
+impl Template for MainView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.child(
+            Stack::new()
+                .child(
+                    Button::new()
+                        .on_click(move |states, _| {
+                            states
+                                .get_mut::<MainState>(id)
+                                .action(Action::ShowModalWindow);
+                            true
+                        })
+                        .margin(4.0)
+                        .text("Show modal window")
+                        .build(ctx),
+                )
+                .child(
+                    Button::new()
+                        .on_click(move |states, _| {
+                            states
+                                .get_mut::<MainState>(id)
+                                .action(Action::ShowModalOverlay);
+                            true
+                        })
+                        .margin(4.0)
+                        .text("Show modal overlay")
+                        .build(ctx),
+                )
+                .child(
+                    TextBlock::new()
+                        .id("answer")
+                        .text("No answer")
+                        .margin(4.0)
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+#[derive(AsAny, Default)]
+struct DialogState {
+    answer: Option<bool>,
+}
+
+impl State for DialogState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(answer) = self.answer.take() {
+            ctx.close_modal(answer);
+        }
+    }
+}
+
+widget!(DialogView<DialogState>);
+
+impl Template for DialogView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.child(
+            Stack::new()
+                .child(
+                    TextBlock::new()
+                        .text("Are you sure?")
+                        .margin(4.0)
+                        .build(ctx),
+                )
+                .child(
+                    Stack::new()
+                        .orientation("horizontal")
+                        .child(
+                            Button::new()
+                                .on_click(move |states, _| {
+                                    states.get_mut::<DialogState>(id).answer = Some(true);
+                                    true
+                                })
+                                .margin(4.0)
+                                .text("Yes")
+                                .build(ctx),
+                        )
+                        .child(
+                            Button::new()
+                                .on_click(move |states, _| {
+                                    states.get_mut::<DialogState>(id).answer = Some(false);
+                                    true
+                                })
+                                .margin(4.0)
+                                .text("No")
+                                .build(ctx),
+                        )
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+fn main() {
+    // use this only if you want to run it as web application.
+    orbtk::initialize();
+
+    Application::new()
+        .window(|ctx| {
+            Window::new()
+                .title("OrbTk - modal example")
+                .position((100.0, 100.0))
+                .size(420.0, 300.0)
+                .child(MainView::new().build(ctx))
+                .build(ctx)
+        })
+        .run();
+}
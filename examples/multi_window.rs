@@ -4,12 +4,17 @@ use orbtk::prelude::*;
 #[derive(AsAny, Default)]
 struct MainState {
     show_window: bool,
+    send_message: bool,
 }
 
 impl MainState {
     fn show_window(&mut self) {
         self.show_window = true;
     }
+
+    fn send_message(&mut self) {
+        self.send_message = true;
+    }
 }
 
 impl State for MainState {
@@ -30,6 +35,11 @@ impl State for MainState {
             });
             self.show_window = false;
         }
+
+        if self.send_message {
+            ctx.send_message("window_2", String::from("Hello from window 1"));
+            self.send_message = false;
+        }
     }
 }
 
@@ -51,6 +61,56 @@ impl Template for MainView {
                         .text("Show window")
                         .build(ctx),
                 )
+                .child(
+                    Button::new()
+                        .on_click(move |states, _| {
+                            states.get_mut::<MainState>(id).send_message();
+                            true
+                        })
+                        .margin(4.0)
+                        .text("Send message to window 2")
+                        .build(ctx),
+                )
+                .build(ctx),
+        )
+    }
+}
+
+#[derive(AsAny, Default)]
+struct ReceiverState {
+    message: Option<String>,
+}
+
+impl State for ReceiverState {
+    fn update(&mut self, _: &mut Registry, ctx: &mut Context) {
+        if let Some(message) = self.message.take() {
+            text_block(ctx.child("message")).set_text(message);
+        }
+    }
+}
+
+widget!(ReceiverView<ReceiverState>);
+
+impl Template for ReceiverView {
+    fn template(self, id: Entity, ctx: &mut BuildContext) -> Self {
+        self.child(
+            Stack::new()
+                .on_message(move |states, message| {
+                    if let Some(message) = message.get::<String>() {
+                        states.get_mut::<ReceiverState>(id).message = Some(message.clone());
+                        return true;
+                    }
+
+                    false
+                })
+                .child(TextBlock::new().text("Window 2").margin(4.0).build(ctx))
+                .child(
+                    TextBlock::new()
+                        .id("message")
+                        .text("No message received")
+                        .margin(4.0)
+                        .build(ctx),
+                )
                 .build(ctx),
         )
     }
@@ -71,15 +131,11 @@ fn main() {
         })
         .window(|ctx| {
             Window::new()
+                .id("window_2")
                 .title("OrbTk - multi window example window 2")
                 .position((600.0, 100.0))
                 .size(420.0, 730.0)
-                .child(
-                    Stack::new()
-                        .child(TextBlock::new().text("Window 2").margin(4.0).build(ctx))
-                        .child(Button::new().margin(4.0).text("Click me").build(ctx))
-                        .build(ctx),
-                )
+                .child(ReceiverView::new().build(ctx))
                 .build(ctx)
         })
         .run();
@@ -222,7 +222,7 @@ impl Template for MainView {
                                     .style("button_single_content")
                                     .text("ToggleButton")
                                     .margin((0, 8, 2, 0))
-                                    .icon(material_icons_font::MD_ALARM_ON)
+                                    .icon("material.alarm_on")
                                     .attach(Grid::column(0))
                                     .attach(Grid::row(3))
                                     .build(ctx),
@@ -12,6 +12,7 @@ pub use crate::{
     api::prelude::*,
     proc_macros::*,
     render::prelude::*,
+    shell::prelude::ColorScheme,
     theme::{
         colors, dark_theme, default_theme, fonts, light_theme, vector_graphics::material_icons_font,
     },